@@ -0,0 +1,51 @@
+// 对比 UringFileWriter 与带缓冲的 FileWriter 在高频小写入下的吞吐量
+// 运行：cargo bench --features io-uring --bench uring_file
+
+use std::time::{Duration, Instant};
+
+use lycrex_tool::lycrex::logger::{FileWriter, Level, LogRecord, UringFileWriter, Writer};
+
+const RECORDS: usize = 200_000;
+
+fn run(name: &str, writer: &dyn Writer, records: &[LogRecord]) -> Duration {
+    let start = Instant::now();
+    for record in records {
+        writer.write(record);
+    }
+    writer.flush().expect("flush failed");
+    let elapsed = start.elapsed();
+
+    println!(
+        "{name:<24} {RECORDS} records in {:>8.2?} ({:>10.0} records/s)",
+        elapsed,
+        RECORDS as f64 / elapsed.as_secs_f64(),
+    );
+    elapsed
+}
+
+fn main() {
+    let dir = std::env::temp_dir().join(format!("lycrex_uring_bench_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).expect("failed to create bench directory");
+
+    let records: Vec<LogRecord> = (0..RECORDS)
+        .map(|i| LogRecord::new(Level::info(), "bench", &format!("small record number {i}")))
+        .collect();
+
+    // 每条都刷新（FileWriter 默认行为）
+    let file_writer = FileWriter::new(dir.join("file.log")).expect("failed to open file writer");
+    run("FileWriter(auto_flush)", &file_writer, &records);
+
+    let buffered_writer = FileWriter::new(dir.join("buffered.log"))
+        .expect("failed to open file writer")
+        .auto_flush(false);
+    run("FileWriter(buffered)", &buffered_writer, &records);
+
+    let uring_writer = UringFileWriter::new(dir.join("uring.log")).expect("failed to open uring writer");
+    if !uring_writer.is_uring() {
+        println!("io_uring unavailable, UringFileWriter fell back to FileWriter");
+    }
+    run("UringFileWriter", &uring_writer, &records);
+
+    drop((file_writer, buffered_writer, uring_writer));
+    let _ = std::fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,22 @@
+// 监控目录变化并打印事件
+// 运行：cargo run --example fs_watch --features fs-watch -- <path>
+
+use lycrex_tool::system::filesystem::FileEvent;
+use lycrex_tool::system::SystemTools;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let path = std::env::args().nth(1).unwrap_or_else(|| ".".to_string());
+    let watcher = SystemTools::filesystem().watch_path(&path, true)?;
+
+    println!("Watching {} (Ctrl+C to stop)", path);
+    for event in watcher.iter() {
+        match event {
+            FileEvent::Created(path) => println!("  created  {}", path.display()),
+            FileEvent::Modified(path) => println!("  modified {}", path.display()),
+            FileEvent::Removed(path) => println!("  removed  {}", path.display()),
+            FileEvent::Renamed { from, to } => println!("  renamed  {} -> {}", from.display(), to.display()),
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,258 @@
+// gzip 压缩（RFC 1951 DEFLATE + RFC 1952 gzip 封装）
+// 用于压缩轮转后的日志备份：输入按 1 MiB 分块，块内做 LZ77 匹配，并使用固定 Huffman 编码输出
+// 日志文本重复度高，固定编码即可获得不错的压缩率，同时不需要引入额外依赖
+
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::Path;
+
+/// 每个 DEFLATE 块处理的输入大小，匹配不会跨块
+const BLOCK_SIZE: usize = 1 << 20;
+const WINDOW_SIZE: usize = 32 * 1024;
+const MIN_MATCH: usize = 3;
+const MAX_MATCH: usize = 258;
+const HASH_BITS: u32 = 15;
+/// 每个位置最多比较的候选数，限制最坏情况下的耗时
+const MAX_CHAIN: usize = 64;
+const NIL: u32 = u32::MAX;
+
+const LENGTH_BASE: [u16; 29] = [
+    3, 4, 5, 6, 7, 8, 9, 10, 11, 13, 15, 17, 19, 23, 27, 31, 35, 43, 51, 59, 67, 83, 99, 115, 131, 163, 195, 227, 258,
+];
+const LENGTH_EXTRA: [u8; 29] = [0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5, 0];
+const DISTANCE_BASE: [u16; 30] = [
+    1, 2, 3, 4, 5, 7, 9, 13, 17, 25, 33, 49, 65, 97, 129, 193, 257, 385, 513, 769, 1025, 1537, 2049, 3073, 4097, 6145,
+    8193, 12289, 16385, 24577,
+];
+const DISTANCE_EXTRA: [u8; 30] = [0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 7, 7, 8, 8, 9, 9, 10, 10, 11, 11, 12, 12, 13, 13];
+
+pub use crate::utils::checksum::crc32;
+
+/// 按 DEFLATE 的位序（低位在前）拼接输出
+#[derive(Default)]
+struct BitWriter {
+    out: Vec<u8>,
+    acc: u64,
+    count: u32,
+}
+
+impl BitWriter {
+    fn write_bits(&mut self, value: u32, bits: u32) {
+        self.acc |= (value as u64) << self.count;
+        self.count += bits;
+        while self.count >= 8 {
+            self.out.push(self.acc as u8);
+            self.acc >>= 8;
+            self.count -= 8;
+        }
+    }
+
+    /// Huffman 编码按高位在前写入
+    fn write_code(&mut self, code: u32, bits: u32) {
+        self.write_bits(code.reverse_bits() >> (32 - bits), bits);
+    }
+
+    fn align(&mut self) {
+        if self.count > 0 {
+            self.out.push(self.acc as u8);
+            self.acc = 0;
+            self.count = 0;
+        }
+    }
+
+    fn take(&mut self) -> Vec<u8> {
+        std::mem::take(&mut self.out)
+    }
+}
+
+/// 流式 gzip 编码器，写入完成后必须调用 `finish` 输出最后一个块和校验尾
+pub struct GzipEncoder<W: Write> {
+    inner: W,
+    pending: Vec<u8>,
+    bits: BitWriter,
+    crc: u32,
+    size: u32,
+}
+
+impl<W: Write> GzipEncoder<W> {
+    pub fn new(mut inner: W) -> io::Result<Self> {
+        // ID1 ID2 CM=8(deflate) FLG=0 MTIME=0 XFL=0 OS=255(未知)
+        inner.write_all(&[0x1f, 0x8b, 8, 0, 0, 0, 0, 0, 0, 255])?;
+        Ok(Self {
+            inner,
+            pending: Vec::new(),
+            bits: BitWriter::default(),
+            crc: 0,
+            size: 0,
+        })
+    }
+
+    /// 输出剩余数据和 gzip 尾部（CRC32 与原始长度），返回内部写入器
+    pub fn finish(mut self) -> io::Result<W> {
+        let data = std::mem::take(&mut self.pending);
+        compress_block(&mut self.bits, &data, true);
+        self.bits.align();
+        self.inner.write_all(&self.bits.take())?;
+        self.inner.write_all(&self.crc.to_le_bytes())?;
+        self.inner.write_all(&self.size.to_le_bytes())?;
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for GzipEncoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.crc = crc32(self.crc, buf);
+        // ISIZE 为原始长度对 2^32 取模
+        self.size = self.size.wrapping_add(buf.len() as u32);
+        self.pending.extend_from_slice(buf);
+
+        while self.pending.len() >= BLOCK_SIZE {
+            let block: Vec<u8> = self.pending.drain(..BLOCK_SIZE).collect();
+            compress_block(&mut self.bits, &block, false);
+            self.inner.write_all(&self.bits.take())?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// 将 source 压缩为 target，target 已存在时会被覆盖
+pub fn compress_file(source: &Path, target: &Path) -> io::Result<()> {
+    let mut reader = BufReader::new(File::open(source)?);
+    let mut encoder = GzipEncoder::new(BufWriter::new(File::create(target)?))?;
+    io::copy(&mut reader, &mut encoder)?;
+    let file = encoder.finish()?.into_inner().map_err(|e| e.into_error())?;
+    file.sync_all()?;
+    // 保留原文件的修改时间，备份清理按修改时间排序
+    if let Ok(modified) = fs::metadata(source).and_then(|m| m.modified()) {
+        let _ = file.set_modified(modified);
+    }
+    Ok(())
+}
+
+fn hash(data: &[u8]) -> usize {
+    let value = (data[0] as usize) << 10 ^ (data[1] as usize) << 5 ^ data[2] as usize;
+    value & ((1 << HASH_BITS) - 1)
+}
+
+/// 使用固定 Huffman 编码输出一个 DEFLATE 块
+fn compress_block(bits: &mut BitWriter, data: &[u8], last: bool) {
+    bits.write_bits(last as u32, 1);
+    bits.write_bits(1, 2); // BTYPE=01 固定 Huffman
+
+    // head 记录每个哈希值最近出现的位置，prev 串起同一哈希值的更早位置
+    let mut head = vec![NIL; 1 << HASH_BITS];
+    let mut prev = vec![NIL; data.len()];
+    let insert = |position: usize, head: &mut [u32], prev: &mut [u32]| {
+        if position + MIN_MATCH <= data.len() {
+            let h = hash(&data[position..]);
+            prev[position] = head[h];
+            head[h] = position as u32;
+        }
+    };
+
+    let mut i = 0;
+    while i < data.len() {
+        let (mut best_len, mut best_distance) = (0, 0);
+        if i + MIN_MATCH <= data.len() {
+            let max = (data.len() - i).min(MAX_MATCH);
+            let mut candidate = head[hash(&data[i..])];
+            let mut chain = 0;
+            while candidate != NIL && chain < MAX_CHAIN {
+                let position = candidate as usize;
+                let distance = i - position;
+                if distance > WINDOW_SIZE {
+                    break;
+                }
+                let len = data[position..].iter().zip(&data[i..i + max]).take_while(|(a, b)| a == b).count();
+                if len > best_len {
+                    best_len = len;
+                    best_distance = distance;
+                    if len == max {
+                        break;
+                    }
+                }
+                candidate = prev[position];
+                chain += 1;
+            }
+        }
+
+        if best_len >= MIN_MATCH {
+            write_length(bits, best_len);
+            write_distance(bits, best_distance);
+            for position in i..i + best_len {
+                insert(position, &mut head, &mut prev);
+            }
+            i += best_len;
+        } else {
+            insert(i, &mut head, &mut prev);
+            write_literal(bits, data[i] as u16);
+            i += 1;
+        }
+    }
+
+    write_literal(bits, 256); // 块结束
+}
+
+/// 固定 Huffman 的字面量 / 长度码表（RFC 1951 3.2.6）
+fn write_literal(bits: &mut BitWriter, symbol: u16) {
+    let symbol = symbol as u32;
+    match symbol {
+        0..=143 => bits.write_code(0x30 + symbol, 8),
+        144..=255 => bits.write_code(0x190 + symbol - 144, 9),
+        256..=279 => bits.write_code(symbol - 256, 7),
+        _ => bits.write_code(0xc0 + symbol - 280, 8),
+    }
+}
+
+fn write_length(bits: &mut BitWriter, len: usize) {
+    let index = LENGTH_BASE.iter().rposition(|&base| base as usize <= len).unwrap_or(0);
+    write_literal(bits, 257 + index as u16);
+    bits.write_bits((len - LENGTH_BASE[index] as usize) as u32, LENGTH_EXTRA[index] as u32);
+}
+
+fn write_distance(bits: &mut BitWriter, distance: usize) {
+    let index = DISTANCE_BASE.iter().rposition(|&base| base as usize <= distance).unwrap_or(0);
+    bits.write_code(index as u32, 5);
+    bits.write_bits((distance - DISTANCE_BASE[index] as usize) as u32, DISTANCE_EXTRA[index] as u32);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn test_gzip_round_trip() {
+        let mut input = Vec::new();
+        for i in 0..50_000 {
+            writeln!(input, "2024-01-01 00:00:{:02} [INFO] request {} handled in {}ms", i % 60, i, i % 97).unwrap();
+        }
+        input.extend((0..=255u8).cycle().take(3000));
+
+        let mut encoder = GzipEncoder::new(Vec::new()).unwrap();
+        encoder.write_all(&input).unwrap();
+        let compressed = encoder.finish().unwrap();
+        assert!(compressed.len() < input.len() / 4, "{} -> {}", input.len(), compressed.len());
+
+        // 系统没有 gzip 时只检查压缩率
+        let Ok(mut child) = std::process::Command::new("gzip")
+            .arg("-dc")
+            .stdin(std::process::Stdio::piped())
+            .stdout(std::process::Stdio::piped())
+            .spawn()
+        else {
+            return;
+        };
+        let mut stdin = child.stdin.take().unwrap();
+        let writer = std::thread::spawn(move || stdin.write_all(&compressed));
+        let output = child.wait_with_output().unwrap();
+        writer.join().unwrap().unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, input);
+    }
+}
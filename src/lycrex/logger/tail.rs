@@ -0,0 +1,221 @@
+// 日志文件跟随读取
+// 类似 `tail -f`：先输出已有内容，再阻塞等待新追加的行，并处理截断和轮转
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+/// 默认轮询间隔
+const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// 日志文件跟随读取器
+pub struct FileTailer {
+    path: PathBuf,
+    poll_interval: Duration,
+    from_end: bool,
+}
+
+impl FileTailer {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            from_end: false,
+        }
+    }
+
+    /// 设置没有新内容时的轮询间隔
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// 从文件末尾开始，只读取之后追加的行（默认从头读取已有内容）
+    pub fn from_end(mut self, from_end: bool) -> Self {
+        self.from_end = from_end;
+        self
+    }
+
+    /// 逐行读取，读完现有内容后阻塞等待追加
+    /// 文件被截断（大小变小）或轮转（路径指向了新文件）时自动从新文件开头重新读取
+    pub fn lines(self) -> TailLines {
+        TailLines {
+            skip_existing: self.from_end,
+            tailer: self,
+            reader: None,
+            identity: None,
+            position: 0,
+            pending: String::new(),
+        }
+    }
+
+    /// 将每一行解析为 JSON（配合 JsonFormatter 输出的 NDJSON 日志使用）
+    /// 无法解析的行返回 `InvalidData` 错误，不会中断后续读取
+    pub fn json_lines(self) -> impl Iterator<Item = io::Result<serde_json::Value>> {
+        self.lines()
+            .filter(|line| !matches!(line, Ok(line) if line.trim().is_empty()))
+            .map(|line| {
+                line.and_then(|line| {
+                    serde_json::from_str(&line).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                })
+            })
+    }
+}
+
+/// 跟随读取的行迭代器
+pub struct TailLines {
+    tailer: FileTailer,
+    reader: Option<BufReader<File>>,
+    identity: Option<FileIdentity>,
+    position: u64,
+    pending: String,
+    skip_existing: bool,
+}
+
+impl TailLines {
+    /// 打开文件，文件不存在时返回 Ok(false)
+    fn open(&mut self) -> io::Result<bool> {
+        let mut file = match File::open(&self.tailer.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(false),
+            Err(e) => return Err(e),
+        };
+
+        // 只有第一次打开时跳过已有内容，轮转后的新文件需要从头读取
+        self.position = if std::mem::take(&mut self.skip_existing) {
+            file.seek(SeekFrom::End(0))?
+        } else {
+            0
+        };
+        self.identity = FileIdentity::of(&file.metadata()?);
+        self.reader = Some(BufReader::new(file));
+        self.pending.clear();
+        Ok(true)
+    }
+
+    /// 到达文件末尾时检查是否发生了截断或轮转
+    fn was_replaced(&self) -> bool {
+        match std::fs::metadata(&self.tailer.path) {
+            Ok(metadata) => {
+                metadata.len() < self.position
+                    || (self.identity.is_some() && FileIdentity::of(&metadata) != self.identity)
+            }
+            // 旧文件已被移走而新文件尚未创建，继续等待
+            Err(_) => false,
+        }
+    }
+}
+
+impl Iterator for TailLines {
+    type Item = io::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.reader.is_none() {
+                match self.open() {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        thread::sleep(self.tailer.poll_interval);
+                        continue;
+                    }
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+
+            let reader = self.reader.as_mut()?;
+            let mut chunk = String::new();
+            match reader.read_line(&mut chunk) {
+                Ok(0) => {
+                    if self.was_replaced() {
+                        self.reader = None;
+                        continue;
+                    }
+                    thread::sleep(self.tailer.poll_interval);
+                }
+                Ok(read) => {
+                    self.position += read as u64;
+                    self.pending.push_str(&chunk);
+                    // 写入方可能只写了半行，等换行符到达后再输出
+                    if self.pending.ends_with('\n') {
+                        let mut line = std::mem::take(&mut self.pending);
+                        line.pop();
+                        if line.ends_with('\r') {
+                            line.pop();
+                        }
+                        return Some(Ok(line));
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// 用于判断路径是否指向了另一个文件
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct FileIdentity {
+    device: u64,
+    inode: u64,
+}
+
+impl FileIdentity {
+    #[cfg(unix)]
+    fn of(metadata: &std::fs::Metadata) -> Option<Self> {
+        use std::os::unix::fs::MetadataExt;
+        Some(Self { device: metadata.dev(), inode: metadata.ino() })
+    }
+
+    /// 非 Unix 平台无法稳定获取文件标识，仅依靠大小变化检测轮转
+    #[cfg(not(unix))]
+    fn of(_metadata: &std::fs::Metadata) -> Option<Self> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use std::sync::mpsc;
+
+    fn append(path: &Path, text: &str) {
+        let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path).unwrap();
+        file.write_all(text.as_bytes()).unwrap();
+    }
+
+    #[test]
+    fn test_follow_appends_and_rotation() {
+        let dir = std::env::temp_dir().join(format!("lycrex-tail-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("app.log");
+        append(&path, "one\ntwo\n");
+
+        let (sender, receiver) = mpsc::channel();
+        let tail_path = path.clone();
+        thread::spawn(move || {
+            for line in FileTailer::new(tail_path).poll_interval(Duration::from_millis(10)).lines() {
+                if sender.send(line.unwrap()).is_err() {
+                    break;
+                }
+            }
+        });
+        let next = || receiver.recv_timeout(Duration::from_secs(5)).unwrap();
+
+        assert_eq!(next(), "one");
+        assert_eq!(next(), "two");
+
+        append(&path, "thr");
+        thread::sleep(Duration::from_millis(50));
+        append(&path, "ee\n");
+        assert_eq!(next(), "three");
+
+        std::fs::rename(&path, dir.join("app.1.log")).unwrap();
+        append(&path, "rotated\n");
+        assert_eq!(next(), "rotated");
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,210 @@
+// TLS 网络连接
+// 基于 rustls（ring 加密后端）建立 TLS 会话，日志逐行加密后写入 TCP 连接
+// 证书链和主机名由 rustls/webpki 校验，默认信任系统根证书，也可以固定为指定的 CA
+
+use super::NetworkConnection;
+use rustls::pki_types::{pem::PemObject, CertificateDer, ServerName};
+use rustls::{ClientConfig, ClientConnection, RootCertStore, StreamOwned};
+use std::{
+    io::{self, Write},
+    net::{SocketAddr, TcpStream},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+/// 基于 TLS 的 TCP 连接，发送格式与 [`super::TcpConnection`] 相同（每条日志后追加换行）
+pub struct TlsConnection {
+    address: SocketAddr,
+    domain: String,
+    ca_file: Option<PathBuf>,
+    timeout: Duration,
+    stream: Option<StreamOwned<ClientConnection, TcpStream>>,
+}
+
+impl TlsConnection {
+    /// `domain` 用于 SNI 和证书主机名校验；为 IP 地址时按证书中的 IP SAN 校验
+    pub fn new(address: SocketAddr, domain: &str, timeout: Duration) -> Self {
+        Self {
+            address,
+            domain: domain.to_string(),
+            ca_file: None,
+            timeout,
+            stream: None,
+        }
+    }
+
+    /// 只信任指定 PEM 文件中的 CA 证书，不再使用系统根证书
+    pub fn pinned_ca<P: AsRef<Path>>(mut self, ca_file: P) -> Self {
+        self.ca_file = Some(ca_file.as_ref().to_path_buf());
+        self
+    }
+
+    /// 根据信任的根证书构造客户端配置
+    fn client_config(&self) -> io::Result<Arc<ClientConfig>> {
+        let mut roots = RootCertStore::empty();
+        match self.ca_file {
+            Some(ref ca_file) => {
+                for cert in CertificateDer::pem_file_iter(ca_file).map_err(invalid_certificate)? {
+                    roots.add(cert.map_err(invalid_certificate)?).map_err(invalid_certificate)?;
+                }
+            }
+            None => {
+                roots.add_parsable_certificates(rustls_native_certs::load_native_certs().certs);
+            }
+        }
+        if roots.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::NotFound, "no trusted root certificates available"));
+        }
+
+        let config = ClientConfig::builder_with_provider(Arc::new(rustls::crypto::ring::default_provider()))
+            .with_safe_default_protocol_versions()
+            .map_err(io::Error::other)?
+            .with_root_certificates(roots)
+            .with_no_client_auth();
+        Ok(Arc::new(config))
+    }
+
+    /// 建立 TCP 连接并完成 TLS 握手
+    /// 连接、握手期间的每次读写都受 `timeout` 限制，证书校验失败时返回 `InvalidData`
+    fn handshake(&self) -> io::Result<StreamOwned<ClientConnection, TcpStream>> {
+        let server_name = ServerName::try_from(self.domain.clone())
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, format!("invalid TLS server name {}: {}", self.domain, e)))?;
+        let connection = ClientConnection::new(self.client_config()?, server_name).map_err(io::Error::other)?;
+
+        let socket = TcpStream::connect_timeout(&self.address, self.timeout)?;
+        socket.set_read_timeout(Some(self.timeout))?;
+        socket.set_write_timeout(Some(self.timeout))?;
+
+        let mut stream = StreamOwned::new(connection, socket);
+        while stream.conn.is_handshaking() {
+            stream.conn.complete_io(&mut stream.sock)?;
+        }
+        Ok(stream)
+    }
+}
+
+fn invalid_certificate(err: impl std::fmt::Display) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, format!("invalid CA certificate: {}", err))
+}
+
+impl NetworkConnection for TlsConnection {
+    fn send(&mut self, data: &str) -> io::Result<()> {
+        if !self.is_connected() {
+            self.reconnect()?;
+        }
+
+        if let Some(ref mut stream) = self.stream {
+            let result = stream
+                .write_all(data.as_bytes())
+                .and_then(|_| stream.write_all(b"\n"))
+                .and_then(|_| stream.flush());
+            // 服务端关闭连接后写入失败，丢弃会话以便下次发送时重新握手
+            if result.is_err() {
+                self.stream = None;
+            }
+            result?;
+        }
+        Ok(())
+    }
+
+    fn is_connected(&self) -> bool {
+        self.stream.is_some()
+    }
+
+    fn reconnect(&mut self) -> io::Result<()> {
+        self.stream = None;
+        self.stream = Some(self.handshake()?);
+        Ok(())
+    }
+
+    fn close(&mut self) -> io::Result<()> {
+        if let Some(mut stream) = self.stream.take() {
+            stream.conn.send_close_notify();
+            let _ = stream.flush();
+        }
+        Ok(())
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::process::{Child, Command, Stdio};
+    use std::thread;
+    use std::time::Instant;
+
+    /// 生成自签名 CA 和由它签发的 localhost 证书，再启动 openssl s_server；系统没有 openssl 时返回 None
+    fn start_server(dir: &Path) -> Option<(Child, SocketAddr)> {
+        let openssl = |args: &[&str]| {
+            Command::new("openssl")
+                .args(args)
+                .current_dir(dir)
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()
+                .is_ok_and(|status| status.success())
+        };
+        let key = "-newkey ec -pkeyopt ec_paramgen_curve:prime256v1 -nodes";
+        std::fs::write(dir.join("leaf.ext"), "subjectAltName=DNS:localhost\nbasicConstraints=CA:FALSE\n").ok()?;
+        let steps = [
+            format!("req -x509 {key} -subj /CN=lycrex-test-ca -days 1 -keyout ca.key -out ca.pem"),
+            format!("req -new {key} -subj /CN=localhost -keyout key.pem -out leaf.csr"),
+            "x509 -req -in leaf.csr -CA ca.pem -CAkey ca.key -CAcreateserial -days 1 -extfile leaf.ext -out cert.pem".into(),
+        ];
+        if !steps.iter().all(|step| openssl(&step.split(' ').collect::<Vec<_>>())) {
+            return None;
+        }
+
+        let address = std::net::TcpListener::bind("127.0.0.1:0").ok()?.local_addr().ok()?;
+        let server = Command::new("openssl")
+            .args(["s_server", "-quiet", "-accept", &address.to_string()])
+            .arg("-cert")
+            .arg(dir.join("cert.pem"))
+            .arg("-key")
+            .arg(dir.join("key.pem"))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .ok()?;
+
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while std::net::TcpStream::connect(address).is_err() {
+            if Instant::now() > deadline {
+                return None;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+        Some((server, address))
+    }
+
+    #[test]
+    fn test_tls_connection_pinned_ca() {
+        let dir = std::env::temp_dir().join(format!("lycrex_tls_test_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let Some((mut server, address)) = start_server(&dir) else {
+            let _ = std::fs::remove_dir_all(&dir);
+            return;
+        };
+
+        // 测试 CA 不在系统根证书中，默认配置下校验失败
+        let mut untrusted = TlsConnection::new(address, "localhost", Duration::from_secs(5));
+        assert!(untrusted.send("rejected").is_err());
+        assert!(!untrusted.is_connected());
+
+        let mut connection = TlsConnection::new(address, "localhost", Duration::from_secs(5)).pinned_ca(dir.join("ca.pem"));
+        connection.send("hello over tls").unwrap();
+        assert!(connection.is_connected());
+
+        let mut line = String::new();
+        BufReader::new(server.stdout.take().unwrap()).read_line(&mut line).unwrap();
+        assert_eq!(line, "hello over tls\n");
+
+        connection.close().unwrap();
+        let _ = server.kill();
+        let _ = server.wait();
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,671 @@
+// io_uring 文件写入器
+// 调用方只负责格式化并把数据放进队列，后台线程把队列中已有的记录合并成若干块，
+// 一次 io_uring_enter 批量提交并回收完成事件，高频小写入时可以显著减少系统调用次数
+// 直接使用 io_uring_setup/io_uring_enter 系统调用；运行时不可用（内核过旧、被 seccomp 禁止、非 Linux 平台）
+// 时自动退回普通的 FileWriter
+
+use super::{
+    ColorProcessor, DefaultFormatter, FileWriter, Formatter, LogRecord, LoggerMetrics, Writer, DEFAULT_LOG_THREAD_NAME,
+};
+use std::{any::Any, io, path::Path, sync::Arc};
+
+#[cfg(target_os = "linux")]
+use std::{
+    fs::{File, OpenOptions},
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        OnceLock,
+    },
+    thread::{self, JoinHandle},
+};
+
+/// 基于 io_uring 的文件写入器
+/// 不支持日志轮转；需要轮转时请使用 [`FileWriter`]
+pub struct UringFileWriter {
+    backend: Backend,
+}
+
+enum Backend {
+    #[cfg(target_os = "linux")]
+    Uring(UringWorker),
+    File(FileWriter),
+}
+
+impl UringFileWriter {
+    /// 以追加模式打开日志文件
+    pub fn new<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::with_options(path, Box::new(DefaultFormatter::without_colors()), true)
+    }
+
+    /// 指定格式化器和写入模式打开日志文件
+    /// - `append`: true 表示追加写入，false 表示清空原有内容
+    pub fn with_options<P: AsRef<Path>>(path: P, formatter: Box<dyn Formatter>, append: bool) -> io::Result<Self> {
+        #[cfg(target_os = "linux")]
+        let formatter = match UringWorker::start(path.as_ref(), formatter, append) {
+            Ok(worker) => return Ok(Self { backend: Backend::Uring(worker) }),
+            Err((formatter, _)) => formatter,
+        };
+
+        let writer = FileWriter::with_formatter(path, formatter)?.append(append);
+        Ok(Self { backend: Backend::File(writer) })
+    }
+
+    /// 写入失败时记录到性能指标的错误计数（退回 FileWriter 时同样计入打开失败）
+    pub fn with_metrics(self, metrics: Arc<LoggerMetrics>) -> Self {
+        match self.backend {
+            #[cfg(target_os = "linux")]
+            Backend::Uring(worker) => {
+                let _ = worker.metrics.set(metrics);
+                Self { backend: Backend::Uring(worker) }
+            }
+            Backend::File(writer) => Self { backend: Backend::File(writer.with_metrics(metrics)) },
+        }
+    }
+
+    /// 是否实际使用了 io_uring（false 表示已退回 FileWriter）
+    pub fn is_uring(&self) -> bool {
+        match self.backend {
+            #[cfg(target_os = "linux")]
+            Backend::Uring(_) => true,
+            Backend::File(_) => false,
+        }
+    }
+}
+
+impl Writer for UringFileWriter {
+    fn write(&self, record: &LogRecord) {
+        match &self.backend {
+            #[cfg(target_os = "linux")]
+            Backend::Uring(worker) => worker.write(record),
+            Backend::File(writer) => writer.write(record),
+        }
+    }
+
+    fn flush(&self) -> io::Result<()> {
+        match &self.backend {
+            #[cfg(target_os = "linux")]
+            Backend::Uring(worker) => worker.flush(),
+            Backend::File(writer) => writer.flush(),
+        }
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn supports_colors(&self) -> bool {
+        false
+    }
+}
+
+/// 单个写请求合并后的最大字节数
+#[cfg(target_os = "linux")]
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// 提交队列深度，也是一批中最多的写请求数
+#[cfg(target_os = "linux")]
+const QUEUE_DEPTH: u32 = 32;
+
+#[cfg(target_os = "linux")]
+enum Command {
+    Write(Vec<u8>),
+    Flush(Sender<io::Result<()>>),
+}
+
+/// 持有后台提交线程
+#[cfg(target_os = "linux")]
+struct UringWorker {
+    formatter: Box<dyn Formatter>,
+    sender: Option<Sender<Command>>,
+    handle: Option<JoinHandle<()>>,
+    /// 与后台线程共享，写入失败时计入错误数
+    metrics: Arc<OnceLock<Arc<LoggerMetrics>>>,
+}
+
+#[cfg(target_os = "linux")]
+impl UringWorker {
+    /// 打开文件并创建 io_uring；失败时把格式化器交还给调用方用于回退
+    fn start(
+        path: &Path,
+        formatter: Box<dyn Formatter>,
+        append: bool,
+    ) -> Result<Self, (Box<dyn Formatter>, io::Error)> {
+        let ring = match ring::Ring::new(QUEUE_DEPTH) {
+            Ok(ring) => ring,
+            Err(e) => return Err((formatter, e)),
+        };
+
+        // 以 O_APPEND 打开，其他进程同时追加写入同一文件时不会互相覆盖；
+        // 同一批内的请求通过 IOSQE_IO_LINK 串联，按提交顺序依次执行
+        let truncated = if append { Ok(()) } else { File::create(path).map(drop) };
+        let opened = truncated.and_then(|_| OpenOptions::new().append(true).create(true).open(path));
+        let file = match opened {
+            Ok(file) => file,
+            Err(e) => return Err((formatter, e)),
+        };
+
+        let (sender, receiver) = mpsc::channel();
+        let metrics = Arc::new(OnceLock::new());
+        let worker_metrics = metrics.clone();
+        let handle = thread::Builder::new()
+            .name(format!("{DEFAULT_LOG_THREAD_NAME}-uring"))
+            .spawn(move || run_worker(ring, file, receiver, worker_metrics));
+        match handle {
+            Ok(handle) => Ok(Self { formatter, sender: Some(sender), handle: Some(handle), metrics }),
+            Err(e) => Err((formatter, e)),
+        }
+    }
+
+    fn write(&self, record: &LogRecord) {
+        let message = ColorProcessor::strip_ansi_codes(&self.formatter.format(record));
+        let mut bytes = message.into_bytes();
+        bytes.push(b'\n');
+        if let Some(sender) = &self.sender {
+            let _ = sender.send(Command::Write(bytes));
+        }
+    }
+
+    /// 等待此前提交的所有记录写入完成，并返回期间发生的写入错误
+    fn flush(&self) -> io::Result<()> {
+        let sender = self.sender.as_ref().ok_or_else(|| io::Error::from(io::ErrorKind::BrokenPipe))?;
+        let (reply, done) = mpsc::channel();
+        sender
+            .send(Command::Flush(reply))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "uring worker stopped"))?;
+        done.recv()
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "uring worker stopped"))?
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl Drop for UringWorker {
+    fn drop(&mut self) {
+        // 关闭通道后线程会写完队列中剩余的记录再退出
+        self.sender.take();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn run_worker(ring: ring::Ring, file: File, receiver: Receiver<Command>, metrics: Arc<OnceLock<Arc<LoggerMetrics>>>) {
+    let mut ring = Some(ring);
+    let mut pending_error: Option<io::Error> = None;
+
+    while let Ok(first) = receiver.recv() {
+        let mut chunks: Vec<Vec<u8>> = Vec::new();
+        let mut waiters = Vec::new();
+        let mut next = Some(first);
+
+        // 把队列中已有的命令一次取完，小记录合并到同一块里
+        while let Some(command) = next {
+            match command {
+                Command::Write(bytes) => match chunks.last_mut() {
+                    Some(chunk) if chunk.len() + bytes.len() <= CHUNK_SIZE => chunk.extend_from_slice(&bytes),
+                    _ => chunks.push(bytes),
+                },
+                Command::Flush(reply) => waiters.push(reply),
+            }
+            if chunks.len() >= QUEUE_DEPTH as usize {
+                break;
+            }
+            next = receiver.try_recv().ok();
+        }
+
+        if let Err(e) = write_chunks(&mut ring, &file, &mut chunks) {
+            if let Some(metrics) = metrics.get() {
+                metrics.record_error();
+            }
+            pending_error = Some(e);
+        }
+        for reply in waiters {
+            let _ = reply.send(pending_error.take().map_or(Ok(()), Err));
+        }
+    }
+}
+
+/// 通过 io_uring 提交一批写请求，只有没有被内核成功写入的块才改用同步写入：
+/// 未提交的块、因前一个请求失败或短写入而被取消的块，以及短写入的剩余部分；
+/// io_uring 出现无法恢复的错误时释放 ring，之后的写入全部走同步路径
+#[cfg(target_os = "linux")]
+fn write_chunks(ring: &mut Option<ring::Ring>, file: &File, chunks: &mut [Vec<u8>]) -> io::Result<()> {
+    use ring::Outcome;
+    use std::io::Write;
+    use std::os::unix::io::AsRawFd;
+
+    if chunks.is_empty() {
+        return Ok(());
+    }
+
+    let outcomes = match ring.as_mut().map(|ring| ring.write_batch(file.as_raw_fd(), chunks)) {
+        Some(Ok(outcomes)) => outcomes,
+        // 返回错误时没有任何请求进入队列
+        _ => vec![Outcome::NotSubmitted; chunks.len()],
+    };
+    if ring.as_ref().is_some_and(|ring| ring.is_broken()) {
+        *ring = None;
+    }
+
+    let mut out = file;
+    let mut first_error = None;
+    for (chunk, outcome) in chunks.iter().zip(outcomes) {
+        let result = match outcome {
+            Outcome::Completed(res) if res == -libc::ECANCELED => out.write_all(chunk),
+            Outcome::Completed(res) if res < 0 => Err(io::Error::from_raw_os_error(-res)),
+            Outcome::Completed(res) => match chunk.get(res as usize..) {
+                Some(rest) if !rest.is_empty() => out.write_all(rest),
+                _ => Ok(()),
+            },
+            Outcome::NotSubmitted => out.write_all(chunk),
+            // 请求可能仍在执行，补写会造成重复，只能报告错误
+            Outcome::Unknown => Err(io::Error::other("io_uring write did not complete; ring was torn down")),
+        };
+        if let Err(e) = result {
+            first_error.get_or_insert(e);
+        }
+    }
+    first_error.map_or(Ok(()), Err)
+}
+
+/// io_uring 的最小封装：只实现批量 WRITEV 所需的部分
+#[cfg(target_os = "linux")]
+mod ring {
+    use std::io;
+    use std::os::unix::io::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+    use std::ptr;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    const IORING_OFF_SQ_RING: libc::off_t = 0;
+    const IORING_OFF_CQ_RING: libc::off_t = 0x0800_0000;
+    const IORING_OFF_SQES: libc::off_t = 0x1000_0000;
+    const IORING_ENTER_GETEVENTS: libc::c_uint = 1;
+    const IORING_OP_WRITEV: u8 = 2;
+    const IOSQE_IO_LINK: u8 = 1 << 2;
+    /// 等待 io_uring_enter 时被信号中断的最大重试次数
+    const MAX_INTERRUPTED_RETRIES: u32 = 64;
+
+    /// 单个写请求的结果
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(super) enum Outcome {
+        /// 完成事件的结果：非负为写入字节数，负数为 -errno
+        Completed(i32),
+        /// 没有提交给内核，已从提交队列中撤回
+        NotSubmitted,
+        /// 已提交但无法等到完成事件，结果未知；对应的缓冲区已被泄漏，保证内核访问时仍然有效
+        Unknown,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct SqringOffsets {
+        head: u32,
+        tail: u32,
+        ring_mask: u32,
+        ring_entries: u32,
+        flags: u32,
+        dropped: u32,
+        array: u32,
+        resv1: u32,
+        user_addr: u64,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct CqringOffsets {
+        head: u32,
+        tail: u32,
+        ring_mask: u32,
+        ring_entries: u32,
+        overflow: u32,
+        cqes: u32,
+        flags: u32,
+        resv1: u32,
+        user_addr: u64,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct Params {
+        sq_entries: u32,
+        cq_entries: u32,
+        flags: u32,
+        sq_thread_cpu: u32,
+        sq_thread_idle: u32,
+        features: u32,
+        wq_fd: u32,
+        resv: [u32; 3],
+        sq_off: SqringOffsets,
+        cq_off: CqringOffsets,
+    }
+
+    #[repr(C)]
+    #[derive(Default)]
+    struct Sqe {
+        opcode: u8,
+        flags: u8,
+        ioprio: u16,
+        fd: i32,
+        off: u64,
+        addr: u64,
+        len: u32,
+        rw_flags: u32,
+        user_data: u64,
+        buf_index: u16,
+        personality: u16,
+        splice_fd_in: i32,
+        addr3: u64,
+        pad: u64,
+    }
+
+    #[repr(C)]
+    struct Cqe {
+        user_data: u64,
+        res: i32,
+        flags: u32,
+    }
+
+    /// 与内核共享的内存映射
+    struct Mmap {
+        ptr: *mut u8,
+        len: usize,
+    }
+
+    impl Mmap {
+        fn new(fd: RawFd, len: usize, offset: libc::off_t) -> io::Result<Self> {
+            let ptr = unsafe {
+                libc::mmap(
+                    ptr::null_mut(),
+                    len,
+                    libc::PROT_READ | libc::PROT_WRITE,
+                    libc::MAP_SHARED | libc::MAP_POPULATE,
+                    fd,
+                    offset,
+                )
+            };
+            if ptr == libc::MAP_FAILED {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(Self { ptr: ptr.cast(), len })
+        }
+
+        /// 映射内偏移 `offset` 处的指针
+        fn at<T>(&self, offset: u32) -> *mut T {
+            debug_assert!((offset as usize) < self.len);
+            unsafe { self.ptr.add(offset as usize).cast() }
+        }
+    }
+
+    impl Drop for Mmap {
+        fn drop(&mut self) {
+            unsafe {
+                libc::munmap(self.ptr.cast(), self.len);
+            }
+        }
+    }
+
+    pub(super) struct Ring {
+        // 字段按声明顺序析构：先解除映射再关闭 fd
+        sq: Mmap,
+        cq: Mmap,
+        sqes: Mmap,
+        fd: OwnedFd,
+        sq_entries: u32,
+        sq_off: SqringOffsets,
+        cq_off: CqringOffsets,
+        /// 出现过无法等到完成事件的请求，ring 不能再使用
+        broken: bool,
+    }
+
+    // 映射只在持有 Ring 的线程中访问
+    unsafe impl Send for Ring {}
+
+    impl Ring {
+        pub(super) fn new(entries: u32) -> io::Result<Self> {
+            let mut params = Params::default();
+            let fd = unsafe { libc::syscall(libc::SYS_io_uring_setup, entries, &mut params as *mut Params) };
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let fd = unsafe { OwnedFd::from_raw_fd(fd as RawFd) };
+            let raw = fd.as_raw_fd();
+
+            let sq_len = params.sq_off.array as usize + params.sq_entries as usize * std::mem::size_of::<u32>();
+            let cq_len = params.cq_off.cqes as usize + params.cq_entries as usize * std::mem::size_of::<Cqe>();
+            let sqes_len = params.sq_entries as usize * std::mem::size_of::<Sqe>();
+
+            Ok(Self {
+                sq: Mmap::new(raw, sq_len, IORING_OFF_SQ_RING)?,
+                cq: Mmap::new(raw, cq_len, IORING_OFF_CQ_RING)?,
+                sqes: Mmap::new(raw, sqes_len, IORING_OFF_SQES)?,
+                fd,
+                sq_entries: params.sq_entries,
+                sq_off: params.sq_off,
+                cq_off: params.cq_off,
+                broken: false,
+            })
+        }
+
+        /// 是否因无法回收完成事件而不可再用，此时应丢弃 ring
+        pub(super) fn is_broken(&self) -> bool {
+            self.broken
+        }
+
+        /// 按顺序提交一批写请求（通过 IOSQE_IO_LINK 串联）并等待全部完成，结果与请求一一对应
+        /// 返回 Err 时没有任何请求进入队列；返回 Ok 时队列中不会残留本批的请求：
+        /// 未能提交的请求会从队列中撤回（[`Outcome::NotSubmitted`]），已提交的请求都会等到完成事件，
+        /// 只有等待失败时才把 ring 标记为不可用，并泄漏仍在执行的请求的缓冲区（[`Outcome::Unknown`]）
+        pub(super) fn write_batch(&mut self, fd: RawFd, chunks: &mut [Vec<u8>]) -> io::Result<Vec<Outcome>> {
+            let count = chunks.len() as u32;
+            if self.broken {
+                return Err(io::Error::other("io_uring ring is no longer usable"));
+            }
+            if count == 0 {
+                return Ok(Vec::new());
+            }
+            if count > self.sq_entries {
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, "too many requests for ring"));
+            }
+
+            // iovec 与数据必须在请求完成前保持有效
+            let iovecs: Vec<libc::iovec> = chunks
+                .iter()
+                .map(|data| libc::iovec { iov_base: data.as_ptr() as *mut libc::c_void, iov_len: data.len() })
+                .collect();
+
+            let (head, tail) = unsafe {
+                (&*self.sq.at::<AtomicU32>(self.sq_off.head), &*self.sq.at::<AtomicU32>(self.sq_off.tail))
+            };
+            let first = tail.load(Ordering::Relaxed);
+            if first.wrapping_sub(head.load(Ordering::Acquire)) + count > self.sq_entries {
+                return Err(io::Error::new(io::ErrorKind::WouldBlock, "submission queue full"));
+            }
+            unsafe {
+                let mask = *self.sq.at::<u32>(self.sq_off.ring_mask);
+                let array = self.sq.at::<u32>(self.sq_off.array);
+                let sqes = self.sqes.at::<Sqe>(0);
+
+                let mut next = first;
+                for (index, iovec) in iovecs.iter().enumerate() {
+                    let slot = next & mask;
+                    sqes.add(slot as usize).write(Sqe {
+                        opcode: IORING_OP_WRITEV,
+                        flags: if index + 1 < iovecs.len() { IOSQE_IO_LINK } else { 0 },
+                        fd,
+                        // 文件以 O_APPEND 打开，内核忽略偏移量，总是写到文件末尾
+                        off: 0,
+                        addr: iovec as *const libc::iovec as u64,
+                        len: 1,
+                        user_data: index as u64,
+                        ..Sqe::default()
+                    });
+                    array.add(slot as usize).write(slot);
+                    next = next.wrapping_add(1);
+                }
+                tail.store(next, Ordering::Release);
+            }
+
+            let mut submitted = 0;
+            let mut interrupts = 0;
+            while submitted < count {
+                match self.enter(count - submitted, 0) {
+                    Ok(n) if n > 0 => submitted += n,
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted && interrupts < MAX_INTERRUPTED_RETRIES => {
+                        interrupts += 1;
+                    }
+                    _ => break,
+                }
+            }
+            // 没有 SQPOLL 时内核只在 io_uring_enter 中消费提交队列，撤回未提交的请求不会与内核竞争
+            if submitted < count {
+                tail.store(first.wrapping_add(submitted), Ordering::Release);
+            }
+
+            let mut outcomes: Vec<Outcome> = (0..count)
+                .map(|index| if index < submitted { Outcome::Unknown } else { Outcome::NotSubmitted })
+                .collect();
+            let mut pending = submitted;
+            let mut interrupts = 0;
+            while pending > 0 {
+                pending -= self.reap(&mut outcomes).min(pending);
+                if pending == 0 {
+                    break;
+                }
+                match self.enter(0, pending) {
+                    Ok(_) => {}
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted && interrupts < MAX_INTERRUPTED_RETRIES => {
+                        interrupts += 1;
+                    }
+                    Err(_) => {
+                        // 无法确认请求何时结束：泄漏仍被内核引用的缓冲区，并停止使用这个 ring
+                        self.broken = true;
+                        for (chunk, outcome) in chunks.iter_mut().zip(&outcomes) {
+                            if *outcome == Outcome::Unknown {
+                                std::mem::forget(std::mem::take(chunk));
+                            }
+                        }
+                        std::mem::forget(iovecs);
+                        return Ok(outcomes);
+                    }
+                }
+            }
+            drop(iovecs);
+            Ok(outcomes)
+        }
+
+        /// 回收当前可用的完成事件，返回数量
+        fn reap(&mut self, outcomes: &mut [Outcome]) -> u32 {
+            unsafe {
+                let head = &*self.cq.at::<AtomicU32>(self.cq_off.head);
+                let tail = &*self.cq.at::<AtomicU32>(self.cq_off.tail);
+                let mask = *self.cq.at::<u32>(self.cq_off.ring_mask);
+                let cqes = self.cq.at::<Cqe>(self.cq_off.cqes);
+
+                let mut current = head.load(Ordering::Relaxed);
+                let available = tail.load(Ordering::Acquire);
+                let mut reaped = 0;
+                while current != available {
+                    let cqe = &*cqes.add((current & mask) as usize);
+                    if let Some(outcome) = outcomes.get_mut(cqe.user_data as usize) {
+                        *outcome = Outcome::Completed(cqe.res);
+                    }
+                    current = current.wrapping_add(1);
+                    reaped += 1;
+                }
+                head.store(current, Ordering::Release);
+                reaped
+            }
+        }
+
+        fn enter(&self, to_submit: u32, min_complete: u32) -> io::Result<u32> {
+            let ret = unsafe {
+                libc::syscall(
+                    libc::SYS_io_uring_enter,
+                    self.fd.as_raw_fd(),
+                    to_submit,
+                    min_complete,
+                    IORING_ENTER_GETEVENTS,
+                    ptr::null::<libc::sigset_t>(),
+                    0usize,
+                )
+            };
+            if ret < 0 {
+                Err(io::Error::last_os_error())
+            } else {
+                Ok(ret as u32)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lycrex::logger::Level;
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_uring_writer_reports_errors_to_metrics() {
+        // /dev/full 上的写入总是以 ENOSPC 失败
+        let metrics = Arc::new(LoggerMetrics::new());
+        let writer = UringFileWriter::new("/dev/full").unwrap().with_metrics(metrics.clone());
+        writer.write(&LogRecord::new(Level::info(), "uring", "lost"));
+        let error = writer.flush().unwrap_err();
+        assert_eq!(error.raw_os_error(), Some(libc::ENOSPC));
+        assert!(metrics.errors.load(std::sync::atomic::Ordering::Relaxed) >= 1);
+    }
+
+    #[test]
+    fn test_uring_writer_preserves_order() {
+        let dir = std::env::temp_dir().join(format!("lycrex_uring_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("uring.log");
+
+        let writer = UringFileWriter::with_options(&path, Box::new(super::super::JsonFormatter::new()), false).unwrap();
+        for i in 0..2000 {
+            writer.write(&LogRecord::new(Level::info(), "uring", &format!("message {i}")));
+        }
+        writer.flush().unwrap();
+        drop(writer);
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 2000);
+        for (i, line) in lines.iter().enumerate() {
+            assert!(line.contains(&format!("\"message {i}\"")), "line {i} out of order: {line}");
+        }
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_ring_failed_link_is_drained_and_appends_are_kept() {
+        use std::io::Write;
+        use std::os::unix::io::AsRawFd;
+
+        let Ok(mut ring) = ring::Ring::new(QUEUE_DEPTH) else { return };
+        let dir = std::env::temp_dir().join(format!("lycrex_uring_link_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("ring.log");
+        let file = OpenOptions::new().append(true).create(true).open(&path).unwrap();
+
+        // 第一个请求失败后，链上其余请求被取消，且全部完成事件都被回收
+        let mut chunks = vec![b"a".to_vec(), b"b".to_vec(), b"c".to_vec()];
+        let outcomes = ring.write_batch(-1, &mut chunks).unwrap();
+        let cancelled = ring::Outcome::Completed(-libc::ECANCELED);
+        assert_eq!(outcomes, vec![ring::Outcome::Completed(-libc::EBADF), cancelled, cancelled]);
+        assert!(!ring.is_broken());
+
+        // 其他写入者追加的内容不会被覆盖
+        let mut chunks = vec![b"one\n".to_vec(), b"two\n".to_vec()];
+        let outcomes = ring.write_batch(file.as_raw_fd(), &mut chunks).unwrap();
+        assert_eq!(outcomes, vec![ring::Outcome::Completed(4); 2]);
+        OpenOptions::new().append(true).open(&path).unwrap().write_all(b"other\n").unwrap();
+        let mut ring = Some(ring);
+        write_chunks(&mut ring, &file, &mut [b"three\n".to_vec()]).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "one\ntwo\nother\nthree\n");
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+}
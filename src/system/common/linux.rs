@@ -0,0 +1,68 @@
+// Linux /proc 访问辅助
+// 以下功能依赖 /proc 文件系统：
+// - 进程内存区域枚举（/proc/<pid>/maps）与内存读写（/proc/<pid>/mem）
+// - 进程环境变量、打开的文件、网络连接等基于 /proc 的查询
+// 在受限沙箱中 /proc 可能未挂载，或以 hidepid 方式挂载导致无法查看其他用户的进程，
+// 这些情况统一转换为 NotSupported / PermissionDenied，而不是直接抛出底层 IO 错误
+
+use std::io::ErrorKind;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use crate::system::common::error::{SystemError, SystemResult};
+
+/// 检查 /proc 是否可用
+pub fn proc_available() -> bool {
+    Path::new("/proc/self/stat").exists()
+}
+
+/// 读取 /proc 下的文件，例如 `read_proc_file("1234/maps")`
+pub fn read_proc_file(relative: &str) -> SystemResult<String> {
+    let path = format!("/proc/{}", relative.trim_start_matches('/'));
+    std::fs::read_to_string(&path).map_err(|e| proc_error(&path, e))
+}
+
+/// 将访问 /proc 时的 IO 错误转换为更明确的系统错误
+pub fn proc_error(path: &str, err: std::io::Error) -> SystemError {
+    if !proc_available() {
+        return SystemError::NotSupported("/proc not accessible".to_string());
+    }
+    match err.kind() {
+        ErrorKind::PermissionDenied => SystemError::PermissionDenied(format!(
+            "{}: access denied (insufficient privileges or /proc mounted with hidepid)", path
+        )),
+        ErrorKind::NotFound => SystemError::NotFound(format!("{}: no such process or file", path)),
+        _ => SystemError::Io(format!("{}: {}", path, err)),
+    }
+}
+
+// systemctl 调用辅助，服务管理和启动项管理共用
+
+/// 运行 systemctl，非零退出时根据错误输出映射为对应的系统错误
+pub fn systemctl(args: &[&str], context: &str) -> SystemResult<Output> {
+    let output = Command::new("systemctl").args(args).output().map_err(|e| match e.kind() {
+        ErrorKind::NotFound => SystemError::NotSupported("systemctl not available".to_string()),
+        _ => SystemError::ProcessError(format!("Failed to execute systemctl: {}", e)),
+    })?;
+
+    if output.status.success() {
+        return Ok(output);
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    Err(systemctl_error(context, stderr.trim()))
+}
+
+/// 根据 systemctl 的错误输出区分权限不足、单元不存在和其他错误
+pub fn systemctl_error(context: &str, stderr: &str) -> SystemError {
+    let lower = stderr.to_lowercase();
+    if lower.contains("access denied")
+        || lower.contains("permission denied")
+        || lower.contains("interactive authentication required")
+    {
+        SystemError::PermissionDenied(format!("{}: {}", context, stderr))
+    } else if lower.contains("not found") || lower.contains("not be found") || lower.contains("no such file") {
+        SystemError::NotFound(format!("Unit not found: {}", context))
+    } else {
+        SystemError::ProcessError(format!("systemctl {} failed: {}", context, stderr))
+    }
+}
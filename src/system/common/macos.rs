@@ -0,0 +1,155 @@
+// macOS launchd 辅助
+// 启动项管理和任务调度都通过 LaunchAgent plist 实现，plist 的生成与加载统一放在这里
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::system::common::error::{SystemError, SystemResult};
+
+/// StartCalendarInterval 条目，未设置的字段表示“任意”
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct CalendarInterval {
+    pub minute: Option<u8>,
+    pub hour: Option<u8>,
+    pub day: Option<u8>,
+    pub weekday: Option<u8>,
+    pub month: Option<u8>,
+}
+
+/// plist 文件内容结构
+#[derive(Debug, Default)]
+pub(crate) struct LaunchPlist {
+    pub label: String,
+    pub program: String,
+    pub program_arguments: Vec<String>,
+    pub run_at_load: bool,
+    pub keep_alive: bool,
+    pub working_directory: Option<String>,
+    pub environment_variables: Option<HashMap<String, String>>,
+    pub start_interval: Option<u32>,
+    pub start_calendar_interval: Option<CalendarInterval>,
+}
+
+impl LaunchPlist {
+    pub fn to_plist_xml(&self) -> String {
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n");
+        xml.push_str("<plist version=\"1.0\">\n<dict>\n");
+
+        // Label
+        xml.push_str(&format!("\t<key>Label</key>\n\t<string>{}</string>\n", escape(&self.label)));
+
+        // Program
+        xml.push_str(&format!("\t<key>Program</key>\n\t<string>{}</string>\n", escape(&self.program)));
+
+        // ProgramArguments
+        if !self.program_arguments.is_empty() {
+            xml.push_str("\t<key>ProgramArguments</key>\n\t<array>\n");
+            xml.push_str(&format!("\t\t<string>{}</string>\n", escape(&self.program)));
+            for arg in &self.program_arguments {
+                xml.push_str(&format!("\t\t<string>{}</string>\n", escape(arg)));
+            }
+            xml.push_str("\t</array>\n");
+        }
+
+        // RunAtLoad
+        if self.run_at_load {
+            xml.push_str("\t<key>RunAtLoad</key>\n\t<true/>\n");
+        }
+
+        // KeepAlive
+        if self.keep_alive {
+            xml.push_str("\t<key>KeepAlive</key>\n\t<true/>\n");
+        }
+
+        // WorkingDirectory
+        if let Some(ref wd) = self.working_directory {
+            xml.push_str(&format!("\t<key>WorkingDirectory</key>\n\t<string>{}</string>\n", escape(wd)));
+        }
+
+        // EnvironmentVariables
+        if let Some(ref env_vars) = self.environment_variables {
+            if !env_vars.is_empty() {
+                xml.push_str("\t<key>EnvironmentVariables</key>\n\t<dict>\n");
+                for (key, value) in env_vars {
+                    xml.push_str(&format!("\t\t<key>{}</key>\n\t\t<string>{}</string>\n", escape(key), escape(value)));
+                }
+                xml.push_str("\t</dict>\n");
+            }
+        }
+
+        // StartInterval
+        if let Some(interval) = self.start_interval {
+            xml.push_str(&format!("\t<key>StartInterval</key>\n\t<integer>{}</integer>\n", interval));
+        }
+
+        // StartCalendarInterval
+        if let Some(ref calendar) = self.start_calendar_interval {
+            xml.push_str("\t<key>StartCalendarInterval</key>\n\t<dict>\n");
+            let fields = [
+                ("Month", calendar.month),
+                ("Day", calendar.day),
+                ("Weekday", calendar.weekday),
+                ("Hour", calendar.hour),
+                ("Minute", calendar.minute),
+            ];
+            for (key, value) in fields {
+                let Some(value) = value else { continue };
+                xml.push_str(&format!("\t\t<key>{}</key>\n\t\t<integer>{}</integer>\n", key, value));
+            }
+            xml.push_str("\t</dict>\n");
+        }
+
+        xml.push_str("</dict>\n</plist>\n");
+        xml
+    }
+}
+
+/// 转义 XML 特殊字符
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// 获取用户 LaunchAgents 目录，不存在时自动创建
+pub(crate) fn user_launch_agents_dir() -> SystemResult<PathBuf> {
+    let home_dir = std::env::var("HOME")
+        .map_err(|_| SystemError::Configuration("Cannot get user home directory".to_string()))?;
+    let launch_agents_dir = Path::new(&home_dir).join("Library/LaunchAgents");
+
+    // 确保目录存在
+    if !launch_agents_dir.exists() {
+        fs::create_dir_all(&launch_agents_dir)?;
+    }
+
+    Ok(launch_agents_dir)
+}
+
+/// 通过 launchctl 加载 plist
+pub(crate) fn launchctl_load(plist_path: &Path) -> SystemResult<()> {
+    let output = Command::new("launchctl")
+        .args(["load", plist_path.to_string_lossy().as_ref()])
+        .output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(SystemError::SystemCall(
+            format!("Failed to load {}: {}", plist_path.display(), stderr.trim()),
+            output.status.code(),
+        ));
+    }
+    Ok(())
+}
+
+/// 卸载 plist，未加载时 launchctl 的错误会被忽略
+pub(crate) fn launchctl_unload(plist_path: &Path) {
+    let _ = Command::new("launchctl")
+        .args(["unload", plist_path.to_string_lossy().as_ref()])
+        .output();
+}
@@ -2,12 +2,16 @@
 pub mod types;
 pub mod error;
 pub mod utils;
+#[cfg(target_os = "linux")]
+pub mod linux;
+#[cfg(target_os = "macos")]
+pub mod macos;
 
 pub use types::{
-    OperatingSystem, PermissionStatus, ServiceStatus, ServiceStartType,
+    OperatingSystem, ContainerKind, PermissionStatus, ServiceStatus, ServiceStartType,
     ProcessInfo, ProcessStatus, FilePermissions, NetworkConnection,
     NetworkProtocol, ConnectionState, SystemPerformance, DiskUsage,
     NetworkInterface, EnvironmentVariables, ScheduledTask, TaskSchedule,
-    MemoryPermission, MemoryOperationResult, ProcessMemoryInfo, MemoryRegion
+    MemoryPermission, MemoryOperationResult, ProcessMemoryInfo, MemoryRegion, ModuleInfo, StrEncoding, AccessRights
 };
 pub use error::{SystemError, SystemResult}; 
\ No newline at end of file
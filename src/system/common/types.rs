@@ -1,3 +1,4 @@
+use crate::system::common::error::{SystemError, SystemResult};
 use std::collections::HashMap;
 use std::time::SystemTime;
 
@@ -21,6 +22,37 @@ impl OperatingSystem {
     }
 }
 
+/// 当前进程所在的容器运行时
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerKind {
+    /// 未检测到容器
+    None,
+    Docker,
+    Podman,
+    Kubernetes,
+    Lxc,
+    SystemdNspawn,
+}
+
+impl ContainerKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ContainerKind::None => "none",
+            ContainerKind::Docker => "docker",
+            ContainerKind::Podman => "podman",
+            ContainerKind::Kubernetes => "kubernetes",
+            ContainerKind::Lxc => "lxc",
+            ContainerKind::SystemdNspawn => "systemd-nspawn",
+        }
+    }
+}
+
+impl std::fmt::Display for ContainerKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// 权限状态
 #[derive(Debug, Clone, PartialEq)]
 pub enum PermissionStatus {
@@ -71,11 +103,18 @@ pub struct ProcessInfo {
 
 /// 进程状态
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ProcessStatus {
     Running,
     Sleeping,
+    /// 不可中断睡眠（通常在等待磁盘 I/O）
+    DiskSleep,
+    Idle,
     Stopped,
+    /// 被调试器跟踪而暂停
+    Tracing,
     Zombie,
+    Dead,
     Unknown,
 }
 
@@ -201,15 +240,205 @@ pub enum TaskSchedule {
 }
 
 /// 内存读写权限
-#[derive(Debug, Clone, PartialEq)]
-pub enum MemoryPermission {
-    Read,
-    Write,
-    Execute,
-    ReadWrite,
-    ReadExecute,
-    WriteExecute,
-    ReadWriteExecute,
+/// 以位标志表示，可通过 `|`、`&`、`-` 组合，也可以从 `"r-x"` 形式的字符串解析
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct MemoryPermission(u8);
+
+#[allow(non_upper_case_globals)]
+impl MemoryPermission {
+    pub const NONE: Self = Self(0);
+    pub const READ: Self = Self(0b001);
+    pub const WRITE: Self = Self(0b010);
+    pub const EXECUTE: Self = Self(0b100);
+
+    // 兼容旧的枚举写法
+    pub const NoAccess: Self = Self::NONE;
+    pub const Read: Self = Self::READ;
+    pub const Write: Self = Self::WRITE;
+    pub const Execute: Self = Self::EXECUTE;
+    pub const ReadWrite: Self = Self(0b011);
+    pub const ReadExecute: Self = Self(0b101);
+    pub const WriteExecute: Self = Self(0b110);
+    pub const ReadWriteExecute: Self = Self(0b111);
+
+    const ALL_BITS: u8 = 0b111;
+
+    pub const fn empty() -> Self {
+        Self::NONE
+    }
+
+    pub const fn all() -> Self {
+        Self(Self::ALL_BITS)
+    }
+
+    pub const fn bits(&self) -> u8 {
+        self.0
+    }
+
+    /// 从位值构造，包含未定义的位时返回 None
+    pub const fn from_bits(bits: u8) -> Option<Self> {
+        if bits & !Self::ALL_BITS == 0 {
+            Some(Self(bits))
+        } else {
+            None
+        }
+    }
+
+    /// 从位值构造，忽略未定义的位
+    pub const fn from_bits_truncate(bits: u8) -> Self {
+        Self(bits & Self::ALL_BITS)
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub const fn contains(&self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn insert(&mut self, other: Self) {
+        self.0 |= other.0;
+    }
+
+    pub fn remove(&mut self, other: Self) {
+        self.0 &= !other.0;
+    }
+
+    /// 根据读/写/执行标志构造权限
+    pub fn from_flags(read: bool, write: bool, execute: bool) -> Self {
+        let mut permission = Self::NONE;
+        if read {
+            permission.insert(Self::READ);
+        }
+        if write {
+            permission.insert(Self::WRITE);
+        }
+        if execute {
+            permission.insert(Self::EXECUTE);
+        }
+        permission
+    }
+
+    /// 解析 `"rwx"` 形式的字符串，每一位为对应字母或 `-`
+    /// 也接受 /proc/<pid>/maps 中带共享标志的四位形式（如 `"r-xp"`）
+    pub fn from_rwx_str(s: &str) -> SystemResult<Self> {
+        let bytes = s.as_bytes();
+        let valid_suffix = match bytes.len() {
+            3 => true,
+            4 => matches!(bytes[3], b'p' | b's' | b'-'),
+            _ => false,
+        };
+        if !valid_suffix {
+            return Err(SystemError::Parse(format!("Invalid permission string: {:?}", s)));
+        }
+
+        let mut permission = Self::NONE;
+        for (byte, (letter, flag)) in bytes
+            .iter()
+            .zip([(b'r', Self::READ), (b'w', Self::WRITE), (b'x', Self::EXECUTE)])
+        {
+            match *byte {
+                b'-' => {}
+                b if b.to_ascii_lowercase() == letter => permission.insert(flag),
+                _ => return Err(SystemError::Parse(format!("Invalid permission string: {:?}", s))),
+            }
+        }
+        Ok(permission)
+    }
+
+    /// 转换为 `"rwx"` 形式的字符串
+    pub fn to_rwx_str(&self) -> String {
+        let mut s = String::with_capacity(3);
+        s.push(if self.is_readable() { 'r' } else { '-' });
+        s.push(if self.is_writable() { 'w' } else { '-' });
+        s.push(if self.is_executable() { 'x' } else { '-' });
+        s
+    }
+
+    pub fn is_readable(&self) -> bool {
+        self.contains(Self::READ)
+    }
+
+    pub fn is_writable(&self) -> bool {
+        self.contains(Self::WRITE)
+    }
+
+    pub fn is_executable(&self) -> bool {
+        self.contains(Self::EXECUTE)
+    }
+}
+
+impl std::fmt::Debug for MemoryPermission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "MemoryPermission({})", self.to_rwx_str())
+    }
+}
+
+impl std::fmt::Display for MemoryPermission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_rwx_str())
+    }
+}
+
+impl std::str::FromStr for MemoryPermission {
+    type Err = SystemError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_rwx_str(s)
+    }
+}
+
+impl std::ops::BitOr for MemoryPermission {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for MemoryPermission {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl std::ops::BitAnd for MemoryPermission {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        Self(self.0 & rhs.0)
+    }
+}
+
+impl std::ops::BitAndAssign for MemoryPermission {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+
+impl std::ops::BitXor for MemoryPermission {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        Self(self.0 ^ rhs.0)
+    }
+}
+
+impl std::ops::Sub for MemoryPermission {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 & !rhs.0)
+    }
+}
+
+impl std::ops::Not for MemoryPermission {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        Self(!self.0 & Self::ALL_BITS)
+    }
 }
 
 /// 内存操作结果
@@ -242,4 +471,74 @@ pub struct MemoryRegion {
     pub is_committed: bool,
     pub is_private: bool,
     pub module_name: Option<String>,
-} 
\ No newline at end of file
+}
+
+/// 进程中已加载的模块（可执行文件、DLL、dylib、共享库）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleInfo {
+    /// 文件名，如 `GameAssembly.dll`
+    pub name: String,
+    /// 完整路径，无法获取时为 None
+    pub path: Option<String>,
+    pub base_address: usize,
+    pub size: usize,
+}
+
+/// 打开进程时请求的访问权限
+/// 仅在 Windows 上影响 OpenProcess 的访问标志，其他平台忽略
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AccessRights {
+    /// 只读：可读取内存和查询区域信息
+    ReadOnly,
+    /// 读写：额外允许写入内存和修改保护属性（默认，与旧版本行为一致）
+    #[default]
+    ReadWrite,
+    /// 全部权限
+    Full,
+}
+
+impl AccessRights {
+    /// 是否允许写入内存
+    pub fn can_write(&self) -> bool {
+        !matches!(self, AccessRights::ReadOnly)
+    }
+}
+
+/// 在进程内存中搜索字符串时使用的编码
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StrEncoding {
+    Utf8,
+    /// UTF-16 小端（Windows 宽字符串）
+    Utf16Le,
+    /// 仅 ASCII 字符
+    Ascii,
+}
+
+impl StrEncoding {
+    /// 将字符串编码为内存中的字节序列，Ascii 编码遇到非 ASCII 字符时返回 None
+    pub fn encode(&self, text: &str) -> Option<Vec<u8>> {
+        match self {
+            StrEncoding::Utf8 => Some(text.as_bytes().to_vec()),
+            StrEncoding::Utf16Le => Some(text.encode_utf16().flat_map(|unit| unit.to_le_bytes()).collect()),
+            StrEncoding::Ascii => text.is_ascii().then(|| text.as_bytes().to_vec()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_memory_permission_rwx() {
+        let permission: MemoryPermission = "r-x".parse().unwrap();
+        assert_eq!(permission, MemoryPermission::READ | MemoryPermission::EXECUTE);
+        assert_eq!(permission, MemoryPermission::ReadExecute);
+        assert_eq!(permission.to_string(), "r-x");
+        assert_eq!(MemoryPermission::from_rwx_str("rw-p").unwrap(), MemoryPermission::ReadWrite);
+        assert_eq!((MemoryPermission::all() - MemoryPermission::WRITE).to_rwx_str(), "r-x");
+        assert_eq!((!MemoryPermission::READ).to_rwx_str(), "-wx");
+        assert!("rwxx".parse::<MemoryPermission>().is_err());
+        assert!("x--".parse::<MemoryPermission>().is_err());
+    }
+}
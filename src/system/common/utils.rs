@@ -1,5 +1,5 @@
 use crate::system::common::error::{SystemResult, SystemError};
-use crate::system::common::types::{OperatingSystem, PermissionStatus};
+use crate::system::common::types::{ContainerKind, OperatingSystem, PermissionStatus};
 use std::process::Command;
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -215,6 +215,104 @@ impl SystemUtils {
             PermissionStatus::RequiresElevation
         }
     }
+
+    /// 检测当前进程运行在哪种容器中
+    /// 依次检查环境变量、`/run/.containerenv`、`/.dockerenv`、cgroup 路径和挂载信息；非 Linux 平台总是返回 `None`
+    pub fn container_runtime() -> ContainerKind {
+        #[cfg(target_os = "linux")]
+        {
+            let proc_text = |path: &str| std::fs::read_to_string(path).unwrap_or_default();
+            let cgroups = [proc_text("/proc/1/cgroup"), proc_text("/proc/self/cgroup")].join("\n");
+            detect_container(
+                |name| std::env::var(name).ok(),
+                |path| std::path::Path::new(path).exists(),
+                &cgroups,
+                &proc_text("/proc/self/mountinfo"),
+            )
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        ContainerKind::None
+    }
+
+    /// 是否运行在容器中
+    pub fn is_containerized() -> bool {
+        Self::container_runtime() != ContainerKind::None
+    }
+}
+
+/// 根据环境变量、标记文件、cgroup 和挂载信息判断容器类型
+/// Kubernetes 优先于底层运行时（Pod 中的容器通常同时具有 docker/containerd 的特征）
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+fn detect_container(
+    env: impl Fn(&str) -> Option<String>,
+    exists: impl Fn(&str) -> bool,
+    cgroups: &str,
+    mountinfo: &str,
+) -> ContainerKind {
+    if env("KUBERNETES_SERVICE_HOST").is_some() || cgroups.contains("kubepods") {
+        return ContainerKind::Kubernetes;
+    }
+
+    // systemd-nspawn、podman、lxc 会为容器内的 init 设置 container 环境变量
+    match env("container").as_deref() {
+        Some("systemd-nspawn") => return ContainerKind::SystemdNspawn,
+        Some("podman") => return ContainerKind::Podman,
+        Some("lxc") | Some("lxc-libvirt") => return ContainerKind::Lxc,
+        Some("docker") => return ContainerKind::Docker,
+        _ => {}
+    }
+
+    if exists("/run/.containerenv") {
+        return ContainerKind::Podman;
+    }
+    if exists("/.dockerenv") {
+        return ContainerKind::Docker;
+    }
+
+    if cgroups.contains("libpod") {
+        return ContainerKind::Podman;
+    }
+    if has_docker_cgroup(cgroups) {
+        return ContainerKind::Docker;
+    }
+    if cgroups.contains("/lxc") || cgroups.contains(".lxc") {
+        return ContainerKind::Lxc;
+    }
+    if cgroups.contains("/machine.slice/machine-") {
+        return ContainerKind::SystemdNspawn;
+    }
+
+    // cgroup v2 的命名空间内只能看到 "0::/"，再借助挂载信息判断
+    // 只看根文件系统以及运行时注入的 /etc/hostname、/etc/resolv.conf，宿主机上任意的 bind mount 不应被误判
+    for line in mountinfo.lines() {
+        let mut fields = line.split_whitespace();
+        let mount_point = fields.nth(4).unwrap_or_default();
+        if !matches!(mount_point, "/" | "/etc/hostname" | "/etc/resolv.conf") {
+            continue;
+        }
+        if line.contains("/docker/containers/") || line.contains("/docker/overlay2/") {
+            return ContainerKind::Docker;
+        }
+        if line.contains("/containers/storage/") {
+            return ContainerKind::Podman;
+        }
+    }
+
+    ContainerKind::None
+}
+
+/// cgroup 路径中是否包含 Docker 容器 ID（`/docker/<64 位十六进制>` 或 `docker-<64 位十六进制>.scope`）
+/// 宿主机上的 `docker.service` 等单元不算
+fn has_docker_cgroup(cgroups: &str) -> bool {
+    ["/docker/", "/docker-"].iter().any(|prefix| {
+        cgroups.match_indices(prefix).any(|(index, _)| {
+            let id = &cgroups[index + prefix.len()..];
+            id.len() >= 64
+                && id.bytes().take(64).all(|b| b.is_ascii_hexdigit())
+                && !id.as_bytes().get(64).is_some_and(u8::is_ascii_hexdigit)
+        })
+    })
 }
 
 /// 字符串工具
@@ -247,4 +345,50 @@ impl StringUtils {
             })
             .collect()
     }
-} 
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_container() {
+        let no_env = |_: &str| None;
+        let no_files = |_: &str| false;
+
+        assert_eq!(detect_container(no_env, no_files, "0::/user.slice/user-1000.slice", ""), ContainerKind::None);
+        assert_eq!(detect_container(no_env, |path| path == "/.dockerenv", "0::/", ""), ContainerKind::Docker);
+        assert_eq!(detect_container(no_env, no_files, "12:memory:/docker/3f2a9c", ""), ContainerKind::None);
+        let container_id = "3f2a9c".repeat(10) + "0123";
+        assert_eq!(
+            detect_container(no_env, no_files, &format!("12:memory:/docker/{container_id}"), ""),
+            ContainerKind::Docker,
+        );
+        assert_eq!(
+            detect_container(no_env, no_files, &format!("0::/system.slice/docker-{container_id}.scope"), ""),
+            ContainerKind::Docker,
+        );
+        // 宿主机上的 docker 守护进程本身不在容器中
+        assert_eq!(detect_container(no_env, no_files, "0::/system.slice/docker.service", ""), ContainerKind::None);
+
+        let root_mount = "1405 1287 0:123 / / rw,relatime master:1 - overlay overlay rw,lowerdir=/var/lib/docker/overlay2/l/ABC";
+        assert_eq!(detect_container(no_env, no_files, "0::/", root_mount), ContainerKind::Docker);
+        let hostname_mount = format!(
+            "1420 1405 259:2 /var/lib/containers/storage/overlay-containers/{container_id}/userdata/hostname /etc/hostname rw - ext4 /dev/nvme0n1p2 rw"
+        );
+        assert_eq!(detect_container(no_env, no_files, "0::/", &hostname_mount), ContainerKind::Podman);
+        // 宿主机上把容器目录 bind mount 到别处不应被当成容器
+        let host_bind = "612 29 259:2 /var/lib/docker/containers /mnt/inspect rw,relatime - ext4 /dev/nvme0n1p2 rw";
+        assert_eq!(detect_container(no_env, no_files, "0::/", host_bind), ContainerKind::None);
+        assert_eq!(
+            detect_container(no_env, |path| path == "/.dockerenv", "0::/kubepods/burstable/pod1234", ""),
+            ContainerKind::Kubernetes,
+        );
+        assert_eq!(
+            detect_container(|name| (name == "container").then(|| "systemd-nspawn".to_string()), no_files, "0::/", ""),
+            ContainerKind::SystemdNspawn,
+        );
+        assert_eq!(detect_container(no_env, no_files, "0::/machine.slice/libpod-abc.scope", ""), ContainerKind::Podman);
+        assert_eq!(detect_container(no_env, no_files, "0::/lxc.payload.web", ""), ContainerKind::Lxc);
+    }
+}
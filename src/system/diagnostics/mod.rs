@@ -0,0 +1,237 @@
+// 诊断包模块
+// 将分散的系统信息收集接口打包成单个 zip 文件，便于随工单提交
+
+mod zip;
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+#[cfg(feature = "log-redaction")]
+use crate::lycrex::logger::RedactionMiddleware;
+use crate::system::common::*;
+use crate::system::sysinfo::SystemInfoManager;
+use zip::ZipWriter;
+
+/// 诊断包收集器
+pub struct DiagnosticBundle;
+
+impl DiagnosticBundle {
+    /// 收集系统信息并写入 zip 文件
+    /// 单项信息收集失败不会中断整体流程，失败原因记录在 errors.txt 中。
+    /// snapshot.json 需要开启 `serde` feature，environment.txt 需要开启 `log-redaction` feature
+    /// （脱敏规则来自 [`RedactionMiddleware`](crate::lycrex::logger::RedactionMiddleware)），未开启时同样记录在 errors.txt 中
+    pub fn write_to(path: &Path) -> SystemResult<()> {
+        let manager = SystemInfoManager::new();
+        manager.refresh();
+
+        let mut files: Vec<(&str, Vec<u8>)> = Vec::new();
+        let mut errors = String::new();
+
+        let sections: [(&str, SystemResult<String>); 5] = [
+            ("snapshot.json", Self::snapshot_json(&manager)),
+            ("processes.csv", Self::processes_csv(&manager)),
+            ("disks.csv", Self::disks_csv(&manager)),
+            ("networks.csv", Self::networks_csv(&manager)),
+            ("environment.txt", Self::environment_text(std::env::vars_os())),
+        ];
+        for (name, content) in sections {
+            match content {
+                Ok(content) => files.push((name, content.into_bytes())),
+                Err(e) => {
+                    let _ = writeln!(errors, "{name}: {e}");
+                }
+            }
+        }
+
+        #[cfg(target_os = "linux")]
+        for (source, name) in [
+            ("cpuinfo", "proc/cpuinfo"),
+            ("meminfo", "proc/meminfo"),
+            ("self/mountinfo", "proc/mountinfo"),
+        ] {
+            match crate::system::common::linux::read_proc_file(source) {
+                Ok(content) => files.push((name, content.into_bytes())),
+                Err(e) => {
+                    let _ = writeln!(errors, "{name}: {e}");
+                }
+            }
+        }
+
+        if !errors.is_empty() {
+            files.push(("errors.txt", errors.into_bytes()));
+        }
+
+        let mut zip = ZipWriter::create(path)?;
+        for (name, content) in &files {
+            zip.add_file(name, content)?;
+        }
+        zip.finish()?;
+        Ok(())
+    }
+
+    /// 系统快照（JSON），与 [`SystemSnapshot::to_json`](crate::system::sysinfo::SystemSnapshot::to_json) 的输出一致
+    #[cfg(feature = "serde")]
+    fn snapshot_json(manager: &SystemInfoManager) -> SystemResult<String> {
+        manager.snapshot()?.to_json()
+    }
+
+    #[cfg(not(feature = "serde"))]
+    fn snapshot_json(_manager: &SystemInfoManager) -> SystemResult<String> {
+        Err(SystemError::NotSupported("snapshot.json requires the `serde` feature".to_string()))
+    }
+
+    fn processes_csv(manager: &SystemInfoManager) -> SystemResult<String> {
+        let mut processes = manager.get_processes()?;
+        processes.sort_by_key(|process| process.pid);
+
+        let mut csv = String::from("pid,parent_pid,name,memory_usage,virtual_memory,cpu_usage,run_time,user,executable_path\n");
+        for process in processes {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{:.2},{},{},{}",
+                process.pid,
+                process.parent_pid.map(|pid| pid.to_string()).unwrap_or_default(),
+                Self::csv_field(&process.name),
+                process.memory_usage.unwrap_or(0),
+                process.virtual_memory.unwrap_or(0),
+                process.cpu_usage.unwrap_or(0.0),
+                process.run_time,
+                Self::csv_field(process.user.as_deref().unwrap_or("")),
+                Self::csv_field(process.executable_path.as_deref().unwrap_or("")),
+            );
+        }
+        Ok(csv)
+    }
+
+    fn disks_csv(manager: &SystemInfoManager) -> SystemResult<String> {
+        let mut csv = String::from("name,mount_point,filesystem,disk_type,total_space,available_space,used_space,is_removable\n");
+        for disk in manager.get_disk_info()? {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{}",
+                Self::csv_field(&disk.name),
+                Self::csv_field(&disk.mount_point),
+                Self::csv_field(&disk.filesystem),
+                Self::csv_field(&disk.disk_type),
+                disk.total_space,
+                disk.available_space,
+                disk.used_space,
+                disk.is_removable,
+            );
+        }
+        Ok(csv)
+    }
+
+    fn networks_csv(manager: &SystemInfoManager) -> SystemResult<String> {
+        let mut csv = String::from(
+            "name,mac_address,bytes_received,bytes_transmitted,packets_received,packets_transmitted,errors_on_received,errors_on_transmitted\n",
+        );
+        for network in manager.get_network_info()? {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{},{},{},{}",
+                Self::csv_field(&network.name),
+                network.mac_address,
+                network.bytes_received,
+                network.bytes_transmitted,
+                network.packets_received,
+                network.packets_transmitted,
+                network.errors_on_received,
+                network.errors_on_transmitted,
+            );
+        }
+        Ok(csv)
+    }
+
+    /// 环境变量（敏感值已脱敏）
+    /// 每行 `KEY=VALUE` 依次经过连接串密码、敏感键名以及 [`RedactionMiddleware::with_presets`] 的规则
+    #[cfg(feature = "log-redaction")]
+    fn environment_text(vars: impl Iterator<Item = (std::ffi::OsString, std::ffi::OsString)>) -> SystemResult<String> {
+        let redaction = RedactionMiddleware::new(vec![
+            RedactionMiddleware::url_credentials(),
+            RedactionMiddleware::secret_assignment(),
+            RedactionMiddleware::credit_card(),
+            RedactionMiddleware::email(),
+            RedactionMiddleware::bearer_token(),
+        ]);
+
+        let mut lines: Vec<String> = vars
+            .map(|(key, value)| format!("{}={}", key.to_string_lossy(), value.to_string_lossy()))
+            .collect();
+        lines.sort();
+
+        let mut text = String::new();
+        for line in lines {
+            let _ = writeln!(text, "{}", redaction.redact(&line).unwrap_or(line));
+        }
+        Ok(text)
+    }
+
+    #[cfg(not(feature = "log-redaction"))]
+    fn environment_text(_vars: impl Iterator<Item = (std::ffi::OsString, std::ffi::OsString)>) -> SystemResult<String> {
+        Err(SystemError::NotSupported("environment.txt requires the `log-redaction` feature".to_string()))
+    }
+
+    fn csv_field(value: &str) -> String {
+        if value.contains([',', '"', '\n', '\r']) {
+            format!("\"{}\"", value.replace('"', "\"\""))
+        } else {
+            value.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundle_round_trip() {
+        let path = std::env::temp_dir().join(format!("lycrex_diagnostics_{}.zip", std::process::id()));
+        DiagnosticBundle::write_to(&path).unwrap();
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let entries = zip::tests::read_archive(&bytes);
+        let names: Vec<&str> = entries.iter().map(|(name, _)| name.as_str()).collect();
+        for name in ["processes.csv", "disks.csv", "networks.csv"] {
+            assert!(names.contains(&name), "{name} missing from {names:?}");
+        }
+        #[cfg(target_os = "linux")]
+        assert!(names.contains(&"proc/meminfo"));
+
+        let entry = |name: &str| {
+            entries.iter().find(|(entry, _)| entry == name).map(|(_, data)| String::from_utf8_lossy(data).to_string())
+        };
+        let own_pid = format!("{},", std::process::id());
+        assert!(entry("processes.csv").unwrap().lines().any(|line| line.starts_with(&own_pid)));
+        #[cfg(feature = "serde")]
+        {
+            let snapshot: serde_json::Value = serde_json::from_str(&entry("snapshot.json").unwrap()).unwrap();
+            assert!(snapshot["memory"]["total"].as_u64().unwrap() > 0);
+        }
+        #[cfg(not(feature = "serde"))]
+        assert!(entry("errors.txt").unwrap().contains("snapshot.json"));
+        #[cfg(not(feature = "log-redaction"))]
+        assert!(entry("errors.txt").unwrap().contains("environment.txt"));
+    }
+
+    #[cfg(feature = "log-redaction")]
+    #[test]
+    fn test_environment_redaction() {
+        let vars = [
+            ("PATH", "/usr/bin:/bin"),
+            ("GITHUB_TOKEN", "ghp_abcdef"),
+            ("DATABASE_URL", "postgres://app:s3cret@db:5432/app"),
+            ("MAINTAINER", "ops@example.com"),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.into(), value.into()));
+
+        let text = DiagnosticBundle::environment_text(vars).unwrap();
+        assert_eq!(
+            text.lines().collect::<Vec<_>>(),
+            ["DATABASE_URL=postgres://app:***@db:5432/app", "GITHUB_TOKEN=***", "MAINTAINER=***", "PATH=/usr/bin:/bin"]
+        );
+    }
+}
@@ -0,0 +1,198 @@
+// 最小化的 ZIP 写入器
+// 只支持不压缩（stored）方式，足以打包诊断文本文件且无需额外依赖
+
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use chrono::{Datelike, Local, Timelike};
+
+use crate::utils::checksum::crc32;
+
+/// 已写入条目的中央目录信息
+struct ZipEntry {
+    name: String,
+    crc32: u32,
+    size: u32,
+    offset: u32,
+}
+
+/// ZIP 归档写入器
+pub(crate) struct ZipWriter {
+    writer: BufWriter<File>,
+    entries: Vec<ZipEntry>,
+    offset: u32,
+    dos_time: u16,
+    dos_date: u16,
+}
+
+impl ZipWriter {
+    pub(crate) fn create(path: &Path) -> io::Result<Self> {
+        let now = Local::now();
+        let dos_time = ((now.hour() << 11) | (now.minute() << 5) | (now.second() / 2)) as u16;
+        let dos_date = (((now.year().max(1980) - 1980) as u32) << 9 | (now.month() << 5) | now.day()) as u16;
+
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+            entries: Vec::new(),
+            offset: 0,
+            dos_time,
+            dos_date,
+        })
+    }
+
+    /// 添加一个文件条目
+    pub(crate) fn add_file(&mut self, name: &str, data: &[u8]) -> io::Result<()> {
+        let size = u32::try_from(data.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "zip entry too large"))?;
+        let crc32 = crc32(0, data);
+        let name_bytes = name.as_bytes();
+
+        let mut header = Vec::with_capacity(30 + name_bytes.len());
+        header.extend_from_slice(&0x0403_4b50u32.to_le_bytes()); // 本地文件头签名
+        header.extend_from_slice(&20u16.to_le_bytes());          // 解压所需版本
+        header.extend_from_slice(&0x0800u16.to_le_bytes());      // 标志位：文件名使用 UTF-8
+        header.extend_from_slice(&0u16.to_le_bytes());           // 压缩方式：stored
+        header.extend_from_slice(&self.dos_time.to_le_bytes());
+        header.extend_from_slice(&self.dos_date.to_le_bytes());
+        header.extend_from_slice(&crc32.to_le_bytes());
+        header.extend_from_slice(&size.to_le_bytes());           // 压缩后大小
+        header.extend_from_slice(&size.to_le_bytes());           // 原始大小
+        header.extend_from_slice(&(name_bytes.len() as u16).to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes());           // 扩展字段长度
+        header.extend_from_slice(name_bytes);
+
+        self.writer.write_all(&header)?;
+        self.writer.write_all(data)?;
+
+        self.entries.push(ZipEntry {
+            name: name.to_string(),
+            crc32,
+            size,
+            offset: self.offset,
+        });
+        self.offset = self.offset
+            .checked_add(header.len() as u32 + size)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "zip archive too large"))?;
+
+        Ok(())
+    }
+
+    /// 写入中央目录并结束归档
+    pub(crate) fn finish(mut self) -> io::Result<()> {
+        let central_directory_offset = self.offset;
+        let mut central_directory_size = 0u32;
+
+        for entry in &self.entries {
+            let name_bytes = entry.name.as_bytes();
+            let mut header = Vec::with_capacity(46 + name_bytes.len());
+            header.extend_from_slice(&0x0201_4b50u32.to_le_bytes()); // 中央目录签名
+            header.extend_from_slice(&20u16.to_le_bytes());          // 创建版本
+            header.extend_from_slice(&20u16.to_le_bytes());          // 解压所需版本
+            header.extend_from_slice(&0x0800u16.to_le_bytes());
+            header.extend_from_slice(&0u16.to_le_bytes());
+            header.extend_from_slice(&self.dos_time.to_le_bytes());
+            header.extend_from_slice(&self.dos_date.to_le_bytes());
+            header.extend_from_slice(&entry.crc32.to_le_bytes());
+            header.extend_from_slice(&entry.size.to_le_bytes());
+            header.extend_from_slice(&entry.size.to_le_bytes());
+            header.extend_from_slice(&(name_bytes.len() as u16).to_le_bytes());
+            header.extend_from_slice(&0u16.to_le_bytes());           // 扩展字段长度
+            header.extend_from_slice(&0u16.to_le_bytes());           // 注释长度
+            header.extend_from_slice(&0u16.to_le_bytes());           // 起始磁盘号
+            header.extend_from_slice(&0u16.to_le_bytes());           // 内部属性
+            header.extend_from_slice(&0u32.to_le_bytes());           // 外部属性
+            header.extend_from_slice(&entry.offset.to_le_bytes());
+            header.extend_from_slice(name_bytes);
+
+            self.writer.write_all(&header)?;
+            central_directory_size += header.len() as u32;
+        }
+
+        let entry_count = self.entries.len() as u16;
+        let mut end = Vec::with_capacity(22);
+        end.extend_from_slice(&0x0605_4b50u32.to_le_bytes()); // 中央目录结束签名
+        end.extend_from_slice(&0u16.to_le_bytes());
+        end.extend_from_slice(&0u16.to_le_bytes());
+        end.extend_from_slice(&entry_count.to_le_bytes());
+        end.extend_from_slice(&entry_count.to_le_bytes());
+        end.extend_from_slice(&central_directory_size.to_le_bytes());
+        end.extend_from_slice(&central_directory_offset.to_le_bytes());
+        end.extend_from_slice(&0u16.to_le_bytes());           // 注释长度
+
+        self.writer.write_all(&end)?;
+        self.writer.flush()
+    }
+}
+
+#[cfg(test)]
+pub(super) mod tests {
+    use super::*;
+
+    fn u16_at(bytes: &[u8], at: usize) -> u16 {
+        u16::from_le_bytes([bytes[at], bytes[at + 1]])
+    }
+
+    fn u32_at(bytes: &[u8], at: usize) -> u32 {
+        u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+    }
+
+    /// 从中央目录出发解析归档，逐项核对本地文件头与中央目录一致、CRC 与内容匹配
+    pub(in crate::system::diagnostics) fn read_archive(bytes: &[u8]) -> Vec<(String, Vec<u8>)> {
+        let end = bytes.len() - 22;
+        assert_eq!(u32_at(bytes, end), 0x0605_4b50);
+        let count = u16_at(bytes, end + 10) as usize;
+        assert_eq!(u16_at(bytes, end + 8) as usize, count);
+        let directory_size = u32_at(bytes, end + 12) as usize;
+        let directory_offset = u32_at(bytes, end + 16) as usize;
+        assert_eq!(directory_offset + directory_size, end);
+
+        let mut entries = Vec::new();
+        let mut at = directory_offset;
+        for _ in 0..count {
+            assert_eq!(u32_at(bytes, at), 0x0201_4b50);
+            let crc = u32_at(bytes, at + 16);
+            let size = u32_at(bytes, at + 20) as usize;
+            let name_len = u16_at(bytes, at + 28) as usize;
+            let offset = u32_at(bytes, at + 42) as usize;
+            let name = String::from_utf8(bytes[at + 46..at + 46 + name_len].to_vec()).unwrap();
+            at += 46 + name_len;
+
+            assert_eq!(u32_at(bytes, offset), 0x0403_4b50);
+            assert_eq!(u16_at(bytes, offset + 8), 0, "entries are stored");
+            assert_eq!(u32_at(bytes, offset + 14), crc);
+            assert_eq!(u32_at(bytes, offset + 18) as usize, size);
+            assert_eq!(u32_at(bytes, offset + 22) as usize, size);
+            assert_eq!(u16_at(bytes, offset + 26) as usize, name_len);
+            assert_eq!(&bytes[offset + 30..offset + 30 + name_len], name.as_bytes());
+
+            let data_start = offset + 30 + name_len + u16_at(bytes, offset + 28) as usize;
+            let data = bytes[data_start..data_start + size].to_vec();
+            assert_eq!(crc32(0, &data), crc, "crc of {name}");
+            entries.push((name, data));
+        }
+        assert_eq!(at, end);
+        entries
+    }
+
+    #[test]
+    fn test_zip_round_trip() {
+        let path = std::env::temp_dir().join(format!("lycrex_zip_{}.zip", std::process::id()));
+        let mut zip = ZipWriter::create(&path).unwrap();
+        zip.add_file("a.txt", b"hello").unwrap();
+        zip.add_file("dir/empty", b"").unwrap();
+        zip.add_file("目录/b.csv", "x,y\n1,2\n".as_bytes()).unwrap();
+        zip.finish().unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let entries = read_archive(&bytes);
+        let expected: Vec<(String, Vec<u8>)> = vec![
+            ("a.txt".into(), b"hello".to_vec()),
+            ("dir/empty".into(), Vec::new()),
+            ("目录/b.csv".into(), b"x,y\n1,2\n".to_vec()),
+        ];
+        assert_eq!(entries, expected);
+        assert_eq!(crc32(0, b"hello"), 0x3610_a686);
+    }
+}
@@ -2,6 +2,12 @@
 
 use crate::system::common::*;
 
+// 文件变化监控
+#[cfg(feature = "fs-watch")]
+mod watch;
+#[cfg(feature = "fs-watch")]
+pub use watch::{FileEvent, FileWatcher};
+
 /// 文件系统工具
 pub struct FileSystemTools;
 
@@ -10,4 +16,11 @@ impl FileSystemTools {
     pub fn watch_file(&self, _path: &str) -> SystemResult<()> {
         Err(SystemError::NotSupported("Not implemented yet".to_string()))
     }
-} 
\ No newline at end of file
+
+    /// 监控路径的变化，事件通过返回的 FileWatcher 接收
+    /// Linux 上使用 inotify，macOS、BSD、Windows 上使用各自的原生接口（通过 notify），释放 FileWatcher 即停止监控
+    #[cfg(feature = "fs-watch")]
+    pub fn watch_path(&self, path: &str, recursive: bool) -> SystemResult<FileWatcher> {
+        FileWatcher::new(path, recursive)
+    }
+}
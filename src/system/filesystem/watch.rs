@@ -0,0 +1,439 @@
+// 文件变化监控
+// Linux 上直接使用 inotify，其他平台使用 notify crate（FSEvents、kqueue、ReadDirectoryChangesW）
+// 事件转换为 FileEvent 后通过 mpsc 通道发送，调用方不依赖底层事件类型
+
+use std::path::PathBuf;
+use std::sync::mpsc::Receiver;
+use std::time::Duration;
+
+use crate::system::common::*;
+
+/// 文件变化事件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileEvent {
+    Created(PathBuf),
+    Modified(PathBuf),
+    Removed(PathBuf),
+    Renamed { from: PathBuf, to: PathBuf },
+}
+
+impl FileEvent {
+    /// 事件涉及的路径（重命名时为新路径）
+    pub fn path(&self) -> &PathBuf {
+        match self {
+            FileEvent::Created(path) | FileEvent::Modified(path) | FileEvent::Removed(path) => path,
+            FileEvent::Renamed { to, .. } => to,
+        }
+    }
+}
+
+/// 文件监控句柄
+/// 释放时停止后台线程并关闭底层的监控句柄
+pub struct FileWatcher {
+    receiver: Receiver<FileEvent>,
+    backend: Option<Backend>,
+}
+
+#[cfg(target_os = "linux")]
+use linux::InotifyBackend as Backend;
+#[cfg(not(target_os = "linux"))]
+use portable::NotifyBackend as Backend;
+
+impl FileWatcher {
+    /// 开始监控路径，recursive 为 true 时同时监控所有子目录（包括之后新建的子目录）
+    pub fn new(path: &str, recursive: bool) -> SystemResult<Self> {
+        let (backend, receiver) = Backend::start(PathBuf::from(path), recursive)?;
+        Ok(Self { receiver, backend: Some(backend) })
+    }
+
+    /// 阻塞等待下一个事件，监控已停止时返回 None
+    pub fn recv(&self) -> Option<FileEvent> {
+        self.receiver.recv().ok()
+    }
+
+    /// 最多等待 timeout，超时或监控已停止时返回 None
+    pub fn recv_timeout(&self, timeout: Duration) -> Option<FileEvent> {
+        self.receiver.recv_timeout(timeout).ok()
+    }
+
+    /// 非阻塞地获取一个已到达的事件
+    pub fn try_recv(&self) -> Option<FileEvent> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// 底层事件通道
+    pub fn receiver(&self) -> &Receiver<FileEvent> {
+        &self.receiver
+    }
+
+    /// 阻塞迭代事件，直到监控停止
+    pub fn iter(&self) -> impl Iterator<Item = FileEvent> + '_ {
+        self.receiver.iter()
+    }
+}
+
+impl Drop for FileWatcher {
+    fn drop(&mut self) {
+        if let Some(backend) = self.backend.take() {
+            backend.stop();
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+mod linux {
+    use std::collections::HashMap;
+    use std::ffi::{CString, OsStr};
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::{Path, PathBuf};
+    use std::sync::mpsc::{self, Receiver, Sender};
+    use std::thread::JoinHandle;
+
+    use super::FileEvent;
+    use crate::system::common::*;
+
+    const WATCH_MASK: u32 = libc::IN_CREATE
+        | libc::IN_MODIFY
+        | libc::IN_ATTRIB
+        | libc::IN_DELETE
+        | libc::IN_DELETE_SELF
+        | libc::IN_MOVED_FROM
+        | libc::IN_MOVED_TO
+        | libc::IN_MOVE_SELF;
+
+    /// inotify 实例与后台读取线程
+    /// 描述符都由 OwnedFd 持有，任何一步失败（包括线程创建失败）时随所有者一起关闭
+    pub struct InotifyBackend {
+        // 写入一个字节唤醒后台线程使其退出
+        wake_fd: OwnedFd,
+        thread: Option<JoinHandle<()>>,
+    }
+
+    impl InotifyBackend {
+        pub fn start(root: PathBuf, recursive: bool) -> SystemResult<(Self, Receiver<FileEvent>)> {
+            if !root.exists() {
+                return Err(SystemError::NotFound(format!("Watch path not found: {}", root.display())));
+            }
+
+            let fd = unsafe { libc::inotify_init1(libc::IN_NONBLOCK | libc::IN_CLOEXEC) };
+            if fd < 0 {
+                return Err(std::io::Error::last_os_error().into());
+            }
+            let fd = unsafe { OwnedFd::from_raw_fd(fd) };
+
+            let mut pipe = [0 as libc::c_int; 2];
+            if unsafe { libc::pipe2(pipe.as_mut_ptr(), libc::O_NONBLOCK | libc::O_CLOEXEC) } != 0 {
+                return Err(std::io::Error::last_os_error().into());
+            }
+            let (wake_read, wake_write) = unsafe { (OwnedFd::from_raw_fd(pipe[0]), OwnedFd::from_raw_fd(pipe[1])) };
+
+            let mut watches = Watches { fd, recursive, paths: HashMap::new() };
+            watches.add_tree(&root)?;
+
+            // 线程创建失败时闭包被释放，其中的 inotify 描述符和管道读端随之关闭
+            let (sender, receiver) = mpsc::channel();
+            let thread = std::thread::Builder::new()
+                .name("lycrex-fs-watch".to_string())
+                .spawn(move || run(watches, wake_read, sender))
+                .map_err(|e| SystemError::Internal(format!("Failed to spawn watch thread: {}", e)))?;
+
+            Ok((Self { wake_fd: wake_write, thread: Some(thread) }, receiver))
+        }
+
+        pub fn stop(mut self) {
+            unsafe { libc::write(self.wake_fd.as_raw_fd(), [1u8].as_ptr().cast(), 1) };
+            if let Some(thread) = self.thread.take() {
+                let _ = thread.join();
+            }
+        }
+    }
+
+    /// watch 描述符与路径的对应关系
+    struct Watches {
+        fd: OwnedFd,
+        recursive: bool,
+        paths: HashMap<libc::c_int, PathBuf>,
+    }
+
+    impl Watches {
+        fn add(&mut self, path: &Path) -> SystemResult<()> {
+            let c_path = CString::new(path.as_os_str().as_bytes())
+                .map_err(|_| SystemError::InvalidArgument(format!("Path contains NUL byte: {}", path.display())))?;
+            let wd = unsafe { libc::inotify_add_watch(self.fd.as_raw_fd(), c_path.as_ptr(), WATCH_MASK) };
+            if wd < 0 {
+                return Err(std::io::Error::last_os_error().into());
+            }
+            self.paths.insert(wd, path.to_path_buf());
+            Ok(())
+        }
+
+        /// 添加路径；递归模式下同时添加所有子目录
+        fn add_tree(&mut self, path: &Path) -> SystemResult<()> {
+            self.add(path)?;
+            if self.recursive && path.is_dir() {
+                for entry in std::fs::read_dir(path)?.flatten() {
+                    // 不跟随符号链接，避免循环
+                    if entry.file_type().is_ok_and(|kind| kind.is_dir()) {
+                        // 遍历期间被删除的子目录直接忽略
+                        let _ = self.add_tree(&entry.path());
+                    }
+                }
+            }
+            Ok(())
+        }
+    }
+
+    fn run(mut watches: Watches, wake_fd: OwnedFd, sender: Sender<FileEvent>) {
+        let mut buffer = vec![0u8; 64 * 1024];
+
+        loop {
+            let mut fds = [
+                libc::pollfd { fd: watches.fd.as_raw_fd(), events: libc::POLLIN, revents: 0 },
+                libc::pollfd { fd: wake_fd.as_raw_fd(), events: libc::POLLIN, revents: 0 },
+            ];
+            let ret = unsafe { libc::poll(fds.as_mut_ptr(), fds.len() as libc::nfds_t, -1) };
+            if ret < 0 {
+                if std::io::Error::last_os_error().kind() == std::io::ErrorKind::Interrupted {
+                    continue;
+                }
+                return;
+            }
+            if fds[1].revents != 0 {
+                return;
+            }
+
+            let len = unsafe { libc::read(watches.fd.as_raw_fd(), buffer.as_mut_ptr().cast(), buffer.len()) };
+            if len <= 0 {
+                continue;
+            }
+
+            let events = translate(&mut watches, &buffer[..len as usize]);
+            for event in events {
+                // 接收端已释放，停止监控
+                if sender.send(event).is_err() {
+                    return;
+                }
+            }
+        }
+    }
+
+    /// 将一批原始事件转换为 FileEvent
+    /// 同一批中 cookie 相同的 MOVED_FROM/MOVED_TO 合并为 Renamed，
+    /// 只有一半的移动（移入或移出被监控的范围）分别视为 Created/Removed
+    fn translate(watches: &mut Watches, buffer: &[u8]) -> Vec<FileEvent> {
+        const HEADER_SIZE: usize = std::mem::size_of::<libc::inotify_event>();
+
+        let mut events = Vec::new();
+        let mut pending_moves: Vec<(u32, PathBuf)> = Vec::new();
+        let mut offset = 0;
+
+        while offset + HEADER_SIZE <= buffer.len() {
+            let raw: libc::inotify_event = unsafe { std::ptr::read_unaligned(buffer[offset..].as_ptr().cast()) };
+            let name_bytes = &buffer[offset + HEADER_SIZE..offset + HEADER_SIZE + raw.len as usize];
+            offset += HEADER_SIZE + raw.len as usize;
+
+            if raw.mask & libc::IN_IGNORED != 0 {
+                watches.paths.remove(&raw.wd);
+                continue;
+            }
+            let Some(dir) = watches.paths.get(&raw.wd) else { continue };
+
+            let name_end = name_bytes.iter().position(|&b| b == 0).unwrap_or(name_bytes.len());
+            let path = if name_end == 0 {
+                dir.clone()
+            } else {
+                dir.join(OsStr::from_bytes(&name_bytes[..name_end]))
+            };
+
+            if raw.mask & libc::IN_CREATE != 0 {
+                if watches.recursive && raw.mask & libc::IN_ISDIR != 0 {
+                    let _ = watches.add_tree(&path);
+                }
+                events.push(FileEvent::Created(path));
+            } else if raw.mask & (libc::IN_MODIFY | libc::IN_ATTRIB) != 0 {
+                events.push(FileEvent::Modified(path));
+            } else if raw.mask & libc::IN_DELETE != 0 {
+                events.push(FileEvent::Removed(path));
+            } else if raw.mask & (libc::IN_DELETE_SELF | libc::IN_MOVE_SELF) != 0 {
+                // 子目录被删除或移动时父目录已经报告过，只为监控根路径生成事件
+                if !watches.paths.values().any(|other| path.starts_with(other) && *other != path) {
+                    events.push(FileEvent::Removed(path));
+                }
+            } else if raw.mask & libc::IN_MOVED_FROM != 0 {
+                pending_moves.push((raw.cookie, path));
+            } else if raw.mask & libc::IN_MOVED_TO != 0 {
+                match pending_moves.iter().position(|(cookie, _)| *cookie == raw.cookie) {
+                    Some(index) => {
+                        let (_, from) = pending_moves.remove(index);
+                        if watches.recursive && raw.mask & libc::IN_ISDIR != 0 {
+                            rename_watches(watches, &from, &path);
+                        }
+                        events.push(FileEvent::Renamed { from, to: path });
+                    }
+                    None => {
+                        if watches.recursive && raw.mask & libc::IN_ISDIR != 0 {
+                            let _ = watches.add_tree(&path);
+                        }
+                        events.push(FileEvent::Created(path));
+                    }
+                }
+            }
+        }
+
+        events.extend(pending_moves.into_iter().map(|(_, path)| FileEvent::Removed(path)));
+        events
+    }
+
+    /// 目录重命名后更新其下所有 watch 记录的路径
+    fn rename_watches(watches: &mut Watches, from: &Path, to: &Path) {
+        for path in watches.paths.values_mut() {
+            if let Ok(rest) = path.strip_prefix(from) {
+                *path = to.join(rest);
+            }
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+mod portable {
+    use std::path::PathBuf;
+    use std::sync::mpsc::{self, Receiver};
+
+    use notify::event::{EventKind, ModifyKind, RenameMode};
+    use notify::{Event, RecursiveMode, Watcher};
+
+    use super::FileEvent;
+    use crate::system::common::*;
+
+    /// notify 的平台监控器，事件在 notify 的后台线程中转换后发送
+    pub struct NotifyBackend {
+        _watcher: notify::RecommendedWatcher,
+    }
+
+    impl NotifyBackend {
+        pub fn start(root: PathBuf, recursive: bool) -> SystemResult<(Self, Receiver<FileEvent>)> {
+            if !root.exists() {
+                return Err(SystemError::NotFound(format!("Watch path not found: {}", root.display())));
+            }
+
+            let (sender, receiver) = mpsc::channel();
+            let mut renames = RenameState::default();
+            let mut watcher = notify::recommended_watcher(move |result: notify::Result<Event>| {
+                // 接收端释放后发送失败，监控器随 FileWatcher 一起释放
+                if let Ok(event) = result {
+                    for event in translate(event, &mut renames) {
+                        let _ = sender.send(event);
+                    }
+                }
+            })
+            .map_err(watch_error)?;
+
+            let mode = if recursive { RecursiveMode::Recursive } else { RecursiveMode::NonRecursive };
+            watcher.watch(&root, mode).map_err(watch_error)?;
+            Ok((Self { _watcher: watcher }, receiver))
+        }
+
+        pub fn stop(self) {}
+    }
+
+    fn watch_error(err: notify::Error) -> SystemError {
+        match err.kind {
+            notify::ErrorKind::Io(io) => io.into(),
+            notify::ErrorKind::PathNotFound => SystemError::NotFound(format!("Watch path not found: {:?}", err.paths)),
+            _ => SystemError::Internal(format!("File watch failed: {}", err)),
+        }
+    }
+
+    /// 跨事件的重命名状态
+    #[derive(Default)]
+    struct RenameState {
+        /// 等待配对的 From 路径
+        pending_from: Option<PathBuf>,
+        /// 刚由 From、To 合并出的重命名，部分后端随后还会上报一次 Both
+        last_paired: Option<(PathBuf, PathBuf)>,
+    }
+
+    /// 将 notify 事件转换为 FileEvent
+    /// 重命名可能分成 From、To 两个事件上报，暂存 From 等待下一个 To 合并为 Renamed；
+    /// 没有等到 To（移出被监控的范围）时视为 Removed
+    fn translate(event: Event, renames: &mut RenameState) -> Vec<FileEvent> {
+        let mut events = Vec::new();
+        let mut paths = event.paths.into_iter();
+
+        if !matches!(event.kind, EventKind::Modify(ModifyKind::Name(RenameMode::To))) {
+            events.extend(renames.pending_from.take().map(FileEvent::Removed));
+        }
+        let last_paired = renames.last_paired.take();
+
+        match event.kind {
+            EventKind::Create(_) => events.extend(paths.map(FileEvent::Created)),
+            EventKind::Remove(_) => events.extend(paths.map(FileEvent::Removed)),
+            EventKind::Modify(ModifyKind::Name(RenameMode::Both)) => {
+                if let (Some(from), Some(to)) = (paths.next(), paths.next()) {
+                    if last_paired.as_ref() != Some(&(from.clone(), to.clone())) {
+                        events.push(FileEvent::Renamed { from, to });
+                    }
+                }
+            }
+            EventKind::Modify(ModifyKind::Name(RenameMode::From)) => renames.pending_from = paths.next(),
+            EventKind::Modify(ModifyKind::Name(RenameMode::To)) => {
+                if let Some(to) = paths.next() {
+                    match renames.pending_from.take() {
+                        Some(from) => {
+                            renames.last_paired = Some((from.clone(), to.clone()));
+                            events.push(FileEvent::Renamed { from, to });
+                        }
+                        None => events.push(FileEvent::Created(to)),
+                    }
+                }
+            }
+            // FSEvents 不区分重命名的两端，按路径是否还存在判断
+            EventKind::Modify(ModifyKind::Name(_)) => events.extend(paths.map(|path| {
+                if path.exists() {
+                    FileEvent::Created(path)
+                } else {
+                    FileEvent::Removed(path)
+                }
+            })),
+            EventKind::Modify(_) => events.extend(paths.map(FileEvent::Modified)),
+            _ => {}
+        }
+        events
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watch_path_reports_events() {
+        let dir = std::env::temp_dir().join(format!("lycrex_watch_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let watcher = FileWatcher::new(dir.to_str().unwrap(), true).unwrap();
+
+        let sub = dir.join("sub");
+        std::fs::create_dir(&sub).unwrap();
+        assert_eq!(watcher.recv_timeout(Duration::from_secs(5)), Some(FileEvent::Created(sub.clone())));
+
+        // 新建的子目录会被自动加入监控
+        let file = sub.join("a.txt");
+        std::fs::write(&file, b"hello").unwrap();
+        assert_eq!(watcher.recv_timeout(Duration::from_secs(5)), Some(FileEvent::Created(file.clone())));
+        assert_eq!(watcher.recv_timeout(Duration::from_secs(5)), Some(FileEvent::Modified(file.clone())));
+
+        let renamed = sub.join("b.txt");
+        std::fs::rename(&file, &renamed).unwrap();
+        assert_eq!(
+            watcher.recv_timeout(Duration::from_secs(5)),
+            Some(FileEvent::Renamed { from: file, to: renamed.clone() })
+        );
+
+        std::fs::remove_file(&renamed).unwrap();
+        assert_eq!(watcher.recv_timeout(Duration::from_secs(5)), Some(FileEvent::Removed(renamed)));
+
+        drop(watcher);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -4,14 +4,235 @@ use crate::system::common::*;
 pub struct HardwareInfo;
 
 impl HardwareInfo {
+    /// 获取处理器型号、核心数和基础频率
+    /// sysinfo 只能按逻辑处理器枚举，物理核心数由各平台自行检测，检测失败时退回 sysinfo 的结果
     pub fn get_cpu_info(&self) -> SystemResult<CpuInfo> {
-        Err(SystemError::NotSupported("Not implemented yet".to_string()))
+        let mut system = sysinfo::System::new();
+        system.refresh_cpu_all();
+
+        let first = system.cpus().first();
+        let logical_cores = match system.cpus().len() {
+            0 => std::thread::available_parallelism().map(|n| n.get()).unwrap_or(1),
+            count => count,
+        };
+        let physical_cores = platform::physical_cores()
+            .or_else(sysinfo::System::physical_core_count)
+            .unwrap_or(logical_cores);
+
+        let name = platform::model_name()
+            .or_else(|| first.map(|cpu| cpu.brand().trim().to_string()))
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| "Unknown".to_string());
+        let frequency = platform::base_frequency()
+            .or_else(|| first.map(|cpu| cpu.frequency()).filter(|&mhz| mhz > 0))
+            .unwrap_or(0);
+
+        Ok(CpuInfo {
+            name,
+            cores: physical_cores as u32,
+            logical_cores: logical_cores as u32,
+            physical_cores: physical_cores as u32,
+            frequency,
+        })
     }
 }
 
+/// 处理器概要信息
+/// 与 `system::sysinfo::CpuInfo`（每个逻辑处理器一条的实时数据）不同，这里描述的是整颗处理器
 #[derive(Debug, Clone)]
 pub struct CpuInfo {
     pub name: String,
+    /// 物理核心数，与 physical_cores 相同
     pub cores: u32,
+    pub logical_cores: u32,
+    pub physical_cores: u32,
+    /// 基础频率 (MHz)，无法获取基础频率时为当前频率，均不可用时为 0
     pub frequency: u64,
-} 
\ No newline at end of file
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::collections::HashSet;
+
+    pub fn physical_cores() -> Option<usize> {
+        parse_cpuinfo(&crate::system::common::linux::read_proc_file("cpuinfo").ok()?).physical_cores
+    }
+
+    pub fn model_name() -> Option<String> {
+        parse_cpuinfo(&crate::system::common::linux::read_proc_file("cpuinfo").ok()?).model_name
+    }
+
+    /// cpufreq 的 base_frequency 只在部分驱动（如 intel_pstate）下存在，单位为 kHz
+    pub fn base_frequency() -> Option<u64> {
+        let khz = std::fs::read_to_string("/sys/devices/system/cpu/cpu0/cpufreq/base_frequency").ok()?;
+        khz.trim().parse::<u64>().ok().map(|khz| khz / 1000)
+    }
+
+    #[derive(Debug, Default, PartialEq)]
+    pub struct CpuInfoSummary {
+        pub model_name: Option<String>,
+        pub physical_cores: Option<usize>,
+    }
+
+    /// 按 (physical id, core id) 去重统计物理核心；ARM 等不提供这两个字段的平台返回 None
+    pub fn parse_cpuinfo(text: &str) -> CpuInfoSummary {
+        let mut summary = CpuInfoSummary::default();
+        let mut cores = HashSet::new();
+        let mut physical_id = None;
+
+        for line in text.lines() {
+            let Some((key, value)) = line.split_once(':') else { continue };
+            let value = value.trim();
+            match key.trim() {
+                "model name" | "Model" if summary.model_name.is_none() && !value.is_empty() => {
+                    summary.model_name = Some(value.to_string());
+                }
+                "physical id" => physical_id = value.parse::<u32>().ok(),
+                "core id" => {
+                    if let Ok(core_id) = value.parse::<u32>() {
+                        cores.insert((physical_id.unwrap_or(0), core_id));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        if !cores.is_empty() {
+            summary.physical_cores = Some(cores.len());
+        }
+        summary
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use std::ffi::CString;
+
+    pub fn physical_cores() -> Option<usize> {
+        sysctl_u64("hw.physicalcpu").map(|count| count as usize)
+    }
+
+    pub fn model_name() -> Option<String> {
+        sysctl_string("machdep.cpu.brand_string")
+    }
+
+    /// hw.cpufrequency 只在 Intel 机型上存在，单位为 Hz
+    pub fn base_frequency() -> Option<u64> {
+        sysctl_u64("hw.cpufrequency").map(|hz| hz / 1_000_000)
+    }
+
+    fn sysctl_raw(name: &str) -> Option<Vec<u8>> {
+        let name = CString::new(name).ok()?;
+        let mut size = 0usize;
+        let ret = unsafe { libc::sysctlbyname(name.as_ptr(), std::ptr::null_mut(), &mut size, std::ptr::null_mut(), 0) };
+        if ret != 0 || size == 0 {
+            return None;
+        }
+        let mut buffer = vec![0u8; size];
+        let ret = unsafe {
+            libc::sysctlbyname(name.as_ptr(), buffer.as_mut_ptr() as *mut _, &mut size, std::ptr::null_mut(), 0)
+        };
+        if ret != 0 {
+            return None;
+        }
+        buffer.truncate(size);
+        Some(buffer)
+    }
+
+    /// 整数类型的 sysctl 可能是 4 字节或 8 字节
+    fn sysctl_u64(name: &str) -> Option<u64> {
+        let buffer = sysctl_raw(name)?;
+        match buffer.len() {
+            4 => Some(u32::from_ne_bytes(buffer[..4].try_into().ok()?) as u64),
+            8 => Some(u64::from_ne_bytes(buffer[..8].try_into().ok()?)),
+            _ => None,
+        }
+    }
+
+    fn sysctl_string(name: &str) -> Option<String> {
+        let buffer = sysctl_raw(name)?;
+        let end = buffer.iter().position(|&b| b == 0).unwrap_or(buffer.len());
+        Some(String::from_utf8_lossy(&buffer[..end]).trim().to_string())
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use windows::Win32::System::SystemInformation::{
+        GetLogicalProcessorInformation, RelationProcessorCore, SYSTEM_LOGICAL_PROCESSOR_INFORMATION,
+    };
+
+    use crate::system::registry::{RegistryTools, RegistryValue};
+
+    const PROCESSOR_KEY: &str = "HARDWARE\\DESCRIPTION\\System\\CentralProcessor\\0";
+
+    /// 统计 RelationProcessorCore 条目数
+    pub fn physical_cores() -> Option<usize> {
+        let mut length = 0u32;
+        // 第一次调用获取所需的缓冲区大小
+        let _ = unsafe { GetLogicalProcessorInformation(None, &mut length) };
+        let entry_size = std::mem::size_of::<SYSTEM_LOGICAL_PROCESSOR_INFORMATION>();
+        let mut buffer = vec![SYSTEM_LOGICAL_PROCESSOR_INFORMATION::default(); (length as usize).div_ceil(entry_size)];
+        unsafe { GetLogicalProcessorInformation(Some(buffer.as_mut_ptr()), &mut length) }.ok()?;
+
+        buffer.truncate(length as usize / entry_size);
+        let count = buffer.iter().filter(|info| info.Relationship == RelationProcessorCore).count();
+        (count > 0).then_some(count)
+    }
+
+    pub fn model_name() -> Option<String> {
+        match RegistryTools.query_value("HKLM", PROCESSOR_KEY, "ProcessorNameString").ok()? {
+            RegistryValue::String(name) => Some(name.trim().to_string()),
+            _ => None,
+        }
+    }
+
+    /// 注册表中的 ~MHz 为处理器的标称频率
+    pub fn base_frequency() -> Option<u64> {
+        match RegistryTools.query_value("HKLM", PROCESSOR_KEY, "~MHz").ok()? {
+            RegistryValue::Dword(mhz) => Some(mhz as u64),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+mod platform {
+    pub fn physical_cores() -> Option<usize> {
+        None
+    }
+
+    pub fn model_name() -> Option<String> {
+        None
+    }
+
+    pub fn base_frequency() -> Option<u64> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_cpu_info() {
+        let info = HardwareInfo.get_cpu_info().unwrap();
+        assert!(info.physical_cores >= 1);
+        assert!(info.logical_cores >= info.physical_cores);
+        assert_eq!(info.cores, info.physical_cores);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_parse_cpuinfo() {
+        let text = "processor\t: 0\nmodel name\t: Intel(R) Xeon(R) CPU @ 2.20GHz\nphysical id\t: 0\ncore id\t\t: 0\n\n\
+            processor\t: 1\nmodel name\t: Intel(R) Xeon(R) CPU @ 2.20GHz\nphysical id\t: 0\ncore id\t\t: 0\n\n\
+            processor\t: 2\nmodel name\t: Intel(R) Xeon(R) CPU @ 2.20GHz\nphysical id\t: 1\ncore id\t\t: 0\n";
+        let summary = platform::parse_cpuinfo(text);
+        assert_eq!(summary.model_name.as_deref(), Some("Intel(R) Xeon(R) CPU @ 2.20GHz"));
+        assert_eq!(summary.physical_cores, Some(2));
+
+        assert_eq!(platform::parse_cpuinfo("processor\t: 0\nBogoMIPS\t: 48.00\n").physical_cores, None);
+    }
+}
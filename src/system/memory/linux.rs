@@ -1,76 +1,555 @@
 #[cfg(target_os = "linux")]
 pub mod implementation {
     use crate::system::common::*;
-    
+    use crate::{debug, info, warn};
+    use std::fs::{File, OpenOptions};
+    use std::io::ErrorKind;
+    use std::os::unix::fs::FileExt;
+    use std::os::unix::io::{AsRawFd, FromRawFd};
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    /// `/proc/<pid>/mem` 的文件描述符
     pub type ProcessHandle = i32;
 
     /// 根据进程名查找 PID
+    /// 扫描 `/proc/<pid>/comm`（内核会截断到 15 个字节）和 `/proc/<pid>/exe` 的文件名做精确匹配，有多个匹配时返回最小的 PID
     pub fn find_pid_by_name(process_name: &str) -> SystemResult<u32> {
-        use std::process::Command;
-        
-        let output = Command::new("pgrep")
-            .arg("-f")
-            .arg(process_name)
-            .output()
-            .map_err(|e| SystemError::ProcessError(format!("Failed to execute pgrep: {}", e)))?;
-
-        if output.status.success() {
-            let output_str = String::from_utf8_lossy(&output.stdout);
-            let pids: Vec<&str> = output_str.trim().split('\n').collect();
-            
-            if let Some(pid_str) = pids.first() {
-                if let Ok(pid) = pid_str.parse::<u32>() {
-                    return Ok(pid);
-                }
+        let entries = std::fs::read_dir("/proc")
+            .map_err(|e| crate::system::common::linux::proc_error("/proc", e))?;
+
+        let matches_name = |pid: u32| {
+            let comm = std::fs::read_to_string(format!("/proc/{}/comm", pid)).unwrap_or_default();
+            if comm.trim_end_matches('\n') == process_name {
+                return true;
             }
-        }
+            std::fs::read_link(format!("/proc/{}/exe", pid))
+                .ok()
+                .and_then(|exe| exe.file_name().map(|name| name == process_name))
+                .unwrap_or(false)
+        };
 
-        Err(SystemError::ProcessError(format!("Process not found: {}", process_name)))
+        entries
+            .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse::<u32>().ok())
+            .filter(|&pid| matches_name(pid))
+            .min()
+            .ok_or_else(|| SystemError::ProcessError(format!("Process not found: {}", process_name)))
     }
 
-    /// 获取进程内存信息
+    /// 获取进程内存信息（解析 /proc/<pid>/status）
     pub fn get_process_memory_info(pid: u32) -> SystemResult<ProcessMemoryInfo> {
-        Err(SystemError::NotSupported("Linux memory operations not implemented yet".to_string()))
+        let status = crate::system::common::linux::read_proc_file(&format!("{}/status", pid))?;
+
+        let mut process_name = None;
+        let mut fields = std::collections::HashMap::new();
+        for line in status.lines() {
+            let Some((key, value)) = line.split_once(':') else { continue };
+            if key == "Name" {
+                process_name = Some(value.trim().to_string());
+            } else if let Some(kb) = value.trim().strip_suffix(" kB") {
+                if let Ok(kb) = kb.trim().parse::<u64>() {
+                    fields.insert(key, kb * 1024);
+                }
+            }
+        }
+        let field = |key: &str| fields.get(key).copied().unwrap_or(0);
+
+        // 内核线程没有用户态映射，无权限读取 maps 时基址同样记为 0
+        let base_address = main_module_base_address(pid).unwrap_or(0);
+
+        Ok(ProcessMemoryInfo {
+            pid,
+            process_name,
+            base_address,
+            memory_usage: field("VmRSS"),
+            virtual_size: field("VmSize"),
+            working_set: field("VmRSS"),
+            peak_working_set: field("VmHWM"),
+            private_bytes: field("RssAnon"),
+        })
     }
 
     /// 列出所有进程的内存信息
+    /// 遍历期间退出的进程会被跳过
     pub fn list_process_memory_info() -> SystemResult<Vec<ProcessMemoryInfo>> {
-        Err(SystemError::NotSupported("Linux memory operations not implemented yet".to_string()))
+        let entries = std::fs::read_dir("/proc")
+            .map_err(|e| crate::system::common::linux::proc_error("/proc", e))?;
+
+        let mut infos: Vec<ProcessMemoryInfo> = entries
+            .filter_map(|entry| entry.ok()?.file_name().to_str()?.parse::<u32>().ok())
+            .filter_map(|pid| get_process_memory_info(pid).ok())
+            .collect();
+        infos.sort_by_key(|info| info.pid);
+        Ok(infos)
+    }
+
+    /// 列出进程的内存区域（解析 /proc/<pid>/maps）
+    pub fn list_memory_regions(pid: u32) -> SystemResult<Vec<MemoryRegion>> {
+        let maps = crate::system::common::linux::read_proc_file(&format!("{}/maps", pid))?;
+        Ok(maps.lines().filter_map(parse_maps_line).collect())
+    }
+
+    /// 解析 maps 中的一行：`start-end perms offset dev inode [path]`
+    fn parse_maps_line(line: &str) -> Option<MemoryRegion> {
+        let mut fields = line.split_whitespace();
+        let (start, end) = fields.next()?.split_once('-')?;
+        let start = usize::from_str_radix(start, 16).ok()?;
+        let end = usize::from_str_radix(end, 16).ok()?;
+        let perms = fields.next()?.as_bytes();
+        if perms.len() < 4 {
+            return None;
+        }
+
+        // 跳过 offset、dev、inode，剩余部分为路径（可能包含空格）
+        let path = fields.skip(3).collect::<Vec<_>>().join(" ");
+        let module_name = if path.is_empty() {
+            None
+        } else if path.starts_with('[') {
+            Some(path)
+        } else {
+            std::path::Path::new(&path)
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+        };
+
+        Some(MemoryRegion {
+            base_address: start,
+            size: end.saturating_sub(start),
+            protection: MemoryPermission::from_flags(perms[0] == b'r', perms[1] == b'w', perms[2] == b'x'),
+            is_committed: true,
+            is_private: perms[3] == b'p',
+            module_name,
+        })
+    }
+
+    /// 列出进程加载的模块（解析 /proc/<pid>/maps）
+    /// 同一文件的多个映射合并为一个模块，范围覆盖其所有段
+    pub fn list_modules(pid: u32) -> SystemResult<Vec<ModuleInfo>> {
+        let maps = crate::system::common::linux::read_proc_file(&format!("{}/maps", pid))?;
+        let mut modules: Vec<ModuleInfo> = Vec::new();
+
+        for line in maps.lines() {
+            let mut fields = line.split_whitespace();
+            let Some((start, end)) = fields
+                .next()
+                .and_then(|range| range.split_once('-'))
+                .and_then(|(start, end)| Some((usize::from_str_radix(start, 16).ok()?, usize::from_str_radix(end, 16).ok()?)))
+            else {
+                continue;
+            };
+            // 只有以 / 开头的路径才是文件映射，[heap]、[stack] 等伪路径和匿名映射跳过
+            let path = fields.skip(4).collect::<Vec<_>>().join(" ");
+            if !path.starts_with('/') {
+                continue;
+            }
+
+            match modules.iter_mut().find(|module| module.path.as_deref() == Some(path.as_str())) {
+                Some(module) => {
+                    let module_end = (module.base_address + module.size).max(end);
+                    module.base_address = module.base_address.min(start);
+                    module.size = module_end - module.base_address;
+                }
+                None => modules.push(ModuleInfo {
+                    name: std::path::Path::new(&path)
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                        .unwrap_or_else(|| path.clone()),
+                    path: Some(path),
+                    base_address: start,
+                    size: end - start,
+                }),
+            }
+        }
+
+        Ok(modules)
+    }
+
+    /// 获取主程序的加载基址：maps 中第一个映射自 /proc/<pid>/exe 指向文件的区域
+    fn main_module_base_address(pid: u32) -> SystemResult<usize> {
+        let exe = std::fs::read_link(format!("/proc/{}/exe", pid))
+            .map_err(|e| crate::system::common::linux::proc_error(&format!("/proc/{}/exe", pid), e))?;
+        let exe = exe.to_string_lossy();
+        // 可执行文件被删除后链接目标会带上 " (deleted)" 后缀，maps 中也是如此
+        let maps = crate::system::common::linux::read_proc_file(&format!("{}/maps", pid))?;
+
+        for line in maps.lines() {
+            let mut fields = line.split_whitespace();
+            let Some(range) = fields.next() else { continue };
+            let path = fields.skip(4).collect::<Vec<_>>().join(" ");
+            if path == exe {
+                if let Some(start) = range.split_once('-').and_then(|(start, _)| usize::from_str_radix(start, 16).ok()) {
+                    return Ok(start);
+                }
+            }
+        }
+
+        Err(SystemError::NotFound(format!("Main module of process {} not found in maps", pid)))
+    }
+
+    /// 打开 /proc/<pid>/mem
+    /// 访问受 ptrace 访问模式检查约束，被 Yama ptrace_scope 拒绝时返回 PermissionDenied
+    fn open_mem(pid: u32, access: AccessRights) -> std::io::Result<File> {
+        let path = format!("/proc/{}/mem", pid);
+        OpenOptions::new()
+            .read(true)
+            .write(access.can_write())
+            .open(&path)
+            .map_err(|e| mem_error(pid, e))
+    }
+
+    /// 为权限错误补充 ptrace_scope 提示
+    fn mem_error(pid: u32, err: std::io::Error) -> std::io::Error {
+        match err.raw_os_error() {
+            Some(libc::EACCES) | Some(libc::EPERM) => std::io::Error::new(
+                ErrorKind::PermissionDenied,
+                format!(
+                    "Access to memory of process {} denied (check /proc/sys/kernel/yama/ptrace_scope or run with CAP_SYS_PTRACE)",
+                    pid
+                ),
+            ),
+            _ => err,
+        }
+    }
+
+    /// 在不取得所有权的情况下以 File 访问文件描述符
+    fn with_handle<T>(handle: ProcessHandle, f: impl FnOnce(&File) -> std::io::Result<T>) -> std::io::Result<T> {
+        let file = std::mem::ManuallyDrop::new(unsafe { File::from_raw_fd(handle) });
+        f(&file)
     }
 
     /// 读取进程内存
+    /// 地址未映射时返回 EIO 对应的错误
     pub fn read_process_memory(
-        _handle: ProcessHandle,
-        _address: usize,
-        _size: usize,
+        handle: ProcessHandle,
+        address: usize,
+        size: usize,
     ) -> std::io::Result<Vec<u8>> {
-        Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Linux memory operations not implemented yet"))
+        if handle < 0 {
+            return Err(std::io::Error::new(ErrorKind::InvalidInput, "Invalid process handle"));
+        }
+
+        let mut buffer = vec![0u8; size];
+        with_handle(handle, |file| file.read_exact_at(&mut buffer, address as u64))
+            .map_err(|e| match e.raw_os_error() {
+                Some(libc::EACCES) | Some(libc::EPERM) => std::io::Error::new(ErrorKind::PermissionDenied, e.to_string()),
+                _ => e,
+            })?;
+        Ok(buffer)
+    }
+
+    /// 一次读取多个 `(地址, 大小)` 范围，结果与输入一一对应
+    /// 通过 process_vm_readv 合并为尽量少的系统调用（每次最多 IOV_MAX 个范围）；
+    /// 系统调用不可用或某个范围未能完整读取时，改为逐个经 /proc/<pid>/mem 读取，以返回准确的错误
+    pub fn read_process_memory_many(
+        pid: u32,
+        handle: ProcessHandle,
+        reads: &[(usize, usize)],
+    ) -> std::io::Result<Vec<Vec<u8>>> {
+        const IOV_MAX: usize = 1024;
+
+        let mut buffers: Vec<Vec<u8>> = reads.iter().map(|&(_, size)| vec![0u8; size]).collect();
+        let complete = reads
+            .chunks(IOV_MAX)
+            .zip(buffers.chunks_mut(IOV_MAX))
+            .all(|(ranges, chunk)| vm_readv(pid, ranges, chunk));
+
+        if complete {
+            return Ok(buffers);
+        }
+        reads.iter().map(|&(address, size)| read_process_memory(handle, address, size)).collect()
     }
 
     /// 写入进程内存
+    /// 通过 /proc/<pid>/mem 写入可以绕过页面的只读保护
     pub fn write_process_memory(
-        _handle: ProcessHandle,
-        _address: usize,
-        _data: &[u8],
+        handle: ProcessHandle,
+        address: usize,
+        data: &[u8],
     ) -> std::io::Result<usize> {
-        Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Linux memory operations not implemented yet"))
+        if handle < 0 {
+            return Err(std::io::Error::new(ErrorKind::InvalidInput, "Invalid process handle"));
+        }
+
+        with_handle(handle, |file| file.write_at(data, address as u64))
     }
 
-    pub struct ProcessInstance;
+    /// process_vm_readv/writev 是否可用，内核不支持（ENOSYS）时置为 false，之后直接走 /proc/<pid>/mem
+    static VM_SYSCALLS_AVAILABLE: AtomicBool = AtomicBool::new(true);
+
+    /// 记录系统调用失败的原因，返回 false 供调用方回退
+    fn vm_syscall_failed() -> bool {
+        if std::io::Error::last_os_error().raw_os_error() == Some(libc::ENOSYS) {
+            VM_SYSCALLS_AVAILABLE.store(false, Ordering::Relaxed);
+        }
+        false
+    }
+
+    /// 用一次 process_vm_readv 把 ranges 读入对应的 buffers，全部读满时返回 true
+    pub(super) fn vm_readv(pid: u32, ranges: &[(usize, usize)], buffers: &mut [Vec<u8>]) -> bool {
+        if !VM_SYSCALLS_AVAILABLE.load(Ordering::Relaxed) {
+            return false;
+        }
+
+        let local: Vec<libc::iovec> = buffers
+            .iter_mut()
+            .map(|buffer| libc::iovec { iov_base: buffer.as_mut_ptr() as *mut libc::c_void, iov_len: buffer.len() })
+            .collect();
+        let remote: Vec<libc::iovec> = ranges
+            .iter()
+            .map(|&(address, size)| libc::iovec { iov_base: address as *mut libc::c_void, iov_len: size })
+            .collect();
+        let expected: usize = ranges.iter().map(|&(_, size)| size).sum();
+
+        let read = unsafe {
+            libc::process_vm_readv(
+                pid as libc::pid_t,
+                local.as_ptr(),
+                local.len() as libc::c_ulong,
+                remote.as_ptr(),
+                remote.len() as libc::c_ulong,
+                0,
+            )
+        };
+        if read < 0 {
+            return vm_syscall_failed();
+        }
+        read as usize == expected
+    }
+
+    /// 用一次 process_vm_writev 写入 data，全部写入时返回 true
+    fn vm_writev(pid: u32, address: usize, data: &[u8]) -> bool {
+        if !VM_SYSCALLS_AVAILABLE.load(Ordering::Relaxed) {
+            return false;
+        }
+
+        let local = libc::iovec { iov_base: data.as_ptr() as *mut libc::c_void, iov_len: data.len() };
+        let remote = libc::iovec { iov_base: address as *mut libc::c_void, iov_len: data.len() };
+        let written = unsafe { libc::process_vm_writev(pid as libc::pid_t, &local, 1, &remote, 1, 0) };
+        if written < 0 {
+            return vm_syscall_failed();
+        }
+        written as usize == data.len()
+    }
+
+    /// 读取进程内存，优先使用 process_vm_readv（无需 seek，一次系统调用）
+    /// 系统调用不可用或读取失败时回退到 /proc/<pid>/mem，错误以回退路径的结果为准
+    pub(crate) fn read_pid_memory(
+        pid: u32,
+        handle: ProcessHandle,
+        address: usize,
+        size: usize,
+    ) -> std::io::Result<Vec<u8>> {
+        let mut buffer = [vec![0u8; size]];
+        if vm_readv(pid, &[(address, size)], &mut buffer) {
+            let [buffer] = buffer;
+            return Ok(buffer);
+        }
+        read_process_memory(handle, address, size)
+    }
+
+    /// 写入进程内存，优先使用 process_vm_writev
+    /// process_vm_writev 遵守页面保护，写入只读页面失败时回退到 /proc/<pid>/mem，因此仍可写入只读页面
+    pub(crate) fn write_pid_memory(
+        pid: u32,
+        handle: ProcessHandle,
+        address: usize,
+        data: &[u8],
+    ) -> std::io::Result<usize> {
+        if vm_writev(pid, address, data) {
+            return Ok(data.len());
+        }
+        write_process_memory(handle, address, data)
+    }
+
+    /// 修改内存页的保护属性，返回起始地址所在区域原来的保护属性
+    /// mprotect 只作用于调用进程自身，修改其他进程需要通过 ptrace 注入系统调用，
+    /// 这里不做这种侵入式操作，因此其他进程返回 NotSupported；
+    /// 写入只读页面本身不需要修改保护，/proc/<pid>/mem 的写入会绕过页面保护
+    pub fn protect_memory(
+        pid: u32,
+        address: usize,
+        size: usize,
+        permission: MemoryPermission,
+    ) -> SystemResult<MemoryPermission> {
+        if pid != std::process::id() {
+            return Err(SystemError::NotSupported(format!(
+                "Changing page protection of another process (PID {}) is not supported on Linux", pid
+            )));
+        }
+
+        let previous = list_memory_regions(pid)?
+            .into_iter()
+            .find(|region| region.base_address <= address && address - region.base_address < region.size)
+            .map(|region| region.protection)
+            .ok_or_else(|| SystemError::MemoryError(format!("Address 0x{:X} is not mapped", address)))?;
+
+        let mut protection = libc::PROT_NONE;
+        if permission.is_readable() { protection |= libc::PROT_READ; }
+        if permission.is_writable() { protection |= libc::PROT_WRITE; }
+        if permission.is_executable() { protection |= libc::PROT_EXEC; }
+
+        let (start, len) = crate::system::memory::utils::page_range(address, size);
+        if unsafe { libc::mprotect(start as *mut libc::c_void, len, protection) } != 0 {
+            let err = std::io::Error::last_os_error();
+            let message = format!("mprotect failed at 0x{:X}: {}", start, err);
+            return Err(match err.raw_os_error() {
+                Some(libc::EACCES) => SystemError::PermissionDenied(message),
+                _ => SystemError::MemoryError(message),
+            });
+        }
+        Ok(previous)
+    }
+
+    /// Linux 进程实例，持有打开的 /proc/<pid>/mem
+    pub struct ProcessInstance {
+        mem: File,
+        base_addr: usize,
+        pid: u32,
+        name: Option<String>,
+        access: AccessRights,
+    }
 
     impl ProcessInstance {
-        pub fn new_by_name(_process_name: &str) -> std::io::Result<Self> {
-            Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Linux memory operations not implemented yet"))
+        /// 根据进程名创建实例
+        pub fn new_by_name(process_name: &str) -> std::io::Result<Self> {
+            Self::new_by_name_with_access(process_name, AccessRights::default())
         }
 
-        pub fn new_by_pid(_pid: u32) -> std::io::Result<Self> {
-            Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Linux memory operations not implemented yet"))
+        /// 根据PID创建实例
+        pub fn new_by_pid(pid: u32) -> std::io::Result<Self> {
+            Self::new_by_pid_with_access(pid, AccessRights::default())
         }
 
-        pub fn pid(&self) -> u32 { 0 }
-        pub fn base_addr(&self) -> usize { 0 }
-        pub fn name(&self) -> Option<&str> { None }
-        pub fn handle(&self) -> ProcessHandle { -1 }
+        /// 根据进程名以指定访问权限创建实例
+        pub fn new_by_name_with_access(process_name: &str, access: AccessRights) -> std::io::Result<Self> {
+            info!("memory", "Create Linux instance by name: {}", process_name);
+
+            let pid = find_pid_by_name(process_name)
+                .map_err(|e| std::io::Error::new(ErrorKind::NotFound, e.to_string()))?;
+
+            let mut instance = Self::new_by_pid_with_access(pid, access)?;
+            instance.name = Some(process_name.to_string());
+            Ok(instance)
+        }
+
+        /// 根据PID以指定访问权限创建实例
+        /// 只读实例以只读方式打开 mem 文件
+        pub fn new_by_pid_with_access(pid: u32, access: AccessRights) -> std::io::Result<Self> {
+            info!("memory", "Create Linux instance by PID: {}", pid);
+
+            let mem = open_mem(pid, access)?;
+            let base_addr = main_module_base_address(pid)
+                .map_err(|e| std::io::Error::new(ErrorKind::NotFound, e.to_string()))?;
+
+            info!("memory", "[{}] Linux instance created: PID={}, BaseAddr=0x{:X}", pid, pid, base_addr);
+
+            Ok(Self {
+                mem,
+                base_addr,
+                pid,
+                name: None,
+                access,
+            })
+        }
+
+        /// 读取内存（相对于模块基址的偏移）
+        pub fn read_memory(&self, offset: usize, size: usize) -> std::io::Result<Vec<u8>> {
+            debug!("memory", "[{}] Read memory: Offset=0x{:X}, Size={}", self.pid, offset, size);
+            read_pid_memory(self.pid, self.handle(), self.base_addr + offset, size)
+        }
+
+        /// 读取绝对地址的内存
+        pub fn read_memory_at(&self, address: usize, size: usize) -> std::io::Result<Vec<u8>> {
+            debug!("memory", "[{}] Read memory at absolute address: Addr=0x{:X}, Size={}", self.pid, address, size);
+            read_pid_memory(self.pid, self.handle(), address, size)
+        }
+
+        /// 写入内存
+        pub fn write_memory(&self, offset: usize, data: &[u8]) -> std::io::Result<()> {
+            debug!("memory", "[{}] Write memory: Offset=0x{:X}, Size={}", self.pid, offset, data.len());
+
+            if !self.access.can_write() {
+                return Err(std::io::Error::new(ErrorKind::PermissionDenied, format!("Process {} was opened read-only", self.pid)));
+            }
+
+            let bytes_written = write_pid_memory(self.pid, self.handle(), self.base_addr + offset, data)?;
+
+            if bytes_written == data.len() {
+                debug!("memory", "[{}] Write memory success: Offset=0x{:X}, Written bytes={}", self.pid, offset, bytes_written);
+                Ok(())
+            } else {
+                warn!("memory", "[{}] Write memory incomplete: Offset=0x{:X}, Expected={}, Actual={}", self.pid, offset, data.len(), bytes_written);
+                Err(std::io::Error::other(format!("Only wrote {} bytes", bytes_written)))
+            }
+        }
+
+        /// 读取 u32（相对偏移）
+        pub fn read_u32(&self, offset: usize) -> std::io::Result<u32> {
+            let bytes = self.read_memory(offset, 4)?;
+            Ok(crate::system::memory::utils::bytes_to_u32(&bytes))
+        }
+
+        /// 读取 u64（相对偏移）
+        pub fn read_u64(&self, offset: usize) -> std::io::Result<u64> {
+            let bytes = self.read_memory(offset, 8)?;
+            Ok(crate::system::memory::utils::bytes_to_u64(&bytes))
+        }
+
+        /// 读取绝对地址的 u32
+        pub fn read_u32_at(&self, address: usize) -> std::io::Result<u32> {
+            let bytes = self.read_memory_at(address, 4)?;
+            Ok(crate::system::memory::utils::bytes_to_u32(&bytes))
+        }
+
+        /// 读取绝对地址的 u64
+        pub fn read_u64_at(&self, address: usize) -> std::io::Result<u64> {
+            let bytes = self.read_memory_at(address, 8)?;
+            Ok(crate::system::memory::utils::bytes_to_u64(&bytes))
+        }
+
+        /// 读取 UTF-8 字符串
+        pub fn read_utf8(&self, offset: usize, size: usize) -> std::io::Result<String> {
+            let bytes = self.read_memory(offset, size)?;
+            Ok(crate::system::memory::utils::bytes_to_utf8_string(&bytes))
+        }
+
+        /// 写入 u32
+        pub fn write_u32(&self, offset: usize, value: u32) -> std::io::Result<()> {
+            self.write_memory(offset, &value.to_le_bytes())
+        }
+
+        /// 写入 u64
+        pub fn write_u64(&self, offset: usize, value: u64) -> std::io::Result<()> {
+            self.write_memory(offset, &value.to_le_bytes())
+        }
+
+        /// 写入 UTF-8 字符串
+        pub fn write_utf8(&self, offset: usize, s: &str) -> std::io::Result<()> {
+            self.write_memory(offset, s.as_bytes())
+        }
+
+        /// 取出 mem 文件，所有权转移给调用方
+        pub fn into_file(self) -> File {
+            self.mem
+        }
+
+        // Getters
+        pub fn pid(&self) -> u32 { self.pid }
+        pub fn base_addr(&self) -> usize { self.base_addr }
+        pub fn name(&self) -> Option<&str> { self.name.as_deref() }
+        pub fn access(&self) -> AccessRights { self.access }
+        pub fn handle(&self) -> ProcessHandle { self.mem.as_raw_fd() }
+    }
+
+    /// 根据进程名和偏移读取内存
+    pub fn read_process_memory_by_name(process_name: &str, offset: usize, size: usize) -> std::io::Result<Vec<u8>> {
+        let pid = find_pid_by_name(process_name)
+            .map_err(|e| std::io::Error::new(ErrorKind::NotFound, e.to_string()))?;
+        read_process_memory_by_pid(pid, offset, size)
+    }
+
+    /// 根据PID和偏移读取内存
+    pub fn read_process_memory_by_pid(pid: u32, offset: usize, size: usize) -> std::io::Result<Vec<u8>> {
+        ProcessInstance::new_by_pid_with_access(pid, AccessRights::ReadOnly)?.read_memory(offset, size)
     }
 }
 
@@ -92,14 +571,38 @@ pub mod implementation {
         Err(SystemError::NotSupported("Linux memory operations only available on Linux".to_string()))
     }
 
+    pub fn list_memory_regions(_pid: u32) -> SystemResult<Vec<MemoryRegion>> {
+        Err(SystemError::NotSupported("Linux memory operations only available on Linux".to_string()))
+    }
+
+    pub fn list_modules(_pid: u32) -> SystemResult<Vec<ModuleInfo>> {
+        Err(SystemError::NotSupported("Linux memory operations only available on Linux".to_string()))
+    }
+
     pub fn read_process_memory(_handle: ProcessHandle, _address: usize, _size: usize) -> std::io::Result<Vec<u8>> {
         Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Linux memory operations only available on Linux"))
     }
 
+    pub fn read_process_memory_many(_pid: u32, _handle: ProcessHandle, _reads: &[(usize, usize)]) -> std::io::Result<Vec<Vec<u8>>> {
+        Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Linux memory operations only available on Linux"))
+    }
+
+    pub fn protect_memory(_pid: u32, _address: usize, _size: usize, _permission: MemoryPermission) -> SystemResult<MemoryPermission> {
+        Err(SystemError::NotSupported("Linux memory operations only available on Linux".to_string()))
+    }
+
     pub fn write_process_memory(_handle: ProcessHandle, _address: usize, _data: &[u8]) -> std::io::Result<usize> {
         Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Linux memory operations only available on Linux"))
     }
 
+    pub(crate) fn read_pid_memory(_pid: u32, _handle: ProcessHandle, _address: usize, _size: usize) -> std::io::Result<Vec<u8>> {
+        Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Linux memory operations only available on Linux"))
+    }
+
+    pub(crate) fn write_pid_memory(_pid: u32, _handle: ProcessHandle, _address: usize, _data: &[u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Linux memory operations only available on Linux"))
+    }
+
     pub struct ProcessInstance;
 
     impl ProcessInstance {
@@ -111,11 +614,299 @@ pub mod implementation {
             Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Linux memory operations only available on Linux"))
         }
 
+        pub fn new_by_name_with_access(_process_name: &str, _access: AccessRights) -> std::io::Result<Self> {
+            Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Linux memory operations only available on Linux"))
+        }
+
+        pub fn new_by_pid_with_access(_pid: u32, _access: AccessRights) -> std::io::Result<Self> {
+            Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Linux memory operations only available on Linux"))
+        }
+
         pub fn pid(&self) -> u32 { 0 }
         pub fn base_addr(&self) -> usize { 0 }
         pub fn name(&self) -> Option<&str> { None }
+        pub fn access(&self) -> AccessRights { AccessRights::default() }
         pub fn handle(&self) -> ProcessHandle { -1 }
     }
 }
 
 pub use implementation::*;
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use crate::system::common::AccessRights;
+    use crate::system::memory::ProcessMemoryInstance;
+
+    #[test]
+    fn test_read_write_own_memory() {
+        let pid = std::process::id();
+        let instance = ProcessMemoryInstance::new_by_pid(pid).unwrap();
+
+        // 主程序基址处为 ELF 头
+        assert_eq!(instance.read_memory(0, 4).unwrap(), b"\x7fELF");
+
+        let value = Box::new(0x1234_5678_u32);
+        let address = &*value as *const u32 as usize;
+        assert_eq!(instance.read_memory_at(address, 4).unwrap(), 0x1234_5678_u32.to_le_bytes());
+
+        let info = super::get_process_memory_info(pid).unwrap();
+        assert_eq!(info.base_address, instance.base_address());
+        assert!(info.memory_usage > 0);
+
+        let read_only = ProcessMemoryInstance::new_by_pid_with_access(pid, AccessRights::ReadOnly).unwrap();
+        assert!(read_only.write_u32(0, 0).is_err());
+    }
+
+    #[test]
+    fn test_find_pid_by_name() {
+        let exe = std::env::current_exe().unwrap();
+        let name = exe.file_name().unwrap().to_str().unwrap();
+        let pid = super::find_pid_by_name(name).unwrap();
+        let found = std::fs::read_link(format!("/proc/{}/exe", pid)).unwrap();
+        assert_eq!(found.file_name().unwrap(), name);
+
+        // 只做精确匹配，不能像 pgrep -f 那样命中命令行的子串
+        assert!(super::find_pid_by_name(&name[..name.len() - 1]).is_err());
+    }
+
+    #[test]
+    fn test_vm_syscalls_match_proc_mem() {
+        use std::os::unix::io::AsRawFd;
+
+        let pid = std::process::id();
+        let mem = std::fs::OpenOptions::new().read(true).write(true).open(format!("/proc/{}/mem", pid)).unwrap();
+        let fd = mem.as_raw_fd();
+
+        let data: Vec<u8> = (0..=255u8).cycle().take(10_000).collect();
+        let address = data.as_ptr() as usize;
+        let mut buffer = [vec![0u8; data.len()]];
+        if super::vm_readv(pid, &[(address, data.len())], &mut buffer) {
+            assert_eq!(buffer[0], data);
+        }
+        assert_eq!(super::read_pid_memory(pid, fd, address, data.len()).unwrap(), data);
+        assert_eq!(super::read_process_memory(fd, address, data.len()).unwrap(), data);
+        assert!(super::read_pid_memory(pid, fd, 0, 4).is_err());
+
+        let value = Box::new(0u64);
+        let address = &*value as *const u64 as usize;
+        assert_eq!(super::write_pid_memory(pid, fd, address, &0xDEAD_BEEF_u64.to_ne_bytes()).unwrap(), 8);
+        assert_eq!(unsafe { std::ptr::read_volatile(&*value) }, 0xDEAD_BEEF);
+
+        // process_vm_writev 无法写入只读页面，回退路径仍可写入
+        let page = crate::system::memory::utils::page_size();
+        let flags = libc::MAP_PRIVATE | libc::MAP_ANONYMOUS;
+        let address = unsafe { libc::mmap(std::ptr::null_mut(), page, libc::PROT_READ, flags, -1, 0) };
+        assert_ne!(address, libc::MAP_FAILED);
+        assert_eq!(super::write_pid_memory(pid, fd, address as usize, b"ro").unwrap(), 2);
+        assert_eq!(super::read_pid_memory(pid, fd, address as usize, 2).unwrap(), b"ro");
+        unsafe { libc::munmap(address, page) };
+    }
+
+    #[test]
+    fn test_read_many_and_read_struct() {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[repr(C)]
+        struct Player {
+            health: u32,
+            level: u16,
+            flags: u16,
+            position: [f32; 2],
+        }
+        unsafe impl crate::system::memory::utils::FromBytes for Player {}
+
+        let instance = ProcessMemoryInstance::new_by_pid(std::process::id()).unwrap();
+        let player = Box::new(Player { health: 100, level: 7, flags: 0xA5A5, position: [1.5, -3.0] });
+        let offset = (&*player as *const Player as usize).wrapping_sub(instance.base_address());
+        assert_eq!(instance.read_struct::<Player>(offset).unwrap(), *player);
+
+        let values = instance.read_many(&[(offset, 4), (offset + 4, 2), (offset + 8, 0), (0, 4)]).unwrap();
+        assert_eq!(values[0], 100u32.to_ne_bytes());
+        assert_eq!(values[1], 7u16.to_ne_bytes());
+        assert!(values[2].is_empty());
+        assert_eq!(values[3], b"\x7fELF");
+        // 任一范围不可读时整体失败
+        assert!(instance.read_many(&[(offset, 4), (0usize.wrapping_sub(instance.base_address()), 4)]).is_err());
+    }
+
+    #[test]
+    fn test_read_write_strings() {
+        let instance = ProcessMemoryInstance::new_by_pid(std::process::id()).unwrap();
+        let buffer = [0xFFu8; 64];
+        let offset = (buffer.as_ptr() as usize).wrapping_sub(instance.base_address());
+
+        instance.write_utf16_string(offset, "Player 1").unwrap();
+        assert_eq!(instance.read_utf16_string(offset, 32).unwrap(), "Player 1");
+        assert_eq!(instance.read_utf16_string(offset, 6).unwrap(), "Player");
+        assert_eq!(buffer[18], 0xFF);
+
+        instance.write_memory(offset, b"level_01\0garbage").unwrap();
+        assert_eq!(instance.read_cstring(offset, 64).unwrap(), "level_01");
+        assert_eq!(instance.read_cstring(offset, 5).unwrap(), "level");
+    }
+
+    #[test]
+    fn test_memory_view_and_floats() {
+        use crate::system::common::SystemError;
+        use crate::system::memory::FieldType;
+
+        #[repr(C)]
+        struct Entity {
+            health: u32,
+            speed: f32,
+            position: [f64; 2],
+            tag: [u8; 4],
+        }
+
+        let instance = ProcessMemoryInstance::new_by_pid(std::process::id()).unwrap();
+        let entity = Box::new(Entity { health: 100, speed: 1.5, position: [3.25, -7.0], tag: *b"NPC1" });
+        let base_offset = (&*entity as *const Entity as usize).wrapping_sub(instance.base_address());
+
+        let view = instance
+            .view(base_offset)
+            .field("health", 0, FieldType::U32)
+            .field("speed", 4, FieldType::F32)
+            .field("y", 16, FieldType::F64)
+            .field("tag", 24, FieldType::Bytes(4));
+        assert_eq!(view.read_u32("health").unwrap(), 100);
+        assert_eq!(view.read_f32("speed").unwrap(), 1.5);
+        assert_eq!(view.read_f64("y").unwrap(), -7.0);
+        assert_eq!(view.read_bytes("tag").unwrap(), b"NPC1");
+        assert_eq!(instance.read_f64(base_offset + 8).unwrap(), 3.25);
+
+        view.write_f32("speed", 2.75).unwrap();
+        view.write_bytes("tag", b"BOSS").unwrap();
+        instance.write_f64(base_offset + 8, 0.5).unwrap();
+        assert_eq!(unsafe { std::ptr::read_volatile(&entity.speed) }, 2.75);
+        assert_eq!(unsafe { std::ptr::read_volatile(&entity.tag) }, *b"BOSS");
+        assert_eq!(unsafe { std::ptr::read_volatile(&entity.position) }, [0.5, -7.0]);
+
+        assert!(matches!(view.read_u32("mana"), Err(SystemError::NotFound(_))));
+        assert!(matches!(view.read_u32("speed"), Err(SystemError::InvalidArgument(_))));
+        assert!(view.write_bytes("tag", b"TOOLONG").is_err());
+        assert_eq!(view.offset_of("y"), Some(base_offset + 16));
+    }
+
+    #[test]
+    fn test_scan_pattern_in_own_memory() {
+        let instance = ProcessMemoryInstance::new_by_pid(std::process::id()).unwrap();
+        let marker: Vec<u8> = vec![0xDE, 0xAD, 0x5C, 0xA7, 0x13, 0x37, 0xBE, 0xEF];
+        let address = marker.as_ptr() as usize;
+
+        let range = Some(address - 64..address + 64);
+        assert_eq!(instance.scan_first("DE AD ?? A7 13 37 BE EF", range.clone()).unwrap(), Some(address));
+        assert_eq!(instance.scan_pattern_in("DE AD ?? A7 13 37 BE EF", range).unwrap(), vec![address]);
+    }
+
+    #[test]
+    fn test_find_string_in_own_memory() {
+        let instance = ProcessMemoryInstance::new_by_pid(std::process::id()).unwrap();
+        // 运行时生成，确保字符串位于堆上而不只是只读数据段中
+        let nanos = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().subsec_nanos();
+        let needle = format!("lycrex-find-string-{}-{nanos}", std::process::id());
+        let utf16: Vec<u16> = needle.encode_utf16().collect();
+
+        let found = instance.find_string(&needle, crate::system::common::StrEncoding::Utf8).unwrap();
+        assert!(found.contains(&(needle.as_ptr() as usize)), "{found:x?}");
+        assert!(found.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let found = instance.find_string(&needle, crate::system::common::StrEncoding::Utf16Le).unwrap();
+        assert!(found.contains(&(utf16.as_ptr() as usize)), "{found:x?}");
+    }
+
+    #[test]
+    fn test_resolve_pointer_chain() {
+        let instance = ProcessMemoryInstance::new_by_pid(std::process::id()).unwrap();
+
+        // root -> [_, node] ; node -> [_, _, value]
+        let value: u64 = 0xC0FF_EE00_1234_5678;
+        let node: [usize; 3] = [0, 0, &value as *const u64 as usize];
+        let root: [usize; 2] = [0, node.as_ptr() as usize];
+        let slot = Box::new(root.as_ptr() as usize);
+
+        let base_offset = (&*slot as *const usize as usize).wrapping_sub(instance.base_address());
+        let ptr = std::mem::size_of::<usize>();
+        assert_eq!(instance.read_chain_u64(base_offset, &[ptr, 2 * ptr, 0]).unwrap(), value);
+        assert!(instance.resolve_pointer_chain(base_offset, &[0, 0]).is_err());
+    }
+
+    #[test]
+    fn test_list_modules_contains_main_executable() {
+        let instance = ProcessMemoryInstance::new_by_pid(std::process::id()).unwrap();
+        let modules = instance.list_modules().unwrap();
+
+        let exe = std::env::current_exe().unwrap();
+        let exe_name = exe.file_name().unwrap().to_str().unwrap();
+        let main = modules.iter().find(|module| module.name == exe_name).unwrap();
+        assert_eq!(main.base_address, instance.base_address());
+        assert!(main.size > 0);
+        assert_eq!(instance.module_base(exe_name).unwrap(), Some(instance.base_address()));
+        assert_eq!(instance.module_base("no-such-module.so").unwrap(), None);
+    }
+
+    #[test]
+    fn test_hash_own_memory() {
+        use crate::system::memory::MemoryManager;
+        use crate::system::security::{HashAlgorithm, Hasher};
+
+        let manager = MemoryManager::new();
+        let pid = std::process::id();
+        // 跨越多个 64 KiB 读取块，且长度不是块大小的整数倍
+        let data: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        let mut expected = Hasher::new(HashAlgorithm::Sha256);
+        expected.update(&data);
+        let hash = manager.hash_region(pid, data.as_ptr() as usize, data.len(), HashAlgorithm::Sha256).unwrap();
+        assert_eq!(hash, expected.finalize_hex());
+
+        // [vsyscall] 等不可读的可执行映射会被跳过，代码段不变时结果稳定
+        let first = manager.hash_executable_regions(pid, HashAlgorithm::Sha256).unwrap();
+        assert_eq!(first.len(), 64);
+        assert_eq!(manager.hash_executable_regions(pid, HashAlgorithm::Sha256).unwrap(), first);
+    }
+
+    #[test]
+    fn test_list_regions_covers_own_memory() {
+        let instance = ProcessMemoryInstance::new_by_pid(std::process::id()).unwrap();
+        let regions = instance.list_regions().unwrap();
+        assert!(regions.windows(2).all(|w| w[0].base_address + w[0].size <= w[1].base_address));
+
+        let protection_at = |address: usize| {
+            regions
+                .iter()
+                .find(|region| region.base_address <= address && address < region.base_address + region.size)
+                .map(|region| region.protection)
+                .unwrap()
+        };
+        let value = Box::new(0u64);
+        let heap = protection_at(&*value as *const u64 as usize);
+        assert!(heap.is_readable() && heap.is_writable());
+        let code = protection_at(test_list_regions_covers_own_memory as *const () as usize);
+        assert!(code.is_readable() && code.is_executable() && !code.is_writable());
+    }
+
+    #[test]
+    fn test_protect_own_page() {
+        use crate::system::common::MemoryPermission;
+
+        let pid = std::process::id();
+        let instance = ProcessMemoryInstance::new_by_pid(pid).unwrap();
+        let page = crate::system::memory::utils::page_size();
+        let prot = libc::PROT_READ | libc::PROT_WRITE;
+        let flags = libc::MAP_PRIVATE | libc::MAP_ANONYMOUS;
+        let address = unsafe { libc::mmap(std::ptr::null_mut(), page, prot, flags, -1, 0) };
+        assert_ne!(address, libc::MAP_FAILED);
+        let offset = (address as usize).wrapping_sub(instance.base_address());
+
+        let protection = || {
+            let regions = instance.list_regions().unwrap();
+            regions.iter().find(|region| region.base_address == address as usize).unwrap().protection
+        };
+        assert_eq!(instance.protect(offset, 16, MemoryPermission::READ).unwrap(), MemoryPermission::ReadWrite);
+        assert_eq!(protection(), MemoryPermission::READ);
+        assert_eq!(instance.protect(offset, 16, MemoryPermission::ReadWrite).unwrap(), MemoryPermission::READ);
+        assert_eq!(protection(), MemoryPermission::ReadWrite);
+
+        let read_only = ProcessMemoryInstance::new_by_pid_with_access(pid, AccessRights::ReadOnly).unwrap();
+        assert!(read_only.protect(offset, 16, MemoryPermission::READ).is_err());
+        unsafe { libc::munmap(address, page) };
+    }
+}
@@ -85,6 +85,17 @@ pub mod implementation {
         user_wired_count: u16,
     }
 
+    const TASK_DYLD_INFO: u32 = 17;
+
+    /// task_info(TASK_DYLD_INFO) 的返回结构，C 端为 4 字节对齐
+    #[repr(C, packed(4))]
+    #[derive(Default)]
+    struct TaskDyldInfo {
+        all_image_info_addr: u64,
+        all_image_info_size: u64,
+        all_image_info_format: i32,
+    }
+
     extern "C" {
         fn task_for_pid(target_task: TaskT, pid: PidT, task: *mut TaskT) -> KernReturn;
         fn mach_task_self() -> TaskT;
@@ -117,6 +128,12 @@ pub mod implementation {
             info_count: *mut u32,
             object_name: *mut u32,
         ) -> KernReturn;
+        fn task_info(
+            target_task: TaskT,
+            flavor: u32,
+            task_info_out: *mut TaskDyldInfo,
+            task_info_count: *mut u32,
+        ) -> KernReturn;
         #[allow(unused)]
         fn getpid() -> PidT;
     }
@@ -597,6 +614,190 @@ pub mod implementation {
         ))
     }
 
+    /// 列出进程的内存区域
+    pub fn list_memory_regions(pid: u32) -> SystemResult<Vec<MemoryRegion>> {
+        let task = get_task_for_pid(pid)?;
+        Ok(query_memory_regions(task))
+    }
+
+    /// 通过 vm_region_64 遍历进程地址空间
+    pub fn query_memory_regions(task: TaskPort) -> Vec<MemoryRegion> {
+        const VM_REGION_BASIC_INFO_64: u32 = 9;
+
+        let mut regions = Vec::new();
+        let mut address: VmAddress = 0;
+
+        loop {
+            let mut size: VmSize = 0;
+            let mut info = VmRegionBasicInfo64 {
+                protection: 0,
+                max_protection: 0,
+                inheritance: VM_INHERIT_SHARE,
+                shared: 0,
+                reserved: 0,
+                offset: 0,
+                behavior: 0,
+                user_wired_count: 0,
+            };
+            let mut info_count = std::mem::size_of::<VmRegionBasicInfo64>() as u32 / 4;
+            let mut object_name: u32 = 0;
+
+            let kern_return = unsafe {
+                vm_region_64(
+                    task,
+                    &mut address,
+                    &mut size,
+                    VM_REGION_BASIC_INFO_64,
+                    &mut info,
+                    &mut info_count,
+                    &mut object_name,
+                )
+            };
+            if kern_return != KERN_SUCCESS || size == 0 {
+                break;
+            }
+
+            regions.push(MemoryRegion {
+                base_address: address,
+                size,
+                protection: MemoryPermission::from_flags(
+                    info.protection & VM_PROT_READ != 0,
+                    info.protection & VM_PROT_WRITE != 0,
+                    info.protection & VM_PROT_EXECUTE != 0,
+                ),
+                is_committed: true,
+                is_private: info.shared == 0,
+                module_name: None,
+            });
+
+            match address.checked_add(size) {
+                Some(next) => address = next,
+                None => break,
+            }
+        }
+
+        regions
+    }
+
+    /// 修改进程内存页的保护属性，返回起始地址所在区域原来的保护属性
+    /// vm_protect 不返回旧值，因此先通过 vm_region_64 查询
+    pub fn protect_memory(
+        task: TaskPort,
+        address: usize,
+        size: usize,
+        permission: MemoryPermission,
+    ) -> std::io::Result<MemoryPermission> {
+        let previous = query_memory_regions(task)
+            .into_iter()
+            .find(|region| region.base_address <= address && address - region.base_address < region.size)
+            .map(|region| region.protection)
+            .ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::NotFound, format!("Address 0x{:X} is not mapped", address))
+            })?;
+
+        let mut protection = 0;
+        if permission.is_readable() { protection |= VM_PROT_READ; }
+        if permission.is_writable() { protection |= VM_PROT_WRITE; }
+        if permission.is_executable() { protection |= VM_PROT_EXECUTE; }
+
+        let (start, len) = crate::system::memory::utils::page_range(address, size);
+        let kern_return = unsafe { vm_protect(task, start, len, false, protection) };
+        if kern_return == KERN_SUCCESS {
+            Ok(previous)
+        } else {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::PermissionDenied,
+                format!("vm_protect failed with code: {}", kern_return)
+            ))
+        }
+    }
+
+    /// 列出进程加载的镜像（主程序与 dylib）
+    /// 基址来自目标进程中 dyld 的 dyld_all_image_infos，大小为从基址开始、
+    /// 到下一个镜像基址或地址空间空洞为止的连续 vm_region 之和
+    pub fn list_modules(pid: u32) -> SystemResult<Vec<ModuleInfo>> {
+        // dyld_all_image_infos: { u32 version; u32 infoArrayCount; dyld_image_info* infoArray; ... }
+        // dyld_image_info: { mach_header* imageLoadAddress; char* imageFilePath; uintptr_t imageFileModDate }
+        const IMAGE_INFO_SIZE: usize = 3 * std::mem::size_of::<usize>();
+
+        let task = get_task_for_pid(pid)?;
+        let read = |address: usize, size: usize| {
+            read_process_memory(task, address, size)
+                .map_err(|e| SystemError::MemoryError(format!("Failed to read dyld image info: {}", e)))
+        };
+
+        let mut dyld_info = TaskDyldInfo::default();
+        let mut count = (std::mem::size_of::<TaskDyldInfo>() / 4) as u32;
+        let kern_return = unsafe { task_info(task, TASK_DYLD_INFO, &mut dyld_info, &mut count) };
+        if kern_return != KERN_SUCCESS {
+            return Err(SystemError::ProcessError(format!("task_info(TASK_DYLD_INFO) failed with code: {}", kern_return)));
+        }
+
+        let all_images = dyld_info.all_image_info_addr as usize;
+        let header = read(all_images, 16)?;
+        let image_count = crate::system::memory::utils::bytes_to_u32(&header[4..8]) as usize;
+        let info_array = crate::system::memory::utils::bytes_to_u64(&header[8..16]) as usize;
+        let infos = read(info_array, image_count * IMAGE_INFO_SIZE)?;
+
+        let mut images: Vec<(usize, String)> = infos
+            .chunks_exact(IMAGE_INFO_SIZE)
+            .filter_map(|info| {
+                let load_address = crate::system::memory::utils::bytes_to_u64(&info[0..8]) as usize;
+                let path_address = crate::system::memory::utils::bytes_to_u64(&info[8..16]) as usize;
+                (load_address != 0).then(|| (load_address, read_c_string(task, path_address)))
+            })
+            .collect();
+        images.sort_by_key(|(base, _)| *base);
+
+        let regions = query_memory_regions(task);
+        let modules = images
+            .iter()
+            .enumerate()
+            .map(|(index, (base, path))| {
+                let limit = images.get(index + 1).map(|(next, _)| *next).unwrap_or(usize::MAX);
+                let mut end = *base;
+                for region in regions.iter().filter(|r| r.base_address.saturating_add(r.size) > *base) {
+                    if region.base_address > end || region.base_address >= limit {
+                        break;
+                    }
+                    end = region.base_address.saturating_add(region.size).min(limit);
+                }
+
+                ModuleInfo {
+                    name: std::path::Path::new(path)
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                        .unwrap_or_else(|| path.clone()),
+                    path: if path.is_empty() { None } else { Some(path.clone()) },
+                    base_address: *base,
+                    size: end - base,
+                }
+            })
+            .collect();
+
+        Ok(modules)
+    }
+
+    /// 读取目标进程中以 NUL 结尾的字符串，按页边界分段读取避免越过未映射页面
+    fn read_c_string(task: TaskPort, address: usize) -> String {
+        const MAX_LEN: usize = 4096;
+
+        let page = crate::system::memory::utils::page_size();
+        let mut bytes = Vec::new();
+        let mut cursor = address;
+        while address != 0 && bytes.len() < MAX_LEN {
+            let len = page - cursor % page;
+            let Ok(chunk) = read_process_memory(task, cursor, len) else { break };
+            if let Some(nul) = chunk.iter().position(|&b| b == 0) {
+                bytes.extend_from_slice(&chunk[..nul]);
+                break;
+            }
+            bytes.extend_from_slice(&chunk);
+            cursor += len;
+        }
+        String::from_utf8_lossy(&bytes).to_string()
+    }
+
     /// 进程内存操作实例
     pub struct ProcessInstance {
         task: TaskPort,
@@ -759,6 +960,14 @@ pub mod implementation {
         Err(SystemError::NotSupported("macOS memory operations only available on macOS".to_string()))
     }
 
+    pub fn list_memory_regions(_pid: u32) -> SystemResult<Vec<MemoryRegion>> {
+        Err(SystemError::NotSupported("macOS memory operations only available on macOS".to_string()))
+    }
+
+    pub fn list_modules(_pid: u32) -> SystemResult<Vec<ModuleInfo>> {
+        Err(SystemError::NotSupported("macOS memory operations only available on macOS".to_string()))
+    }
+
     pub fn read_process_memory(_task: TaskPort, _address: usize, _size: usize) -> std::io::Result<Vec<u8>> {
         Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "macOS memory operations only available on macOS"))
     }
@@ -767,6 +976,10 @@ pub mod implementation {
         Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "macOS memory operations only available on macOS"))
     }
 
+    pub fn protect_memory(_task: TaskPort, _address: usize, _size: usize, _permission: MemoryPermission) -> std::io::Result<MemoryPermission> {
+        Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "macOS memory operations only available on macOS"))
+    }
+
     pub struct ProcessInstance;
 
     impl ProcessInstance {
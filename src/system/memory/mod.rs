@@ -2,6 +2,8 @@
 // 提供跨平台的内存读写、进程内存操作等功能
 
 use crate::system::common::*;
+use crate::system::security::{HashAlgorithm, Hasher};
+use std::ops::Range;
 
 // 平台特定的内存操作模块
 #[cfg(target_os = "windows")]
@@ -16,6 +18,10 @@ pub mod linux;
 // 通用内存工具
 pub mod utils;
 
+// 带字段名的内存视图
+mod view;
+pub use view::*;
+
 /// 内存管理器
 pub struct MemoryManager;
 
@@ -35,6 +41,16 @@ impl MemoryManager {
         ProcessMemoryInstance::new_by_pid(pid)
     }
 
+    /// 根据进程名以指定访问权限创建进程内存实例
+    pub fn create_process_instance_by_name_with_access(&self, process_name: &str, access: AccessRights) -> SystemResult<ProcessMemoryInstance> {
+        ProcessMemoryInstance::new_by_name_with_access(process_name, access)
+    }
+
+    /// 根据PID以指定访问权限创建进程内存实例
+    pub fn create_process_instance_by_pid_with_access(&self, pid: u32, access: AccessRights) -> SystemResult<ProcessMemoryInstance> {
+        ProcessMemoryInstance::new_by_pid_with_access(pid, access)
+    }
+
     /// 获取进程内存信息
     pub fn get_process_memory_info(&self, pid: u32) -> SystemResult<ProcessMemoryInfo> {
         #[cfg(target_os = "windows")]
@@ -79,6 +95,77 @@ impl MemoryManager {
         #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
         Err(SystemError::NotSupported("Platform not supported".to_string()))
     }
+
+    /// 列出进程的内存区域，按地址排序
+    pub fn list_memory_regions(&self, pid: u32) -> SystemResult<Vec<MemoryRegion>> {
+        #[cfg(target_os = "windows")]
+        let result = windows::list_memory_regions(pid);
+
+        #[cfg(target_os = "macos")]
+        let result = macos::list_memory_regions(pid);
+
+        #[cfg(target_os = "linux")]
+        let result = linux::list_memory_regions(pid);
+
+        #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+        let result: SystemResult<Vec<MemoryRegion>> = Err(SystemError::NotSupported("Platform not supported".to_string()));
+
+        let mut regions = result?;
+        regions.sort_by_key(|region| region.base_address);
+        Ok(regions)
+    }
+
+    /// 计算进程内存区域的哈希值（小写十六进制）
+    /// 按块读取并流式送入哈希计算，不会一次性把整个区域读入内存
+    pub fn hash_region(&self, pid: u32, start: usize, size: usize, algo: HashAlgorithm) -> SystemResult<String> {
+        let instance = self.create_process_instance_by_pid(pid)?;
+        let mut hasher = Hasher::new(algo);
+        Self::hash_into(&instance, start, size, &mut hasher)?;
+        Ok(hasher.finalize_hex())
+    }
+
+    /// 按地址顺序计算进程所有可读可执行区域的整体哈希值，用于检测内存代码被篡改
+    /// 不可读的区域（如 Linux 上 `--xp` 的 `[vsyscall]`）以及内核特殊映射不参与计算
+    pub fn hash_executable_regions(&self, pid: u32, algo: HashAlgorithm) -> SystemResult<String> {
+        /// 由内核提供、无法通过进程内存接口读取的特殊映射
+        const SPECIAL_MAPPINGS: &[&str] = &["[vsyscall]", "[vvar]", "[vvar_vclock]"];
+
+        let instance = self.create_process_instance_by_pid(pid)?;
+        let regions = instance.list_regions()?;
+        let mut hasher = Hasher::new(algo);
+
+        let hashable = regions.iter().filter(|r| {
+            r.is_committed
+                && r.protection.is_readable()
+                && r.protection.is_executable()
+                && !r.module_name.as_deref().is_some_and(|name| SPECIAL_MAPPINGS.contains(&name))
+        });
+        for region in hashable {
+            Self::hash_into(&instance, region.base_address, region.size, &mut hasher)?;
+        }
+
+        Ok(hasher.finalize_hex())
+    }
+
+    fn hash_into(instance: &ProcessMemoryInstance, start: usize, size: usize, hasher: &mut Hasher) -> SystemResult<()> {
+        const HASH_CHUNK_SIZE: usize = 64 * 1024;
+
+        let mut offset = 0;
+        while offset < size {
+            let len = HASH_CHUNK_SIZE.min(size - offset);
+            let address = start + offset;
+            let chunk = instance.read_memory_at(address, len)?;
+            if chunk.len() != len {
+                return Err(SystemError::MemoryError(format!(
+                    "Short read at 0x{:X}: expected {} bytes, got {}", address, len, chunk.len()
+                )));
+            }
+            hasher.update(&chunk);
+            offset += len;
+        }
+
+        Ok(())
+    }
 }
 
 /// 进程内存操作实例
@@ -87,61 +174,109 @@ pub struct ProcessMemoryInstance {
     pid: u32,
     process_name: Option<String>,
     base_address: usize,
+    access: AccessRights,
     #[cfg(target_os = "windows")]
     handle: windows::ProcessHandle,
     #[cfg(target_os = "macos")]
     task: macos::TaskPort,
+    #[cfg(target_os = "linux")]
+    mem: std::fs::File,
 }
 
 impl ProcessMemoryInstance {
-    /// 通过进程名创建实例
+    /// 通过进程名创建实例（读写权限）
     pub fn new_by_name(process_name: &str) -> SystemResult<Self> {
+        Self::new_by_name_with_access(process_name, AccessRights::default())
+    }
+
+    /// 通过PID创建实例（读写权限）
+    pub fn new_by_pid(pid: u32) -> SystemResult<Self> {
+        Self::new_by_pid_with_access(pid, AccessRights::default())
+    }
+
+    /// 通过进程名以指定访问权限创建实例
+    /// Windows 上只请求对应的最小 OpenProcess 权限，只读工具因此可以附加到更多进程；
+    /// macOS 上 task port 不区分读写，权限仅用于拒绝只读实例的写入操作；
+    /// Linux 上只读实例以只读方式打开 /proc/<pid>/mem
+    pub fn new_by_name_with_access(process_name: &str, access: AccessRights) -> SystemResult<Self> {
         #[cfg(target_os = "windows")]
-        return windows::ProcessInstance::new_by_name(process_name)
+        return windows::ProcessInstance::new_by_name_with_access(process_name, access)
             .map_err(|e| SystemError::ProcessError(format!("Failed to create Windows process instance: {}", e)))
             .and_then(|instance| Ok(Self::from_windows_instance(instance)));
         
         #[cfg(target_os = "macos")]
         return macos::ProcessInstance::new_by_name(process_name)
             .map_err(|e| SystemError::ProcessError(format!("Failed to create macOS process instance: {}", e)))
-            .and_then(|instance| Ok(Self::from_macos_instance(instance)));
+            .and_then(|instance| Ok(Self::from_macos_instance(instance, access)));
+
+        #[cfg(target_os = "linux")]
+        return linux::ProcessInstance::new_by_name_with_access(process_name, access)
+            .map_err(|e| Self::linux_error("Failed to create Linux process instance", e))
+            .map(Self::from_linux_instance);
         
-        #[cfg(not(any(target_os = "windows", target_os = "macos")))]
-        Err(SystemError::NotSupported("Platform not supported".to_string()))
+        #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+        {
+            let _ = (process_name, access);
+            Err(SystemError::NotSupported("Platform not supported".to_string()))
+        }
     }
 
-    /// 通过PID创建实例
-    pub fn new_by_pid(pid: u32) -> SystemResult<Self> {
+    /// 通过PID以指定访问权限创建实例
+    pub fn new_by_pid_with_access(pid: u32, access: AccessRights) -> SystemResult<Self> {
         #[cfg(target_os = "windows")]
-        return windows::ProcessInstance::new_by_pid(pid)
+        return windows::ProcessInstance::new_by_pid_with_access(pid, access)
             .map_err(|e| SystemError::ProcessError(format!("Failed to create Windows process instance: {}", e)))
             .and_then(|instance| Ok(Self::from_windows_instance(instance)));
         
         #[cfg(target_os = "macos")]
         return macos::ProcessInstance::new_by_pid(pid)
             .map_err(|e| SystemError::ProcessError(format!("Failed to create macOS process instance: {}", e)))
-            .and_then(|instance| Ok(Self::from_macos_instance(instance)));
+            .and_then(|instance| Ok(Self::from_macos_instance(instance, access)));
+
+        #[cfg(target_os = "linux")]
+        return linux::ProcessInstance::new_by_pid_with_access(pid, access)
+            .map_err(|e| Self::linux_error("Failed to create Linux process instance", e))
+            .map(Self::from_linux_instance);
         
-        #[cfg(not(any(target_os = "windows", target_os = "macos")))]
-        Err(SystemError::NotSupported("Platform not supported".to_string()))
+        #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+        {
+            let _ = (pid, access);
+            Err(SystemError::NotSupported("Platform not supported".to_string()))
+        }
     }
 
     /// 读取内存
     pub fn read_memory(&self, offset: usize, size: usize) -> SystemResult<Vec<u8>> {
+        self.read_memory_at(self.base_address + offset, size)
+    }
+
+    /// 按绝对地址读取内存
+    pub fn read_memory_at(&self, address: usize, size: usize) -> SystemResult<Vec<u8>> {
         #[cfg(target_os = "windows")]
-        return windows::read_process_memory(self.handle, self.base_address + offset, size)
+        return windows::read_process_memory(self.handle, address, size)
             .map_err(|e| SystemError::MemoryError(format!("Windows memory read failed: {}", e)));
         
         #[cfg(target_os = "macos")]
-        return macos::read_process_memory(self.task, self.base_address + offset, size)
+        return macos::read_process_memory(self.task, address, size)
             .map_err(|e| SystemError::MemoryError(format!("macOS memory read failed: {}", e)));
+
+        #[cfg(target_os = "linux")]
+        return linux::read_pid_memory(self.pid, std::os::unix::io::AsRawFd::as_raw_fd(&self.mem), address, size)
+            .map_err(|e| Self::linux_error("Linux memory read failed", e));
         
-        #[cfg(not(any(target_os = "windows", target_os = "macos")))]
-        Err(SystemError::NotSupported("Platform not supported".to_string()))
+        #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+        {
+            let _ = (address, size);
+            Err(SystemError::NotSupported("Platform not supported".to_string()))
+        }
     }
 
     /// 写入内存
     pub fn write_memory(&self, offset: usize, data: &[u8]) -> SystemResult<MemoryOperationResult> {
+        if !self.access.can_write() {
+            return Err(SystemError::PermissionDenied(format!("Process {} was opened read-only", self.pid)));
+        }
+
         #[cfg(target_os = "windows")]
         return windows::write_process_memory(self.handle, self.base_address + offset, data)
             .map(|bytes_written| MemoryOperationResult {
@@ -163,9 +298,83 @@ impl ProcessMemoryInstance {
                 } else { None },
             })
             .map_err(|e| SystemError::MemoryError(format!("macOS memory write failed: {}", e)));
+
+        #[cfg(target_os = "linux")]
+        return linux::write_pid_memory(self.pid, std::os::unix::io::AsRawFd::as_raw_fd(&self.mem), self.base_address + offset, data)
+            .map(|bytes_written| MemoryOperationResult {
+                success: bytes_written == data.len(),
+                bytes_processed: bytes_written,
+                error_message: if bytes_written != data.len() { 
+                    Some(format!("Only wrote {} of {} bytes", bytes_written, data.len()))
+                } else { None },
+            })
+            .map_err(|e| Self::linux_error("Linux memory write failed", e));
         
-        #[cfg(not(any(target_os = "windows", target_os = "macos")))]
-        Err(SystemError::NotSupported("Platform not supported".to_string()))
+        #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+        {
+            let _ = (offset, data);
+            Err(SystemError::NotSupported("Platform not supported".to_string()))
+        }
+    }
+
+    /// 批量读取多个 `(偏移, 大小)` 范围（相对于模块基址），结果与输入一一对应
+    /// Linux 上通过 process_vm_readv 把全部范围合并为一次系统调用，其他平台逐个读取
+    pub fn read_many(&self, reads: &[(usize, usize)]) -> SystemResult<Vec<Vec<u8>>> {
+        let ranges: Vec<(usize, usize)> = reads
+            .iter()
+            .map(|&(offset, size)| (self.base_address.wrapping_add(offset), size))
+            .collect();
+
+        #[cfg(target_os = "linux")]
+        return linux::read_process_memory_many(self.pid, std::os::unix::io::AsRawFd::as_raw_fd(&self.mem), &ranges)
+            .map_err(|e| Self::linux_error("Linux memory read failed", e));
+
+        #[cfg(not(target_os = "linux"))]
+        ranges.iter().map(|&(address, size)| self.read_memory_at(address, size)).collect()
+    }
+
+    /// 一次读取 `size_of::<T>()` 字节并按 T 解释（相对于模块基址的偏移）
+    /// 适合按 `#[repr(C)]` 结构体整体读取，避免逐字段读取带来的多次系统调用
+    pub fn read_struct<T: utils::FromBytes>(&self, offset: usize) -> SystemResult<T> {
+        let size = std::mem::size_of::<T>();
+        let bytes = self.read_memory(offset, size)?;
+        utils::from_bytes(&bytes).ok_or_else(|| {
+            SystemError::MemoryError(format!(
+                "Short read at offset 0x{:X}: expected {} bytes, got {}", offset, size, bytes.len()
+            ))
+        })
+    }
+
+    /// 修改内存页的保护属性（相对于模块基址的偏移），返回修改前起始地址所在页的保护属性
+    /// 范围会扩展到完整的页；常用于在只读代码段打补丁前临时加上写权限，之后再用返回值恢复。
+    /// Windows 使用 VirtualProtectEx，macOS 使用 vm_protect；
+    /// Linux 没有修改其他进程页保护的系统调用，只支持当前进程（mprotect），其他进程返回 NotSupported，
+    /// 但 Linux 上的 write_memory 经由 /proc/<pid>/mem 写入，本身就能写入只读页面
+    pub fn protect(&self, offset: usize, size: usize, perm: MemoryPermission) -> SystemResult<MemoryPermission> {
+        if !self.access.can_write() {
+            return Err(SystemError::PermissionDenied(format!("Process {} was opened read-only", self.pid)));
+        }
+        if size == 0 {
+            return Err(SystemError::InvalidArgument("Protection range is empty".to_string()));
+        }
+        let address = self.base_address.wrapping_add(offset);
+
+        #[cfg(target_os = "windows")]
+        return windows::protect_memory(self.handle, address, size, perm)
+            .map_err(|e| SystemError::MemoryError(format!("Windows memory protect failed: {}", e)));
+
+        #[cfg(target_os = "macos")]
+        return macos::protect_memory(self.task, address, size, perm)
+            .map_err(|e| SystemError::MemoryError(format!("macOS memory protect failed: {}", e)));
+
+        #[cfg(target_os = "linux")]
+        return linux::protect_memory(self.pid, address, size, perm);
+
+        #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+        {
+            let _ = (address, perm);
+            Err(SystemError::NotSupported("Platform not supported".to_string()))
+        }
     }
 
     /// 读取 u32 值
@@ -180,12 +389,63 @@ impl ProcessMemoryInstance {
         Ok(utils::bytes_to_u64(&bytes))
     }
 
+    /// 读取 f32 值
+    pub fn read_f32(&self, offset: usize) -> SystemResult<f32> {
+        Ok(f32::from_bits(self.read_u32(offset)?))
+    }
+
+    /// 读取 f64 值
+    pub fn read_f64(&self, offset: usize) -> SystemResult<f64> {
+        Ok(f64::from_bits(self.read_u64(offset)?))
+    }
+
     /// 读取 UTF-8 字符串
     pub fn read_utf8_string(&self, offset: usize, size: usize) -> SystemResult<String> {
         let bytes = self.read_memory(offset, size)?;
         Ok(utils::bytes_to_utf8_string(&bytes))
     }
 
+    /// 读取以 0 结尾的 UTF-16LE 字符串（Windows 宽字符串），最多读取 max_chars 个 u16
+    /// 未遇到终止符时返回前 max_chars 个 u16 解码的结果
+    pub fn read_utf16_string(&self, offset: usize, max_chars: usize) -> SystemResult<String> {
+        let bytes = self.read_terminated(offset, max_chars.saturating_mul(2), 2)?;
+        Ok(utils::bytes_to_utf16_string(&bytes))
+    }
+
+    /// 读取以 0 结尾的 UTF-8/ASCII 字符串，长度未知时使用，最多读取 max_len 字节
+    pub fn read_cstring(&self, offset: usize, max_len: usize) -> SystemResult<String> {
+        let bytes = self.read_terminated(offset, max_len, 1)?;
+        Ok(utils::bytes_to_utf8_string(&bytes))
+    }
+
+    /// 按页分块读取，直到遇到 unit 字节宽的全 0 终止符或读满 max_len 字节，返回终止符之前的字节
+    /// 每块不跨越页边界，因此字符串后面的页面未映射时不会导致读取失败
+    fn read_terminated(&self, offset: usize, max_len: usize, unit: usize) -> SystemResult<Vec<u8>> {
+        let page = utils::page_size();
+        let start = self.base_address.wrapping_add(offset);
+        let mut bytes = Vec::new();
+
+        while bytes.len() < max_len {
+            let address = start.wrapping_add(bytes.len());
+            let len = (page - address % page).min(max_len - bytes.len());
+            let chunk = self.read_memory_at(address, len)?;
+            let short = chunk.len() < len;
+            bytes.extend_from_slice(&chunk);
+
+            if let Some(end) = utils::find_terminator(&bytes, unit) {
+                bytes.truncate(end);
+                return Ok(bytes);
+            }
+            // Windows 上部分读取成功时返回的数据会变短
+            if short {
+                break;
+            }
+        }
+
+        bytes.truncate(bytes.len() - bytes.len() % unit);
+        Ok(bytes)
+    }
+
     /// 写入 u32 值
     pub fn write_u32(&self, offset: usize, value: u32) -> SystemResult<MemoryOperationResult> {
         self.write_memory(offset, &value.to_le_bytes())
@@ -196,36 +456,276 @@ impl ProcessMemoryInstance {
         self.write_memory(offset, &value.to_le_bytes())
     }
 
+    /// 写入 f32 值
+    pub fn write_f32(&self, offset: usize, value: f32) -> SystemResult<MemoryOperationResult> {
+        self.write_memory(offset, &value.to_le_bytes())
+    }
+
+    /// 写入 f64 值
+    pub fn write_f64(&self, offset: usize, value: f64) -> SystemResult<MemoryOperationResult> {
+        self.write_memory(offset, &value.to_le_bytes())
+    }
+
     /// 写入 UTF-8 字符串
     pub fn write_utf8_string(&self, offset: usize, s: &str) -> SystemResult<MemoryOperationResult> {
         self.write_memory(offset, s.as_bytes())
     }
 
+    /// 写入 UTF-16LE 字符串，末尾附加 u16 的 0 终止符
+    pub fn write_utf16_string(&self, offset: usize, s: &str) -> SystemResult<MemoryOperationResult> {
+        self.write_memory(offset, &utils::utf16_string_to_bytes(s))
+    }
+
+    /// 列出进程加载的模块（主程序、DLL、dylib、共享库），按基址排序
+    pub fn list_modules(&self) -> SystemResult<Vec<ModuleInfo>> {
+        #[cfg(target_os = "windows")]
+        let result = windows::list_modules(self.pid);
+
+        #[cfg(target_os = "macos")]
+        let result = macos::list_modules(self.pid);
+
+        #[cfg(target_os = "linux")]
+        let result = linux::list_modules(self.pid);
+
+        #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+        let result: SystemResult<Vec<ModuleInfo>> = Err(SystemError::NotSupported("Platform not supported".to_string()));
+
+        let mut modules = result?;
+        modules.sort_by_key(|module| module.base_address);
+        Ok(modules)
+    }
+
+    /// 列出进程的内存区域（起始地址、大小和读/写/执行权限），按地址排序
+    /// 复用实例已打开的句柄：Windows 上通过 VirtualQueryEx，macOS 上通过 vm_region_64，
+    /// Linux 上解析 /proc/<pid>/maps；Windows 与 macOS 只返回已提交的区域
+    pub fn list_regions(&self) -> SystemResult<Vec<MemoryRegion>> {
+        #[cfg(target_os = "windows")]
+        let result: SystemResult<Vec<MemoryRegion>> = Ok(windows::query_memory_regions(self.handle));
+
+        #[cfg(target_os = "macos")]
+        let result: SystemResult<Vec<MemoryRegion>> = Ok(macos::query_memory_regions(self.task));
+
+        #[cfg(target_os = "linux")]
+        let result = linux::list_memory_regions(self.pid);
+
+        #[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+        let result: SystemResult<Vec<MemoryRegion>> = Err(SystemError::NotSupported("Platform not supported".to_string()));
+
+        let mut regions = result?;
+        regions.sort_by_key(|region| region.base_address);
+        Ok(regions)
+    }
+
+    /// 创建结构体视图，base_offset 为结构体相对于模块基址的偏移
+    /// 用 [`MemoryView::field`] 注册字段后即可按名字读写，如 `view.read_u32("health")`
+    pub fn view(&self, base_offset: usize) -> MemoryView<'_> {
+        MemoryView::new(self, base_offset)
+    }
+
+    /// 根据模块文件名查找基址（Windows 上不区分大小写）
+    pub fn module_base(&self, name: &str) -> SystemResult<Option<usize>> {
+        let matches = |module: &ModuleInfo| {
+            if cfg!(target_os = "windows") {
+                module.name.eq_ignore_ascii_case(name)
+            } else {
+                module.name == name
+            }
+        };
+        Ok(self.list_modules()?.iter().find(|module| matches(module)).map(|module| module.base_address))
+    }
+
+    /// 读取绝对地址处的指针，宽度与目标架构的指针一致
+    pub fn read_pointer_at(&self, address: usize) -> SystemResult<usize> {
+        const POINTER_SIZE: usize = std::mem::size_of::<usize>();
+
+        let bytes = self.read_memory_at(address, POINTER_SIZE)?;
+        let bytes: [u8; POINTER_SIZE] = bytes.as_slice().try_into().map_err(|_| {
+            SystemError::MemoryError(format!("Short pointer read at 0x{:X}: got {} bytes", address, bytes.len()))
+        })?;
+        Ok(usize::from_le_bytes(bytes))
+    }
+
+    /// 解析多级指针，返回最终地址
+    /// 先读取 `base_address + base_offset` 处的指针，之后每一级加上偏移后解引用，
+    /// 最后一个偏移只相加不解引用（与 Cheat Engine 的指针链语义一致）
+    pub fn resolve_pointer_chain(&self, base_offset: usize, offsets: &[usize]) -> SystemResult<usize> {
+        let start = self.base_address.wrapping_add(base_offset);
+        let mut address = self.read_pointer_at(start).map_err(|e| {
+            SystemError::MemoryError(format!("Pointer chain failed at base 0x{:X}: {}", start, e))
+        })?;
+
+        for (level, offset) in offsets.iter().enumerate() {
+            if address == 0 {
+                return Err(SystemError::MemoryError(format!("Pointer chain hit a null pointer at level {}", level)));
+            }
+            address = address.wrapping_add(*offset);
+            if level + 1 < offsets.len() {
+                address = self.read_pointer_at(address).map_err(|e| {
+                    SystemError::MemoryError(format!("Pointer chain failed at level {} (0x{:X}): {}", level + 1, address, e))
+                })?;
+            }
+        }
+
+        Ok(address)
+    }
+
+    /// 解析指针链并读取最终地址处的 u32
+    pub fn read_chain_u32(&self, base_offset: usize, offsets: &[usize]) -> SystemResult<u32> {
+        let address = self.resolve_pointer_chain(base_offset, offsets)?;
+        let bytes = self.read_memory_at(address, 4)?;
+        Ok(utils::bytes_to_u32(&bytes))
+    }
+
+    /// 解析指针链并读取最终地址处的 u64
+    pub fn read_chain_u64(&self, base_offset: usize, offsets: &[usize]) -> SystemResult<u64> {
+        let address = self.resolve_pointer_chain(base_offset, offsets)?;
+        let bytes = self.read_memory_at(address, 8)?;
+        Ok(utils::bytes_to_u64(&bytes))
+    }
+
+    /// 在进程所有可读区域中搜索字符串，返回全部匹配的绝对地址（按地址排序）
+    pub fn find_string(&self, needle: &str, encoding: StrEncoding) -> SystemResult<Vec<usize>> {
+        let pattern = Self::encode_needle(needle, encoding)?;
+        self.scan_readable_regions(&pattern, None, usize::MAX)
+    }
+
+    /// 搜索字符串的第一个匹配地址
+    pub fn find_string_first(&self, needle: &str, encoding: StrEncoding) -> SystemResult<Option<usize>> {
+        let pattern = Self::encode_needle(needle, encoding)?;
+        Ok(self.scan_readable_regions(&pattern, None, 1)?.into_iter().next())
+    }
+
+    /// 在进程所有可读区域中搜索字节特征码（AOB），返回全部匹配的绝对地址（按地址排序）
+    /// 特征码形如 `"48 8B ?? C3"`，`??` 匹配任意字节
+    pub fn scan_pattern(&self, pattern: &str) -> SystemResult<Vec<usize>> {
+        self.scan_pattern_in(pattern, None)
+    }
+
+    /// 在指定地址范围内搜索字节特征码，range 为 None 时扫描全部可读区域
+    pub fn scan_pattern_in(&self, pattern: &str, range: Option<Range<usize>>) -> SystemResult<Vec<usize>> {
+        let pattern = utils::parse_byte_pattern(pattern)?;
+        self.scan_readable_regions(&pattern, range, usize::MAX)
+    }
+
+    /// 搜索字节特征码的第一个匹配地址，找到后立即停止扫描
+    pub fn scan_first(&self, pattern: &str, range: Option<Range<usize>>) -> SystemResult<Option<usize>> {
+        let pattern = utils::parse_byte_pattern(pattern)?;
+        Ok(self.scan_readable_regions(&pattern, range, 1)?.into_iter().next())
+    }
+
+    fn encode_needle(needle: &str, encoding: StrEncoding) -> SystemResult<Vec<Option<u8>>> {
+        if needle.is_empty() {
+            return Err(SystemError::InvalidArgument("Search string is empty".to_string()));
+        }
+        encoding
+            .encode(needle)
+            .map(|bytes| bytes.into_iter().map(Some).collect())
+            .ok_or_else(|| {
+                SystemError::InvalidArgument(format!("String cannot be encoded as {:?}: {}", encoding, needle))
+            })
+    }
+
+    /// 分块扫描已提交的可读区域（可限定在 range 内），最多返回 limit 个匹配
+    /// 相邻块之间重叠 pattern.len() - 1 字节，保证跨块的匹配不会遗漏
+    fn scan_readable_regions(&self, pattern: &[Option<u8>], range: Option<Range<usize>>, limit: usize) -> SystemResult<Vec<usize>> {
+        const SCAN_CHUNK_SIZE: usize = 1024 * 1024;
+
+        let range = range.unwrap_or(0..usize::MAX);
+        let regions = self.list_regions()?;
+        let mut matches = Vec::new();
+
+        for region in regions.iter().filter(|r| r.is_committed && r.protection.is_readable()) {
+            let end = region.base_address.saturating_add(region.size).min(range.end);
+            let mut address = region.base_address.max(range.start);
+
+            while address < end {
+                let len = (SCAN_CHUNK_SIZE + pattern.len() - 1).min(end - address);
+                let chunk = match self.read_memory_at(address, len) {
+                    Ok(chunk) => chunk,
+                    Err(e @ SystemError::NotSupported(_)) => return Err(e),
+                    // 保护页等无法读取的区域直接跳过
+                    Err(_) => break,
+                };
+
+                let mut start = 0;
+                while let Some(pos) = utils::find_masked_pattern(&chunk[start..], pattern) {
+                    let offset = start + pos;
+                    // 落在重叠部分的匹配留给下一块处理，避免重复
+                    if offset >= SCAN_CHUNK_SIZE {
+                        break;
+                    }
+                    matches.push(address + offset);
+                    if matches.len() >= limit {
+                        return Ok(matches);
+                    }
+                    start = offset + 1;
+                }
+
+                address = address.saturating_add(SCAN_CHUNK_SIZE);
+            }
+        }
+
+        Ok(matches)
+    }
+
     /// 获取进程信息
     pub fn pid(&self) -> u32 { self.pid }
     pub fn base_address(&self) -> usize { self.base_address }
     pub fn process_name(&self) -> Option<&str> { self.process_name.as_deref() }
+    pub fn access(&self) -> AccessRights { self.access }
 
     // 平台特定的转换函数
     #[cfg(target_os = "windows")]
     fn from_windows_instance(instance: windows::ProcessInstance) -> Self {
+        // 句柄的所有权转移到本实例，由 Drop 负责关闭
         Self {
             pid: instance.pid(),
             process_name: instance.name().map(|s| s.to_string()),
             base_address: instance.base_addr(),
-            handle: instance.handle(),
+            access: instance.access(),
+            handle: instance.into_handle(),
         }
     }
 
     #[cfg(target_os = "macos")]
-    fn from_macos_instance(instance: macos::ProcessInstance) -> Self {
+    fn from_macos_instance(instance: macos::ProcessInstance, access: AccessRights) -> Self {
         Self {
             pid: instance.pid(),
             process_name: instance.name().map(|s| s.to_string()),
             base_address: instance.base_addr(),
+            access,
             task: instance.task(),
         }
     }
+
+    #[cfg(target_os = "linux")]
+    fn from_linux_instance(instance: linux::ProcessInstance) -> Self {
+        // mem 文件随本实例一起关闭
+        Self {
+            pid: instance.pid(),
+            process_name: instance.name().map(|s| s.to_string()),
+            base_address: instance.base_addr(),
+            access: instance.access(),
+            mem: instance.into_file(),
+        }
+    }
+
+    /// ptrace 访问检查失败时返回 PermissionDenied，其余错误归为内存错误
+    #[cfg(target_os = "linux")]
+    fn linux_error(context: &str, err: std::io::Error) -> SystemError {
+        match err.kind() {
+            std::io::ErrorKind::PermissionDenied => SystemError::PermissionDenied(format!("{}: {}", context, err)),
+            std::io::ErrorKind::NotFound => SystemError::NotFound(format!("{}: {}", context, err)),
+            _ => SystemError::MemoryError(format!("{}: {}", context, err)),
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl Drop for ProcessMemoryInstance {
+    fn drop(&mut self) {
+        windows::close_process_handle(self.handle);
+    }
 }
 
 impl Default for MemoryManager {
@@ -1,3 +1,5 @@
+use crate::system::common::{SystemError, SystemResult};
+
 /// 将字节数组转换为 u32
 pub fn bytes_to_u32(bytes: &[u8]) -> u32 {
     if bytes.len() >= 4 {
@@ -31,6 +33,59 @@ pub fn bytes_to_utf8_string(bytes: &[u8]) -> String {
     }
 }
 
+/// 将 UTF-16LE 字节数组转换为字符串
+/// 在第一个值为 0 的 u16 处截断，末尾不足 2 字节的部分被忽略，解码失败时返回空字符串
+pub fn bytes_to_utf16_string(bytes: &[u8]) -> String {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .take_while(|&unit| unit != 0)
+        .collect();
+
+    String::from_utf16(&units).unwrap_or_default()
+}
+
+/// 将字符串编码为 UTF-16LE 字节数组，末尾附加 u16 的 0 终止符
+pub fn utf16_string_to_bytes(s: &str) -> Vec<u8> {
+    s.encode_utf16().chain(std::iter::once(0)).flat_map(|unit| unit.to_le_bytes()).collect()
+}
+
+/// 查找第一个按 unit 字节对齐、且全部为 0 的终止符，返回其字节偏移
+/// unit 为 1 时对应 C 字符串，为 2 时对应 UTF-16 字符串
+pub fn find_terminator(bytes: &[u8], unit: usize) -> Option<usize> {
+    bytes
+        .chunks_exact(unit)
+        .position(|chunk| chunk.iter().all(|&b| b == 0))
+        .map(|index| index * unit)
+}
+
+/// 可以直接从内存中的字节解释得到的类型
+///
+/// # Safety
+/// 实现者必须保证任意位模式都是合法的值，即类型中不含引用、指针所有权、`bool`、`char`、枚举等
+/// 存在无效位模式的字段；通常是只由整数、浮点数及其数组组成的 `#[repr(C)]` 结构体
+pub unsafe trait FromBytes: Copy {}
+
+macro_rules! impl_from_bytes {
+    ($($ty:ty),*) => {
+        $(unsafe impl FromBytes for $ty {})*
+    };
+}
+
+impl_from_bytes!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+unsafe impl<T: FromBytes, const N: usize> FromBytes for [T; N] {}
+
+/// 按本机字节序把字节序列解释为 T，长度不足时返回 None，多余的字节被忽略
+/// 不要求字节序列按 T 对齐
+pub fn from_bytes<T: FromBytes>(bytes: &[u8]) -> Option<T> {
+    if bytes.len() < std::mem::size_of::<T>() {
+        return None;
+    }
+    // SAFETY: 长度已检查，FromBytes 保证任意位模式都是合法的 T
+    Some(unsafe { std::ptr::read_unaligned(bytes.as_ptr() as *const T) })
+}
+
 /// 将字节数组转换为十六进制字符串
 pub fn bytes_to_hex_string(bytes: &[u8]) -> String {
     bytes.iter()
@@ -93,6 +148,38 @@ pub fn find_pattern(haystack: &[u8], needle: &[u8]) -> Option<usize> {
     None
 }
 
+/// 查找带通配符的字节模式（None 匹配任意字节）在数组中的位置
+pub fn find_masked_pattern(haystack: &[u8], pattern: &[Option<u8>]) -> Option<usize> {
+    if pattern.is_empty() || haystack.len() < pattern.len() {
+        return None;
+    }
+
+    haystack.windows(pattern.len()).position(|window| {
+        window
+            .iter()
+            .zip(pattern)
+            .all(|(byte, expected)| expected.is_none_or(|expected| *byte == expected))
+    })
+}
+
+/// 解析 `"48 8B ?? C3"` 形式的特征码，`??` 或 `?` 表示通配字节
+pub fn parse_byte_pattern(pattern: &str) -> SystemResult<Vec<Option<u8>>> {
+    let bytes = pattern
+        .split_whitespace()
+        .map(|token| match token {
+            "?" | "??" => Ok(None),
+            _ if token.len() == 2 => u8::from_str_radix(token, 16).map(Some).map_err(|_| token),
+            _ => Err(token),
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|token| SystemError::InvalidArgument(format!("Invalid byte in pattern: {:?}", token)))?;
+
+    if bytes.is_empty() {
+        return Err(SystemError::InvalidArgument("Pattern is empty".to_string()));
+    }
+    Ok(bytes)
+}
+
 /// 将地址格式化为十六进制字符串
 pub fn format_address(address: usize) -> String {
     format!("0x{:X}", address)
@@ -116,6 +203,51 @@ pub fn format_size(size: u64) -> String {
     }
 }
 
+/// 获取系统内存页大小（字节），结果在首次调用后缓存
+pub fn page_size() -> usize {
+    static PAGE_SIZE: std::sync::OnceLock<usize> = std::sync::OnceLock::new();
+    *PAGE_SIZE.get_or_init(query_page_size)
+}
+
+#[cfg(unix)]
+fn query_page_size() -> usize {
+    let size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+    if size > 0 { size as usize } else { 4096 }
+}
+
+#[cfg(windows)]
+fn query_page_size() -> usize {
+    use windows::Win32::System::SystemInformation::{GetSystemInfo, SYSTEM_INFO};
+
+    let mut info = SYSTEM_INFO::default();
+    unsafe { GetSystemInfo(&mut info) };
+    if info.dwPageSize > 0 { info.dwPageSize as usize } else { 4096 }
+}
+
+#[cfg(not(any(unix, windows)))]
+fn query_page_size() -> usize {
+    4096
+}
+
+/// 将地址向下对齐到 align 的整数倍（align 必须大于 0）
+pub fn align_down(addr: usize, align: usize) -> usize {
+    addr - addr % align
+}
+
+/// 将地址向上对齐到 align 的整数倍（align 必须大于 0，结果溢出时 panic）
+pub fn align_up(addr: usize, align: usize) -> usize {
+    addr.next_multiple_of(align)
+}
+
+/// 计算覆盖 [addr, addr + size) 的页对齐区域，返回 (起始地址, 大小)
+/// 可直接用于 VirtualProtectEx / vm_protect / mprotect 等按页操作的接口
+pub fn page_range(addr: usize, size: usize) -> (usize, usize) {
+    let page = page_size();
+    let start = align_down(addr, page);
+    let end = align_up(addr + size, page);
+    (start, end - start)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,6 +282,44 @@ mod tests {
         assert_eq!(bytes_to_utf8_string(&invalid_utf8), "");
     }
 
+    #[test]
+    fn test_utf16_and_terminators() {
+        let bytes = utf16_string_to_bytes("Hi 世界");
+        assert_eq!(&bytes[..4], &[b'H', 0, b'i', 0]);
+        assert_eq!(&bytes[bytes.len() - 2..], &[0, 0]);
+        assert_eq!(bytes_to_utf16_string(&bytes), "Hi 世界");
+        assert_eq!(bytes_to_utf16_string(b"A\0B\0\0\0C\0"), "AB");
+        // 末尾的奇数字节被忽略
+        assert_eq!(bytes_to_utf16_string(b"A\0B"), "A");
+        // 孤立的代理项无法解码
+        assert_eq!(bytes_to_utf16_string(&[0x00, 0xD8, b'A', 0]), "");
+
+        assert_eq!(find_terminator(b"abc\0def", 1), Some(3));
+        assert_eq!(find_terminator(b"abc", 1), None);
+        // 跨越两个 u16 的 0 字节不是终止符
+        assert_eq!(find_terminator(&[b'A', 0, 0, b'B', 0, 0], 2), Some(4));
+        assert_eq!(find_terminator(&[b'A', 0, 0], 2), None);
+    }
+
+    #[test]
+    fn test_from_bytes() {
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        #[repr(C)]
+        struct Vec3 {
+            x: f32,
+            y: f32,
+            z: f32,
+        }
+        unsafe impl FromBytes for Vec3 {}
+
+        let bytes: Vec<u8> = [1.0f32, -2.5, 4.0].iter().flat_map(|v| v.to_ne_bytes()).collect();
+        assert_eq!(from_bytes::<Vec3>(&bytes), Some(Vec3 { x: 1.0, y: -2.5, z: 4.0 }));
+        // 未对齐的切片同样可以解释
+        assert_eq!(from_bytes::<[f32; 2]>(&[&[0u8][..], &bytes].concat()[1..]), Some([1.0, -2.5]));
+        assert_eq!(from_bytes::<u16>(&[0x34, 0x12, 0xFF]), Some(u16::from_ne_bytes([0x34, 0x12])));
+        assert_eq!(from_bytes::<Vec3>(&bytes[..8]), None);
+    }
+
     #[test]
     fn test_find_pattern() {
         let haystack = b"Hello World, Hello Universe";
@@ -164,6 +334,18 @@ mod tests {
         assert_eq!(find_pattern(haystack, needle3), None);
     }
 
+    #[test]
+    fn test_parse_and_find_masked_pattern() {
+        let pattern = parse_byte_pattern("48 8B ?? C3").unwrap();
+        assert_eq!(pattern, vec![Some(0x48), Some(0x8B), None, Some(0xC3)]);
+        assert_eq!(find_masked_pattern(&[0x00, 0x48, 0x8B, 0x05, 0xC3], &pattern), Some(1));
+        assert_eq!(find_masked_pattern(&[0x48, 0x8B, 0x05, 0xC4], &pattern), None);
+
+        assert!(parse_byte_pattern("").is_err());
+        assert!(parse_byte_pattern("48 GG").is_err());
+        assert!(parse_byte_pattern("488B").is_err());
+    }
+
     #[test]
     fn test_format_size() {
         assert_eq!(format_size(512), "512 B");
@@ -171,4 +353,17 @@ mod tests {
         assert_eq!(format_size(1536), "1.50 KB");
         assert_eq!(format_size(1048576), "1.00 MB");
     }
+
+    #[test]
+    fn test_page_alignment() {
+        assert_eq!(align_down(0x1234, 0x1000), 0x1000);
+        assert_eq!(align_up(0x1234, 0x1000), 0x2000);
+        assert_eq!(align_up(0x2000, 0x1000), 0x2000);
+
+        let page = page_size();
+        assert!(page.is_power_of_two());
+        assert_eq!(page_range(page + 1, 1), (page, page));
+        assert_eq!(page_range(page - 1, 2), (0, page * 2));
+        assert_eq!(page_range(page, page), (page, page));
+    }
 }
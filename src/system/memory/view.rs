@@ -0,0 +1,153 @@
+// 带字段名的内存视图
+// 一次声明结构体布局（字段名 -> 偏移与类型），之后按名字读写，避免在调用处手写偏移运算
+
+use super::ProcessMemoryInstance;
+use crate::system::common::*;
+use std::collections::HashMap;
+
+/// 字段的类型，决定读写的字节数
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldType {
+    U32,
+    U64,
+    F32,
+    F64,
+    /// 固定长度的原始字节
+    Bytes(usize),
+}
+
+impl FieldType {
+    /// 字段占用的字节数
+    pub fn size(&self) -> usize {
+        match self {
+            FieldType::U32 | FieldType::F32 => 4,
+            FieldType::U64 | FieldType::F64 => 8,
+            FieldType::Bytes(len) => *len,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Field {
+    offset: usize,
+    ty: FieldType,
+}
+
+/// 进程内存中一个结构体的视图
+/// 通过 [`ProcessMemoryInstance::view`] 创建，字段偏移相对于结构体起始位置；
+/// 读写时按字段名查找偏移和类型，再调用实例已有的读写方法
+pub struct MemoryView<'a> {
+    instance: &'a ProcessMemoryInstance,
+    base_offset: usize,
+    fields: HashMap<String, Field>,
+}
+
+impl<'a> MemoryView<'a> {
+    /// 创建视图，base_offset 为结构体相对于模块基址的偏移
+    pub fn new(instance: &'a ProcessMemoryInstance, base_offset: usize) -> Self {
+        Self {
+            instance,
+            base_offset,
+            fields: HashMap::new(),
+        }
+    }
+
+    /// 注册字段，同名字段会被覆盖
+    pub fn field(mut self, name: &str, offset: usize, ty: FieldType) -> Self {
+        self.fields.insert(name.to_string(), Field { offset, ty });
+        self
+    }
+
+    /// 结构体相对于模块基址的偏移
+    pub fn base_offset(&self) -> usize {
+        self.base_offset
+    }
+
+    /// 字段相对于模块基址的偏移，未注册时返回 None
+    pub fn offset_of(&self, name: &str) -> Option<usize> {
+        self.fields.get(name).map(|field| self.base_offset.wrapping_add(field.offset))
+    }
+
+    /// 读取 u32 字段
+    pub fn read_u32(&self, name: &str) -> SystemResult<u32> {
+        self.instance.read_u32(self.locate(name, FieldType::U32)?)
+    }
+
+    /// 读取 u64 字段
+    pub fn read_u64(&self, name: &str) -> SystemResult<u64> {
+        self.instance.read_u64(self.locate(name, FieldType::U64)?)
+    }
+
+    /// 读取 f32 字段
+    pub fn read_f32(&self, name: &str) -> SystemResult<f32> {
+        self.instance.read_f32(self.locate(name, FieldType::F32)?)
+    }
+
+    /// 读取 f64 字段
+    pub fn read_f64(&self, name: &str) -> SystemResult<f64> {
+        self.instance.read_f64(self.locate(name, FieldType::F64)?)
+    }
+
+    /// 读取字节字段的全部内容
+    pub fn read_bytes(&self, name: &str) -> SystemResult<Vec<u8>> {
+        let (offset, len) = self.locate_bytes(name)?;
+        self.instance.read_memory(offset, len)
+    }
+
+    /// 写入 u32 字段
+    pub fn write_u32(&self, name: &str, value: u32) -> SystemResult<MemoryOperationResult> {
+        self.instance.write_u32(self.locate(name, FieldType::U32)?, value)
+    }
+
+    /// 写入 u64 字段
+    pub fn write_u64(&self, name: &str, value: u64) -> SystemResult<MemoryOperationResult> {
+        self.instance.write_u64(self.locate(name, FieldType::U64)?, value)
+    }
+
+    /// 写入 f32 字段
+    pub fn write_f32(&self, name: &str, value: f32) -> SystemResult<MemoryOperationResult> {
+        self.instance.write_f32(self.locate(name, FieldType::F32)?, value)
+    }
+
+    /// 写入 f64 字段
+    pub fn write_f64(&self, name: &str, value: f64) -> SystemResult<MemoryOperationResult> {
+        self.instance.write_f64(self.locate(name, FieldType::F64)?, value)
+    }
+
+    /// 写入字节字段，data 的长度必须与字段长度一致
+    pub fn write_bytes(&self, name: &str, data: &[u8]) -> SystemResult<MemoryOperationResult> {
+        let (offset, len) = self.locate_bytes(name)?;
+        if data.len() != len {
+            return Err(SystemError::InvalidArgument(format!(
+                "Field '{}' is {} bytes, got {} bytes", name, len, data.len()
+            )));
+        }
+        self.instance.write_memory(offset, data)
+    }
+
+    fn lookup(&self, name: &str) -> SystemResult<Field> {
+        self.fields
+            .get(name)
+            .copied()
+            .ok_or_else(|| SystemError::NotFound(format!("Field '{}' is not registered in the memory view", name)))
+    }
+
+    /// 查找字段并检查类型，返回相对于模块基址的偏移
+    fn locate(&self, name: &str, expected: FieldType) -> SystemResult<usize> {
+        let field = self.lookup(name)?;
+        if field.ty != expected {
+            return Err(SystemError::InvalidArgument(format!(
+                "Field '{}' is {:?}, not {:?}", name, field.ty, expected
+            )));
+        }
+        Ok(self.base_offset.wrapping_add(field.offset))
+    }
+
+    fn locate_bytes(&self, name: &str) -> SystemResult<(usize, usize)> {
+        let field = self.lookup(name)?;
+        match field.ty {
+            FieldType::Bytes(len) => Ok((self.base_offset.wrapping_add(field.offset), len)),
+            ty => Err(SystemError::InvalidArgument(format!("Field '{}' is {:?}, not Bytes", name, ty))),
+        }
+    }
+}
@@ -2,10 +2,12 @@
 pub mod implementation {
     use windows::Win32::Foundation::{HANDLE, CloseHandle};
     use windows::Win32::System::Diagnostics::Debug::{ReadProcessMemory, WriteProcessMemory};
-    use windows::Win32::System::Threading::{OpenProcess, PROCESS_VM_READ, PROCESS_QUERY_INFORMATION, PROCESS_VM_WRITE, PROCESS_VM_OPERATION};
+    use windows::Win32::System::Threading::{
+        OpenProcess, PROCESS_ACCESS_RIGHTS, PROCESS_ALL_ACCESS, PROCESS_VM_READ, PROCESS_QUERY_INFORMATION, PROCESS_VM_WRITE, PROCESS_VM_OPERATION
+    };
     use windows::Win32::System::Diagnostics::ToolHelp::{
         CreateToolhelp32Snapshot, TH32CS_SNAPPROCESS, PROCESSENTRY32, Process32First, Process32Next,
-        TH32CS_SNAPMODULE, MODULEENTRY32, Module32First
+        TH32CS_SNAPMODULE, TH32CS_SNAPMODULE32, MODULEENTRY32, Module32First, Module32Next
     };
     use crate::{trace, debug, info, error, warn};
     use crate::system::common::*;
@@ -173,38 +175,170 @@ pub mod implementation {
         Ok(processes)
     }
 
+    /// 列出进程的内存区域
+    pub fn list_memory_regions(pid: u32) -> SystemResult<Vec<MemoryRegion>> {
+        let handle = unsafe { OpenProcess(PROCESS_QUERY_INFORMATION, false, pid) }
+            .map_err(|e| SystemError::ProcessError(format!("Failed to open process {}: {}", pid, e)))?;
+        let regions = query_memory_regions(handle);
+        unsafe { CloseHandle(handle) }.ok();
+        Ok(regions)
+    }
+
+    /// 通过 VirtualQueryEx 遍历进程地址空间，只返回已提交的区域
+    pub fn query_memory_regions(handle: HANDLE) -> Vec<MemoryRegion> {
+        use windows::Win32::System::Memory::{VirtualQueryEx, MEMORY_BASIC_INFORMATION, MEM_COMMIT, MEM_PRIVATE};
+
+        let mut regions = Vec::new();
+        let mut address = 0usize;
+
+        loop {
+            let mut info = MEMORY_BASIC_INFORMATION::default();
+            let written = unsafe {
+                VirtualQueryEx(
+                    handle,
+                    Some(address as *const std::ffi::c_void),
+                    &mut info,
+                    std::mem::size_of::<MEMORY_BASIC_INFORMATION>(),
+                )
+            };
+            if written == 0 || info.RegionSize == 0 {
+                break;
+            }
+
+            let base_address = info.BaseAddress as usize;
+            if info.State == MEM_COMMIT {
+                regions.push(MemoryRegion {
+                    base_address,
+                    size: info.RegionSize,
+                    protection: page_protection_to_permission(info.Protect.0),
+                    is_committed: true,
+                    is_private: info.Type == MEM_PRIVATE,
+                    module_name: None,
+                });
+            }
+
+            match base_address.checked_add(info.RegionSize) {
+                Some(next) if next > address => address = next,
+                _ => break,
+            }
+        }
+
+        regions
+    }
+
+    /// 将 PAGE_* 保护属性转换为读写执行权限
+    fn page_protection_to_permission(protect: u32) -> MemoryPermission {
+        const PAGE_GUARD: u32 = 0x100;
+        if protect & PAGE_GUARD != 0 {
+            return MemoryPermission::NoAccess;
+        }
+        match protect & 0xFF {
+            0x02 => MemoryPermission::Read,              // PAGE_READONLY
+            0x04 | 0x08 => MemoryPermission::ReadWrite,  // PAGE_READWRITE / PAGE_WRITECOPY
+            0x10 => MemoryPermission::Execute,           // PAGE_EXECUTE
+            0x20 => MemoryPermission::ReadExecute,       // PAGE_EXECUTE_READ
+            0x40 | 0x80 => MemoryPermission::ReadWriteExecute, // PAGE_EXECUTE_READWRITE / PAGE_EXECUTE_WRITECOPY
+            _ => MemoryPermission::NoAccess,             // PAGE_NOACCESS
+        }
+    }
+
+    /// 将读写执行权限转换为 PAGE_* 保护属性，只写权限按读写处理
+    fn permission_to_page_protection(permission: MemoryPermission) -> u32 {
+        let readable = permission.is_readable() || permission.is_writable();
+        match (readable, permission.is_writable(), permission.is_executable()) {
+            (false, _, false) => 0x01,    // PAGE_NOACCESS
+            (true, false, false) => 0x02, // PAGE_READONLY
+            (true, true, false) => 0x04,  // PAGE_READWRITE
+            (false, _, true) => 0x10,     // PAGE_EXECUTE
+            (true, false, true) => 0x20,  // PAGE_EXECUTE_READ
+            (true, true, true) => 0x40,   // PAGE_EXECUTE_READWRITE
+        }
+    }
+
+    /// 修改进程内存页的保护属性，返回起始页原来的保护属性
+    pub fn protect_memory(
+        handle: HANDLE,
+        address: usize,
+        size: usize,
+        permission: MemoryPermission,
+    ) -> std::io::Result<MemoryPermission> {
+        use windows::Win32::System::Memory::{VirtualProtectEx, PAGE_PROTECTION_FLAGS};
+
+        let mut old_protect = PAGE_PROTECTION_FLAGS::default();
+        let result = unsafe {
+            VirtualProtectEx(
+                handle,
+                address as *const std::ffi::c_void,
+                size,
+                PAGE_PROTECTION_FLAGS(permission_to_page_protection(permission)),
+                &mut old_protect,
+            )
+        };
+        match result {
+            Ok(_) => Ok(page_protection_to_permission(old_protect.0)),
+            Err(e) => Err(std::io::Error::from_raw_os_error(e.code().0 as i32)),
+        }
+    }
+
+    /// 访问权限对应的最小 OpenProcess 标志
+    fn access_flags(access: AccessRights) -> PROCESS_ACCESS_RIGHTS {
+        match access {
+            AccessRights::ReadOnly => PROCESS_VM_READ | PROCESS_QUERY_INFORMATION,
+            AccessRights::ReadWrite => PROCESS_VM_READ | PROCESS_QUERY_INFORMATION | PROCESS_VM_WRITE | PROCESS_VM_OPERATION,
+            AccessRights::Full => PROCESS_ALL_ACCESS,
+        }
+    }
+
+    /// 关闭进程句柄
+    pub fn close_process_handle(handle: ProcessHandle) {
+        unsafe { CloseHandle(handle) }.ok();
+    }
+
     /// 进程内存操作实例
     pub struct ProcessInstance {
         handle: HANDLE,
         base_addr: usize,
         pid: u32,
         name: Option<String>,
+        access: AccessRights,
     }
 
     impl ProcessInstance {
         /// 根据进程名创建实例
         pub fn new_by_name(process_name: &str) -> std::io::Result<Self> {
+            Self::new_by_name_with_access(process_name, AccessRights::default())
+        }
+
+        /// 根据进程名以指定访问权限创建实例
+        pub fn new_by_name_with_access(process_name: &str, access: AccessRights) -> std::io::Result<Self> {
             info!("memory", "Create instance by name: {}", process_name);
             let pid = find_pid_by_name(process_name)
                 .map_err(|e| std::io::Error::new(std::io::ErrorKind::NotFound, e.to_string()))?;
-            let (handle, base_addr) = open_handle_and_base(pid)?;
+            let (handle, base_addr) = open_handle_and_base(pid, access)?;
             info!("memory", "[{}] Instance created: PID={}, BaseAddr=0x{:X}", pid, pid, base_addr);
             Ok(Self {
                 handle,
                 base_addr,
                 pid,
                 name: Some(process_name.to_string()),
+                access,
             })
         }
 
         /// 根据PID创建实例
         pub fn new_by_pid(pid: u32) -> std::io::Result<Self> {
-            let (handle, base_addr) = open_handle_and_base(pid)?;
+            Self::new_by_pid_with_access(pid, AccessRights::default())
+        }
+
+        /// 根据PID以指定访问权限创建实例
+        pub fn new_by_pid_with_access(pid: u32, access: AccessRights) -> std::io::Result<Self> {
+            let (handle, base_addr) = open_handle_and_base(pid, access)?;
             Ok(Self {
                 handle,
                 base_addr,
                 pid,
                 name: None,
+                access,
             })
         }
 
@@ -270,6 +404,13 @@ pub mod implementation {
         pub fn base_addr(&self) -> usize { self.base_addr }
         pub fn name(&self) -> Option<&str> { self.name.as_deref() }
         pub fn handle(&self) -> HANDLE { self.handle }
+        pub fn access(&self) -> AccessRights { self.access }
+
+        /// 取出句柄的所有权，调用方负责通过 `close_process_handle` 关闭
+        pub fn into_handle(self) -> HANDLE {
+            let this = std::mem::ManuallyDrop::new(self);
+            this.handle
+        }
     }
 
     impl Drop for ProcessInstance {
@@ -279,13 +420,48 @@ pub mod implementation {
         }
     }
 
+    /// 通过 Toolhelp 模块快照列出进程加载的模块
+    pub fn list_modules(pid: u32) -> SystemResult<Vec<ModuleInfo>> {
+        let snapshot = unsafe { CreateToolhelp32Snapshot(TH32CS_SNAPMODULE | TH32CS_SNAPMODULE32, pid) }
+            .map_err(|e| SystemError::ProcessError(format!("Failed to create module snapshot for process {}: {}", pid, e)))?;
+
+        let c_string = |chars: &[i8]| {
+            let nul_pos = chars.iter().position(|&c| c == 0).unwrap_or(chars.len());
+            String::from_utf8_lossy(unsafe {
+                std::slice::from_raw_parts(chars.as_ptr() as *const u8, nul_pos)
+            }).to_string()
+        };
+
+        let mut entry = MODULEENTRY32 {
+            dwSize: std::mem::size_of::<MODULEENTRY32>() as u32,
+            ..Default::default()
+        };
+        let mut modules = Vec::new();
+
+        if unsafe { Module32First(snapshot, &mut entry) }.is_ok() {
+            loop {
+                let path = c_string(&entry.szExePath);
+                modules.push(ModuleInfo {
+                    name: c_string(&entry.szModule),
+                    path: if path.is_empty() { None } else { Some(path) },
+                    base_address: entry.modBaseAddr as usize,
+                    size: entry.modBaseSize as usize,
+                });
+                if unsafe { Module32Next(snapshot, &mut entry) }.is_err() {
+                    break;
+                }
+            }
+        }
+
+        unsafe { CloseHandle(snapshot) }.ok();
+        Ok(modules)
+    }
+
     /// 打开进程句柄并获取主模块基址
-    fn open_handle_and_base(pid: u32) -> std::io::Result<(HANDLE, usize)> {
-        trace!("memory", "[{}] Open process handle", pid);
+    fn open_handle_and_base(pid: u32, access: AccessRights) -> std::io::Result<(HANDLE, usize)> {
+        trace!("memory", "[{}] Open process handle: Access={:?}", pid, access);
         
-        let handle = unsafe { OpenProcess(
-            PROCESS_VM_READ | PROCESS_QUERY_INFORMATION | PROCESS_VM_WRITE | PROCESS_VM_OPERATION,
-            false, pid) }
+        let handle = unsafe { OpenProcess(access_flags(access), false, pid) }
             .map_err(|e| {
                 error!("memory", "[{}] Open process failed: Error={}", pid, e);
                 std::io::Error::new(std::io::ErrorKind::Other, format!("Failed to open process: {}", e))
@@ -329,7 +505,7 @@ pub mod implementation {
 
     /// 根据PID和偏移读取内存 
     pub fn read_process_memory_by_pid(pid: u32, offset: usize, size: usize) -> std::io::Result<Vec<u8>> {
-        let (handle, base_addr) = open_handle_and_base(pid)?;
+        let (handle, base_addr) = open_handle_and_base(pid, AccessRights::ReadOnly)?;
         let result = read_process_memory(handle, base_addr + offset, size);
         unsafe { CloseHandle(handle) }.ok();
         result
@@ -354,10 +530,22 @@ pub mod implementation {
         Err(SystemError::NotSupported("Windows memory operations not enabled. Enable 'win-memory' feature.".to_string()))
     }
 
+    pub fn list_memory_regions(_pid: u32) -> SystemResult<Vec<MemoryRegion>> {
+        Err(SystemError::NotSupported("Windows memory operations not enabled. Enable 'win-memory' feature.".to_string()))
+    }
+
+    pub fn list_modules(_pid: u32) -> SystemResult<Vec<ModuleInfo>> {
+        Err(SystemError::NotSupported("Windows memory operations not enabled. Enable 'win-memory' feature.".to_string()))
+    }
+
     pub fn read_process_memory(_handle: ProcessHandle, _address: usize, _size: usize) -> std::io::Result<Vec<u8>> {
         Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Windows memory operations not enabled"))
     }
 
+    pub fn protect_memory(_handle: ProcessHandle, _address: usize, _size: usize, _permission: MemoryPermission) -> std::io::Result<MemoryPermission> {
+        Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Windows memory operations not enabled"))
+    }
+
     pub fn write_process_memory(_handle: ProcessHandle, _address: usize, _data: &[u8]) -> std::io::Result<usize> {
         Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Windows memory operations not enabled"))
     }
@@ -373,11 +561,23 @@ pub mod implementation {
             Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Windows memory operations not enabled"))
         }
 
+        pub fn new_by_name_with_access(_process_name: &str, _access: AccessRights) -> std::io::Result<Self> {
+            Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Windows memory operations not enabled"))
+        }
+
+        pub fn new_by_pid_with_access(_pid: u32, _access: AccessRights) -> std::io::Result<Self> {
+            Err(std::io::Error::new(std::io::ErrorKind::Unsupported, "Windows memory operations not enabled"))
+        }
+
         pub fn pid(&self) -> u32 { 0 }
         pub fn base_addr(&self) -> usize { 0 }
         pub fn name(&self) -> Option<&str> { None }
         pub fn handle(&self) -> ProcessHandle { 0 }
+        pub fn access(&self) -> AccessRights { AccessRights::default() }
+        pub fn into_handle(self) -> ProcessHandle { 0 }
     }
+
+    pub fn close_process_handle(_handle: ProcessHandle) {}
 }
 
 // 重新导出实现
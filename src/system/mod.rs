@@ -38,6 +38,9 @@ pub mod security;
 // 内存管理模块
 pub mod memory;
 
+// 诊断包模块
+pub mod diagnostics;
+
 // 通用工具和类型定义
 pub mod common;
 
@@ -52,6 +55,14 @@ impl SystemTools {
         sys
     }
     
+    /// 获取完整的系统快照（基本信息、内存、CPU、磁盘、活跃网络接口和性能摘要）
+    /// 所有字段来自同一次刷新；为了让 CPU 使用率有效，会先等待 `::sysinfo::MINIMUM_CPU_UPDATE_INTERVAL`
+    pub fn snapshot() -> common::SystemResult<sysinfo::SystemSnapshot> {
+        let sys = sysinfo::SystemInfoManager::new();
+        std::thread::sleep(::sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+        sys.snapshot()
+    }
+    
     /// 获取启动管理器
     pub fn startup() -> &'static startup::StartupManager {
         &startup::StartupManager
@@ -107,6 +118,12 @@ impl SystemTools {
         memory::MemoryManager::new()
     }
     
+    /// 生成诊断包（zip），包含系统快照、进程列表、磁盘和网络信息等
+    /// 系统快照需要 `serde` feature，脱敏后的环境变量需要 `log-redaction` feature
+    pub fn diagnostic_bundle(path: &std::path::Path) -> common::SystemResult<()> {
+        diagnostics::DiagnosticBundle::write_to(path)
+    }
+    
     /// 获取注册表工具 (Windows only)
     #[cfg(target_os = "windows")]
     pub fn registry() -> &'static registry::RegistryTools {
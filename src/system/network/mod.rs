@@ -1,7 +1,37 @@
 // 网络工具模块
 
+use std::process::Command;
+use std::time::Duration;
+
 use crate::system::common::*;
 
+/// ping 的统计结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct PingResult {
+    pub target: String,
+    pub packets_sent: u32,
+    pub packets_received: u32,
+    /// 往返时间统计，一个回复都没有收到时为 None
+    pub min_rtt: Option<Duration>,
+    pub avg_rtt: Option<Duration>,
+    pub max_rtt: Option<Duration>,
+}
+
+impl PingResult {
+    /// 是否至少收到一个回复
+    pub fn is_reachable(&self) -> bool {
+        self.packets_received > 0
+    }
+
+    /// 丢包率（0.0 ~ 100.0）
+    pub fn packet_loss(&self) -> f64 {
+        if self.packets_sent == 0 {
+            return 0.0;
+        }
+        100.0 * (self.packets_sent - self.packets_received.min(self.packets_sent)) as f64 / self.packets_sent as f64
+    }
+}
+
 /// 网络工具
 pub struct NetworkTools;
 
@@ -10,4 +40,170 @@ impl NetworkTools {
     pub fn scan_ports(&self, _target: &str, _start: u16, _end: u16) -> SystemResult<Vec<u16>> {
         Err(SystemError::NotSupported("Not implemented yet".to_string()))
     }
-} 
\ No newline at end of file
+
+    /// 向目标发送 count 个 ICMP echo 请求，timeout 为每个请求的等待时间
+    /// Linux 等系统的 ping 只接受整秒，timeout 向上取整且最少为 1 秒；Windows 和 macOS 上精确到毫秒
+    /// 原始 ICMP 套接字需要特权，目前通过调用系统的 ping 命令并解析输出实现；
+    /// 以后可以在 Linux 上改用无需特权的 ICMP 数据报套接字（net.ipv4.ping_group_range）
+    /// Windows 上只能解析英文输出，其他语言的系统上统计结果可能为空
+    pub fn ping(&self, target: &str, count: u32, timeout: Duration) -> SystemResult<PingResult> {
+        if target.is_empty() || target.starts_with('-') || target.chars().any(char::is_whitespace) {
+            return Err(SystemError::InvalidArgument(format!("Invalid ping target: {:?}", target)));
+        }
+        if count == 0 {
+            return Err(SystemError::InvalidArgument("Ping count must be greater than 0".to_string()));
+        }
+
+        let output = Self::ping_command(target, count, timeout)
+            .output()
+            .map_err(|e| match e.kind() {
+                std::io::ErrorKind::NotFound => SystemError::NotSupported("ping command not available".to_string()),
+                _ => SystemError::ProcessError(format!("Failed to execute ping: {}", e)),
+            })?;
+
+        // 目标不可达时 ping 以非零状态退出，但仍会输出统计信息
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let mut result = Self::parse_ping_output(target, &stdout).ok_or_else(|| {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            SystemError::ProcessError(format!("Failed to ping {}: {}", target, stderr.trim()))
+        })?;
+        if result.packets_received == 0 {
+            result.min_rtt = None;
+            result.avg_rtt = None;
+            result.max_rtt = None;
+        }
+        Ok(result)
+    }
+
+    fn ping_command(target: &str, count: u32, timeout: Duration) -> Command {
+        let mut command = Command::new("ping");
+
+        #[cfg(target_os = "windows")]
+        command
+            .args(["-n", &count.to_string()])
+            .args(["-w", &timeout.as_millis().max(1).to_string()]);
+
+        // macOS 的 -W 单位为毫秒
+        #[cfg(target_os = "macos")]
+        command
+            .args(["-c", &count.to_string()])
+            .args(["-W", &timeout.as_millis().max(1).to_string()]);
+
+        // Linux 等系统的 -W 单位为秒，向上取整以免等待时间比要求的短
+        #[cfg(all(unix, not(target_os = "macos")))]
+        command
+            .args(["-c", &count.to_string()])
+            .args(["-W", &(timeout.as_secs_f64().ceil() as u64).max(1).to_string()]);
+
+        #[cfg(not(any(unix, target_os = "windows")))]
+        let _ = (count, timeout);
+
+        command.arg(target);
+        command
+    }
+
+    /// 解析 ping 输出中的统计信息，同时支持 Unix 与 Windows 格式
+    fn parse_ping_output(target: &str, output: &str) -> Option<PingResult> {
+        let mut counts = None;
+        let mut rtt = None;
+
+        for line in output.lines().map(str::trim) {
+            // Unix: "4 packets transmitted, 4 received, 0% packet loss"
+            //       "4 packets transmitted, 4 packets received, 0.0% packet loss"
+            if line.contains("packets transmitted") {
+                let mut numbers = line
+                    .split(',')
+                    .filter_map(|part| part.split_whitespace().next()?.parse::<u32>().ok());
+                counts = Some((numbers.next()?, numbers.next()?));
+            }
+            // Windows: "Packets: Sent = 4, Received = 4, Lost = 0 (0% loss),"
+            else if line.starts_with("Packets:") {
+                counts = Some((Self::windows_field(line, "Sent")? as u32, Self::windows_field(line, "Received")? as u32));
+            }
+            // Unix: "rtt min/avg/max/mdev = 0.031/0.045/0.059/0.011 ms"
+            //       "round-trip min/avg/max/stddev = 0.031/0.045/0.059/0.011 ms"
+            else if line.contains("min/avg/max") {
+                let values = line.split('=').nth(1)?.split_whitespace().next()?;
+                let mut values = values.split('/').filter_map(|value| value.parse::<f64>().ok());
+                rtt = Some((values.next()?, values.next()?, values.next()?));
+            }
+            // Windows: "Minimum = 1ms, Maximum = 2ms, Average = 1ms"
+            else if line.starts_with("Minimum") {
+                rtt = Some((
+                    Self::windows_field(line, "Minimum")?,
+                    Self::windows_field(line, "Average")?,
+                    Self::windows_field(line, "Maximum")?,
+                ));
+            }
+        }
+
+        let (packets_sent, packets_received) = counts?;
+        let millis = |ms: f64| Duration::from_secs_f64(ms / 1000.0);
+        Some(PingResult {
+            target: target.to_string(),
+            packets_sent,
+            packets_received,
+            min_rtt: rtt.map(|(min, _, _)| millis(min)),
+            avg_rtt: rtt.map(|(_, avg, _)| millis(avg)),
+            max_rtt: rtt.map(|(_, _, max)| millis(max)),
+        })
+    }
+
+    /// 读取 Windows 输出中 `Name = 123ms` 形式的数值
+    fn windows_field(line: &str, name: &str) -> Option<f64> {
+        line.split(',')
+            .filter_map(|part| part.split_once('='))
+            .find(|(key, _)| key.trim().trim_start_matches("Packets:").trim() == name)
+            .and_then(|(_, value)| value.trim().trim_end_matches("ms").split_whitespace().next()?.parse().ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ping_output() {
+        let linux = "PING 127.0.0.1 (127.0.0.1) 56(84) bytes of data.\n\
+            64 bytes from 127.0.0.1: icmp_seq=1 ttl=64 time=0.031 ms\n\n\
+            --- 127.0.0.1 ping statistics ---\n\
+            2 packets transmitted, 2 received, 0% packet loss, time 1001ms\n\
+            rtt min/avg/max/mdev = 0.031/0.045/0.059/0.014 ms\n";
+        let result = NetworkTools::parse_ping_output("127.0.0.1", linux).unwrap();
+        assert_eq!((result.packets_sent, result.packets_received), (2, 2));
+        assert_eq!(result.min_rtt, Some(Duration::from_micros(31)));
+        assert_eq!(result.max_rtt, Some(Duration::from_micros(59)));
+
+        let macos = "--- 10.0.0.1 ping statistics ---\n\
+            3 packets transmitted, 0 packets received, 100.0% packet loss\n";
+        let result = NetworkTools::parse_ping_output("10.0.0.1", macos).unwrap();
+        assert_eq!((result.packets_sent, result.packets_received), (3, 0));
+        assert_eq!(result.avg_rtt, None);
+        assert_eq!(result.packet_loss(), 100.0);
+
+        let windows = "Ping statistics for 8.8.8.8:\r\n    \
+            Packets: Sent = 4, Received = 3, Lost = 1 (25% loss),\r\n\
+            Approximate round trip times in milli-seconds:\r\n    \
+            Minimum = 12ms, Maximum = 20ms, Average = 15ms\r\n";
+        let result = NetworkTools::parse_ping_output("8.8.8.8", windows).unwrap();
+        assert_eq!((result.packets_sent, result.packets_received), (4, 3));
+        assert_eq!(result.avg_rtt, Some(Duration::from_millis(15)));
+        assert_eq!(result.max_rtt, Some(Duration::from_millis(20)));
+
+        assert!(NetworkTools::parse_ping_output("host", "ping: unknown host").is_none());
+    }
+
+    #[cfg(all(unix, not(target_os = "macos")))]
+    #[test]
+    fn test_ping_timeout_rounds_up_to_seconds() {
+        let wait_seconds = |timeout: Duration| {
+            let command = NetworkTools::ping_command("127.0.0.1", 1, timeout);
+            let args: Vec<_> = command.get_args().map(|arg| arg.to_string_lossy().to_string()).collect();
+            let index = args.iter().position(|arg| arg == "-W").unwrap();
+            args[index + 1].clone()
+        };
+        assert_eq!(wait_seconds(Duration::from_millis(200)), "1");
+        assert_eq!(wait_seconds(Duration::from_millis(1500)), "2");
+        assert_eq!(wait_seconds(Duration::from_secs(3)), "3");
+    }
+}
@@ -2,32 +2,233 @@
 // 管理系统进程，获取进程信息、启动/停止进程等
 
 use crate::system::common::*;
+use crate::system::sysinfo::{self, SystemInfoManager};
+use std::sync::OnceLock;
+use std::thread;
+use std::time::{Duration, Instant};
+
+// 平台特定的进程控制
+#[cfg(unix)]
+pub mod unix;
+
+#[cfg(target_os = "windows")]
+pub mod windows;
 
 /// 进程管理器
+///
+/// 进程查询委托给进程内共享的 [`SystemInfoManager`]，它在第一次查询时才创建，
+/// 因此构造 `ProcessManager` 本身没有开销。进程列表在共享管理器的刷新间隔（默认2秒）内会被复用，
+/// 按 PID 查询时总是先单独刷新该进程。
 pub struct ProcessManager;
 
+/// 所有 ProcessManager 共享的系统信息管理器
+fn shared_system_info() -> &'static SystemInfoManager {
+    static SHARED: OnceLock<SystemInfoManager> = OnceLock::new();
+    SHARED.get_or_init(SystemInfoManager::new)
+}
+
+/// `wait_for` 的等待条件
+#[derive(Debug, Clone, PartialEq)]
+pub enum WaitCondition {
+    /// 进程已退出（包括已终止但尚未被回收的僵尸进程）
+    Exited,
+    /// 物理内存使用超过指定字节数
+    MemoryAbove(u64),
+    /// CPU 使用率低于指定百分比（至少采样两次后才会判断）
+    CpuBelow(f32),
+    /// 等待时间达到上限
+    Timeout(Duration),
+}
+
+/// `wait_for` 的结果
+#[derive(Debug, Clone)]
+pub struct WaitOutcome {
+    /// 最先满足的条件
+    pub condition: WaitCondition,
+    /// 从开始等待到条件满足经过的时间
+    pub elapsed: Duration,
+    /// 条件满足时最后一次采样到的进程信息，进程已不存在时为 None
+    pub process: Option<sysinfo::ProcessInfo>,
+}
+
 impl ProcessManager {
     /// 获取所有进程列表
     pub fn list_processes(&self) -> SystemResult<Vec<ProcessInfo>> {
-        // TODO: 实现进程列表获取
-        Ok(Vec::new())
+        let processes = shared_system_info().get_processes()?;
+        Ok(processes.into_iter().map(ProcessInfo::from).collect())
     }
     
     /// 根据PID获取进程信息
-    pub fn get_process_by_pid(&self, _pid: u32) -> SystemResult<Option<ProcessInfo>> {
-        // TODO: 实现根据PID获取进程信息
-        Ok(None)
+    pub fn get_process_by_pid(&self, pid: u32) -> SystemResult<Option<ProcessInfo>> {
+        let manager = shared_system_info();
+        if !manager.refresh_process(pid)? {
+            return Ok(None);
+        }
+        Ok(manager.get_process_by_pid(pid)?.map(ProcessInfo::from))
     }
     
-    /// 根据名称查找进程
-    pub fn find_processes_by_name(&self, _name: &str) -> SystemResult<Vec<ProcessInfo>> {
-        // TODO: 实现根据名称查找进程
-        Ok(Vec::new())
+    /// 根据名称查找进程（名称包含 `name`，不区分大小写）
+    pub fn find_processes_by_name(&self, name: &str) -> SystemResult<Vec<ProcessInfo>> {
+        let processes = shared_system_info().find_processes_by_name(name)?;
+        Ok(processes.into_iter().map(ProcessInfo::from).collect())
     }
     
-    /// 终止进程
-    pub fn kill_process(&self, _pid: u32) -> SystemResult<()> {
-        // TODO: 实现终止进程
-        Err(SystemError::NotSupported("Not implemented yet".to_string()))
+    /// 终止进程（Unix 上发送 SIGTERM，进程可以自行清理后退出）
+    /// 进程不存在时返回 NotFound，无权限时返回 PermissionDenied
+    pub fn kill_process(&self, pid: u32) -> SystemResult<()> {
+        #[cfg(unix)]
+        return unix::terminate(pid);
+
+        #[cfg(target_os = "windows")]
+        return windows::terminate(pid);
+
+        #[cfg(not(any(unix, target_os = "windows")))]
+        {
+            let _ = pid;
+            Err(SystemError::NotSupported("Platform not supported".to_string()))
+        }
+    }
+
+    /// 先请求进程退出，在 `timeout` 内未退出时强制结束（Unix 上为 SIGTERM 后 SIGKILL）
+    pub fn kill_process_with_timeout(&self, pid: u32, timeout: Duration) -> SystemResult<()> {
+        self.kill_process(pid)?;
+        if self.wait_for_exit(pid, Some(timeout))? {
+            return Ok(());
+        }
+
+        let result = {
+            #[cfg(unix)]
+            { unix::force_kill(pid) }
+
+            #[cfg(target_os = "windows")]
+            { windows::force_kill(pid) }
+
+            #[cfg(not(any(unix, target_os = "windows")))]
+            { Err(SystemError::NotSupported("Platform not supported".to_string())) }
+        };
+        match result {
+            // 在超时与强制结束之间自行退出
+            Err(SystemError::NotFound(_)) => Ok(()),
+            other => other,
+        }
+    }
+
+    /// 按 `poll` 间隔采样进程，直到任一条件满足，返回最先满足的条件
+    /// 同一次采样中多个条件同时满足时，以 `conditions` 中靠前的为准
+    /// 进程退出而条件中没有 `Exited` 时返回 NotFound 错误
+    pub fn wait_for(&self, pid: u32, conditions: &[WaitCondition], poll: Duration) -> SystemResult<WaitOutcome> {
+        if conditions.is_empty() {
+            return Err(SystemError::InvalidArgument("No wait condition given".to_string()));
+        }
+
+        // 复用共享的管理器，每次采样只单独刷新目标进程
+        let manager = shared_system_info();
+        let deadline = conditions.iter().filter_map(|condition| match condition {
+            WaitCondition::Timeout(timeout) => Some(*timeout),
+            _ => None,
+        }).min();
+
+        let start = Instant::now();
+        let mut samples = 0u32;
+        loop {
+            let process = if manager.refresh_process(pid)? {
+                manager.get_process_by_pid(pid)?
+            } else {
+                None
+            };
+            let exited = process
+                .as_ref()
+                .is_none_or(|process| matches!(process.status, ProcessStatus::Zombie | ProcessStatus::Dead));
+            let elapsed = start.elapsed();
+            samples += 1;
+
+            let fired = conditions.iter().find(|condition| match condition {
+                WaitCondition::Exited => exited,
+                WaitCondition::MemoryAbove(limit) => !exited && process.as_ref().is_some_and(|process| process.memory_usage > Some(*limit)),
+                // 第一次采样的 CPU 使用率没有参照区间，总是 0
+                WaitCondition::CpuBelow(percent) => {
+                    !exited && samples > 1 && process.as_ref().and_then(|process| process.cpu_usage).is_some_and(|usage| usage < *percent)
+                }
+                WaitCondition::Timeout(timeout) => elapsed >= *timeout,
+            });
+            if let Some(condition) = fired {
+                return Ok(WaitOutcome { condition: condition.clone(), elapsed, process });
+            }
+            if exited {
+                return Err(SystemError::NotFound(format!("Process {} exited before any wait condition was met", pid)));
+            }
+
+            let sleep = match deadline {
+                Some(deadline) => poll.min(deadline.saturating_sub(start.elapsed())),
+                None => poll,
+            };
+            thread::sleep(sleep);
+        }
+    }
+
+    /// 等待进程退出，`timeout` 为 None 时一直等待
+    /// 返回 true 表示进程已退出，false 表示超时
+    pub fn wait_for_exit(&self, pid: u32, timeout: Option<Duration>) -> SystemResult<bool> {
+        let mut conditions = vec![WaitCondition::Exited];
+        conditions.extend(timeout.map(WaitCondition::Timeout));
+        let outcome = self.wait_for(pid, &conditions, Duration::from_millis(100))?;
+        Ok(outcome.condition == WaitCondition::Exited)
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    #[test]
+    fn test_wait_for_exit_or_timeout() {
+        let manager = ProcessManager;
+        let poll = Duration::from_millis(20);
+
+        let mut short = Command::new("sleep").arg("0.2").spawn().expect("failed to spawn sleep");
+        let outcome = manager
+            .wait_for(short.id(), &[WaitCondition::Timeout(Duration::from_secs(10)), WaitCondition::Exited], poll)
+            .unwrap();
+        assert_eq!(outcome.condition, WaitCondition::Exited);
+        short.wait().unwrap();
+
+        let mut long = Command::new("sleep").arg("30").spawn().expect("failed to spawn sleep");
+        let outcome = manager
+            .wait_for(long.id(), &[WaitCondition::Exited, WaitCondition::Timeout(Duration::from_millis(200))], poll)
+            .unwrap();
+        assert_eq!(outcome.condition, WaitCondition::Timeout(Duration::from_millis(200)));
+        assert!(outcome.process.is_some());
+        long.kill().unwrap();
+        long.wait().unwrap();
+    }
+
+    #[test]
+    fn test_list_processes_includes_self() {
+        let manager = ProcessManager;
+        let pid = std::process::id();
+
+        assert!(manager.list_processes().unwrap().iter().any(|process| process.pid == pid));
+        let current = manager.get_process_by_pid(pid).unwrap().expect("current process not found");
+        assert_eq!(current.pid, pid);
+        assert!(current.memory_usage.unwrap_or(0) > 0);
+    }
+
+    #[test]
+    fn test_kill_process_with_timeout() {
+        let manager = ProcessManager;
+
+        // 忽略 SIGTERM 的进程只能被 SIGKILL 结束（被忽略的信号在 exec 后保持忽略）
+        let mut stubborn = Command::new("sh")
+            .args(["-c", "trap '' TERM; exec sleep 30"])
+            .spawn()
+            .expect("failed to spawn sh");
+        std::thread::sleep(Duration::from_millis(100));
+        manager.kill_process_with_timeout(stubborn.id(), Duration::from_millis(300)).unwrap();
+        assert!(manager.wait_for_exit(stubborn.id(), Some(Duration::from_secs(5))).unwrap());
+        stubborn.wait().unwrap();
+
+        assert!(matches!(manager.kill_process(stubborn.id()), Err(SystemError::NotFound(_))));
+        assert!(matches!(manager.kill_process(0), Err(SystemError::InvalidArgument(_))));
     }
-} 
\ No newline at end of file
+}
@@ -0,0 +1,34 @@
+// Unix 进程控制（Linux / macOS）
+// 通过 kill(2) 发送信号
+
+use crate::system::common::*;
+
+/// 向进程发送信号
+pub fn send_signal(pid: u32, signal: libc::c_int) -> SystemResult<()> {
+    // pid 为 0 或超出 pid_t 范围时 kill 会作用于整个进程组，必须拒绝
+    let raw_pid = libc::pid_t::try_from(pid)
+        .ok()
+        .filter(|pid| *pid > 0)
+        .ok_or_else(|| SystemError::InvalidArgument(format!("Invalid pid: {}", pid)))?;
+
+    if unsafe { libc::kill(raw_pid, signal) } == 0 {
+        return Ok(());
+    }
+
+    let error = std::io::Error::last_os_error();
+    match error.raw_os_error() {
+        Some(libc::ESRCH) => Err(SystemError::NotFound(format!("Process {} not found", pid))),
+        Some(libc::EPERM) => Err(SystemError::PermissionDenied(format!("Not allowed to signal process {}", pid))),
+        _ => Err(SystemError::ProcessError(format!("Failed to signal process {}: {}", pid, error))),
+    }
+}
+
+/// 请求进程退出（SIGTERM）
+pub fn terminate(pid: u32) -> SystemResult<()> {
+    send_signal(pid, libc::SIGTERM)
+}
+
+/// 强制结束进程（SIGKILL）
+pub fn force_kill(pid: u32) -> SystemResult<()> {
+    send_signal(pid, libc::SIGKILL)
+}
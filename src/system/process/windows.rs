@@ -0,0 +1,14 @@
+// Windows 进程控制
+// 尚未实现，保留与 Unix 相同的接口以便上层统一分发
+
+use crate::system::common::*;
+
+/// 请求进程退出
+pub fn terminate(_pid: u32) -> SystemResult<()> {
+    Err(SystemError::NotSupported("Process termination is not implemented on Windows yet".to_string()))
+}
+
+/// 强制结束进程
+pub fn force_kill(_pid: u32) -> SystemResult<()> {
+    Err(SystemError::NotSupported("Process termination is not implemented on Windows yet".to_string()))
+}
@@ -1,13 +1,341 @@
 // Windows注册表工具模块
-#[cfg(target_os = "windows")]
+// 整个模块只在 Windows 上编译（见 system/mod.rs）
+
+use std::fmt;
+
+use windows::core::PCWSTR;
+use windows::Win32::Foundation::{
+    ERROR_ACCESS_DENIED, ERROR_FILE_NOT_FOUND, ERROR_MORE_DATA, ERROR_NO_MORE_ITEMS, ERROR_PATH_NOT_FOUND,
+    ERROR_SUCCESS, WIN32_ERROR,
+};
+use windows::Win32::System::Registry::{
+    RegCloseKey, RegCreateKeyExW, RegDeleteValueW, RegEnumKeyExW, RegEnumValueW, RegOpenKeyExW, RegQueryInfoKeyW,
+    RegQueryValueExW, RegSetValueExW, HKEY, HKEY_CLASSES_ROOT, HKEY_CURRENT_CONFIG, HKEY_CURRENT_USER,
+    HKEY_LOCAL_MACHINE, HKEY_USERS, KEY_ENUMERATE_SUB_KEYS, KEY_QUERY_VALUE, KEY_READ, KEY_SET_VALUE,
+    REG_BINARY, REG_DWORD, REG_EXPAND_SZ, REG_MULTI_SZ, REG_OPTION_NON_VOLATILE, REG_QWORD, REG_SAM_FLAGS,
+    REG_SZ, REG_VALUE_TYPE,
+};
+
 use crate::system::common::*;
 
-#[cfg(target_os = "windows")]
+/// 注册表值
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryValue {
+    String(String),
+    ExpandString(String),
+    MultiString(Vec<String>),
+    Dword(u32),
+    Qword(u64),
+    Binary(Vec<u8>),
+}
+
+impl RegistryValue {
+    fn value_type(&self) -> REG_VALUE_TYPE {
+        match self {
+            RegistryValue::String(_) => REG_SZ,
+            RegistryValue::ExpandString(_) => REG_EXPAND_SZ,
+            RegistryValue::MultiString(_) => REG_MULTI_SZ,
+            RegistryValue::Dword(_) => REG_DWORD,
+            RegistryValue::Qword(_) => REG_QWORD,
+            RegistryValue::Binary(_) => REG_BINARY,
+        }
+    }
+
+    /// 转换为 RegSetValueExW 需要的字节表示，字符串以 UTF-16 存储并带结尾 NUL
+    fn to_bytes(&self) -> Vec<u8> {
+        let utf16 = |units: Vec<u16>| units.into_iter().flat_map(u16::to_le_bytes).collect();
+        match self {
+            RegistryValue::String(s) | RegistryValue::ExpandString(s) => utf16(wide(s)),
+            RegistryValue::MultiString(items) => {
+                let mut units: Vec<u16> = items.iter().flat_map(|item| wide(item)).collect();
+                units.push(0);
+                utf16(units)
+            }
+            RegistryValue::Dword(value) => value.to_le_bytes().to_vec(),
+            RegistryValue::Qword(value) => value.to_le_bytes().to_vec(),
+            RegistryValue::Binary(bytes) => bytes.clone(),
+        }
+    }
+
+    fn from_bytes(value_type: REG_VALUE_TYPE, data: &[u8]) -> Self {
+        let units = || -> Vec<u16> {
+            data.chunks_exact(2).map(|pair| u16::from_le_bytes([pair[0], pair[1]])).collect()
+        };
+        let string = |units: &[u16]| {
+            let end = units.iter().position(|&unit| unit == 0).unwrap_or(units.len());
+            String::from_utf16_lossy(&units[..end])
+        };
+
+        match value_type {
+            REG_SZ => RegistryValue::String(string(&units())),
+            REG_EXPAND_SZ => RegistryValue::ExpandString(string(&units())),
+            REG_MULTI_SZ => RegistryValue::MultiString(
+                units()
+                    .split(|&unit| unit == 0)
+                    .filter(|item| !item.is_empty())
+                    .map(String::from_utf16_lossy)
+                    .collect(),
+            ),
+            REG_DWORD if data.len() >= 4 => RegistryValue::Dword(u32::from_le_bytes([data[0], data[1], data[2], data[3]])),
+            REG_QWORD if data.len() >= 8 => {
+                RegistryValue::Qword(u64::from_le_bytes(data[..8].try_into().expect("slice length checked")))
+            }
+            _ => RegistryValue::Binary(data.to_vec()),
+        }
+    }
+}
+
+/// 字符串值原样输出，多字符串按行拼接，数值为十进制，二进制为小写十六进制
+impl fmt::Display for RegistryValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryValue::String(s) | RegistryValue::ExpandString(s) => f.write_str(s),
+            RegistryValue::MultiString(items) => f.write_str(&items.join("\n")),
+            RegistryValue::Dword(value) => write!(f, "{}", value),
+            RegistryValue::Qword(value) => write!(f, "{}", value),
+            RegistryValue::Binary(bytes) => f.write_str(&crate::system::security::to_hex(bytes)),
+        }
+    }
+}
+
+/// 打开的注册表键，释放时关闭
+struct OwnedKey(HKEY);
+
+impl Drop for OwnedKey {
+    fn drop(&mut self) {
+        unsafe { RegCloseKey(self.0) };
+    }
+}
+
 pub struct RegistryTools;
 
-#[cfg(target_os = "windows")]
 impl RegistryTools {
-    pub fn read_value(&self, _hkey: &str, _subkey: &str, _value_name: &str) -> SystemResult<String> {
-        Err(SystemError::NotSupported("Not implemented yet".to_string()))
+    /// 读取注册表值并转换为字符串
+    /// hkey 可以是 `HKLM`、`HKCU`、`HKCR`、`HKU`、`HKCC` 或对应的完整名称
+    pub fn read_value(&self, hkey: &str, subkey: &str, value_name: &str) -> SystemResult<String> {
+        Ok(self.query_value(hkey, subkey, value_name)?.to_string())
+    }
+
+    /// 读取注册表值，保留原始类型
+    pub fn query_value(&self, hkey: &str, subkey: &str, value_name: &str) -> SystemResult<RegistryValue> {
+        let key = Self::open_key(hkey, subkey, KEY_READ)?;
+        let name = wide(value_name);
+        let context = || format!("{}\\{}\\{}", hkey, subkey, value_name);
+
+        let mut value_type = REG_VALUE_TYPE::default();
+        let mut size = 0u32;
+        check(
+            unsafe { RegQueryValueExW(key.0, PCWSTR::from_raw(name.as_ptr()), None, Some(&mut value_type), None, Some(&mut size)) },
+            &context(),
+        )?;
+
+        // 两次调用之间值可能变大，遇到 ERROR_MORE_DATA 时按新的大小重试
+        let mut data = vec![0u8; size as usize];
+        loop {
+            size = data.len() as u32;
+            let code = unsafe {
+                RegQueryValueExW(
+                    key.0,
+                    PCWSTR::from_raw(name.as_ptr()),
+                    None,
+                    Some(&mut value_type),
+                    Some(data.as_mut_ptr()),
+                    Some(&mut size),
+                )
+            };
+            if code == ERROR_MORE_DATA {
+                data.resize(size as usize, 0);
+                continue;
+            }
+            check(code, &context())?;
+            break;
+        }
+
+        data.truncate(size as usize);
+        Ok(RegistryValue::from_bytes(value_type, &data))
+    }
+
+    /// 写入注册表值，子键不存在时自动创建
+    pub fn write_value(&self, hkey: &str, subkey: &str, value_name: &str, value: &RegistryValue) -> SystemResult<()> {
+        let root = root_key(hkey)?;
+        let subkey_wide = wide(subkey);
+        let context = format!("{}\\{}", hkey, subkey);
+
+        let mut raw = HKEY::default();
+        check(
+            unsafe {
+                RegCreateKeyExW(
+                    root,
+                    PCWSTR::from_raw(subkey_wide.as_ptr()),
+                    None,
+                    PCWSTR::null(),
+                    REG_OPTION_NON_VOLATILE,
+                    KEY_SET_VALUE,
+                    None,
+                    &mut raw,
+                    None,
+                )
+            },
+            &context,
+        )?;
+        let key = OwnedKey(raw);
+
+        let name = wide(value_name);
+        let data = value.to_bytes();
+        check(
+            unsafe { RegSetValueExW(key.0, PCWSTR::from_raw(name.as_ptr()), None, value.value_type(), Some(&data)) },
+            &format!("{}\\{}", context, value_name),
+        )
+    }
+
+    /// 删除注册表值
+    pub fn delete_value(&self, hkey: &str, subkey: &str, value_name: &str) -> SystemResult<()> {
+        let key = Self::open_key(hkey, subkey, KEY_SET_VALUE)?;
+        let name = wide(value_name);
+        check(
+            unsafe { RegDeleteValueW(key.0, PCWSTR::from_raw(name.as_ptr())) },
+            &format!("{}\\{}\\{}", hkey, subkey, value_name),
+        )
+    }
+
+    /// 列出子键名称
+    pub fn list_subkeys(&self, hkey: &str, subkey: &str) -> SystemResult<Vec<String>> {
+        let key = Self::open_key(hkey, subkey, KEY_ENUMERATE_SUB_KEYS | KEY_QUERY_VALUE)?;
+        let context = format!("{}\\{}", hkey, subkey);
+
+        let mut max_name_len = 0u32;
+        check(
+            unsafe {
+                RegQueryInfoKeyW(
+                    key.0, None, None, None, None, Some(&mut max_name_len), None, None, None, None, None, None,
+                )
+            },
+            &context,
+        )?;
+
+        let mut names = Vec::new();
+        let mut buffer = vec![0u16; max_name_len as usize + 1];
+        for index in 0.. {
+            let mut len = buffer.len() as u32;
+            let code = unsafe {
+                RegEnumKeyExW(
+                    key.0,
+                    index,
+                    Some(windows::core::PWSTR::from_raw(buffer.as_mut_ptr())),
+                    &mut len,
+                    None,
+                    None,
+                    None,
+                    None,
+                )
+            };
+            if code == ERROR_NO_MORE_ITEMS {
+                break;
+            }
+            // 枚举期间新增了更长的子键
+            if code == ERROR_MORE_DATA {
+                buffer.resize(buffer.len() * 2, 0);
+                continue;
+            }
+            check(code, &context)?;
+            names.push(String::from_utf16_lossy(&buffer[..len as usize]));
+        }
+
+        Ok(names)
+    }
+
+    /// 列出键下所有的值及其名称，默认值的名称为空字符串
+    pub fn list_values(&self, hkey: &str, subkey: &str) -> SystemResult<Vec<(String, RegistryValue)>> {
+        let key = Self::open_key(hkey, subkey, KEY_QUERY_VALUE)?;
+        let context = format!("{}\\{}", hkey, subkey);
+
+        let mut max_name_len = 0u32;
+        let mut max_data_len = 0u32;
+        check(
+            unsafe {
+                RegQueryInfoKeyW(
+                    key.0, None, None, None, None, None, None, None,
+                    Some(&mut max_name_len), Some(&mut max_data_len), None, None,
+                )
+            },
+            &context,
+        )?;
+
+        let mut values = Vec::new();
+        let mut name = vec![0u16; max_name_len as usize + 1];
+        let mut data = vec![0u8; max_data_len as usize];
+        let mut index = 0;
+        loop {
+            let mut name_len = name.len() as u32;
+            let mut data_len = data.len() as u32;
+            let mut value_type = 0u32;
+            let code = unsafe {
+                RegEnumValueW(
+                    key.0,
+                    index,
+                    Some(windows::core::PWSTR::from_raw(name.as_mut_ptr())),
+                    &mut name_len,
+                    None,
+                    Some(&mut value_type),
+                    Some(data.as_mut_ptr()),
+                    Some(&mut data_len),
+                )
+            };
+            if code == ERROR_NO_MORE_ITEMS {
+                break;
+            }
+            // 枚举期间新增了更长的值
+            if code == ERROR_MORE_DATA {
+                name.resize(name.len() * 2, 0);
+                data.resize((data.len() * 2).max(data_len as usize), 0);
+                continue;
+            }
+            check(code, &context)?;
+            values.push((
+                String::from_utf16_lossy(&name[..name_len as usize]),
+                RegistryValue::from_bytes(REG_VALUE_TYPE(value_type), &data[..data_len as usize]),
+            ));
+            index += 1;
+        }
+
+        Ok(values)
+    }
+
+    fn open_key(hkey: &str, subkey: &str, access: REG_SAM_FLAGS) -> SystemResult<OwnedKey> {
+        let root = root_key(hkey)?;
+        let subkey_wide = wide(subkey);
+        let mut raw = HKEY::default();
+        check(
+            unsafe { RegOpenKeyExW(root, PCWSTR::from_raw(subkey_wide.as_ptr()), None, access, &mut raw) },
+            &format!("{}\\{}", hkey, subkey),
+        )?;
+        Ok(OwnedKey(raw))
+    }
+}
+
+/// 将根键名称映射为预定义句柄
+fn root_key(name: &str) -> SystemResult<HKEY> {
+    match name.to_ascii_uppercase().as_str() {
+        "HKLM" | "HKEY_LOCAL_MACHINE" => Ok(HKEY_LOCAL_MACHINE),
+        "HKCU" | "HKEY_CURRENT_USER" => Ok(HKEY_CURRENT_USER),
+        "HKCR" | "HKEY_CLASSES_ROOT" => Ok(HKEY_CLASSES_ROOT),
+        "HKU" | "HKEY_USERS" => Ok(HKEY_USERS),
+        "HKCC" | "HKEY_CURRENT_CONFIG" => Ok(HKEY_CURRENT_CONFIG),
+        _ => Err(SystemError::InvalidArgument(format!("Unknown registry hive: {}", name))),
+    }
+}
+
+/// 转换为以 NUL 结尾的 UTF-16
+fn wide(s: &str) -> Vec<u16> {
+    s.encode_utf16().chain(std::iter::once(0)).collect()
+}
+
+/// 将注册表 API 的返回码转换为系统错误
+fn check(code: WIN32_ERROR, context: &str) -> SystemResult<()> {
+    match code {
+        ERROR_SUCCESS => Ok(()),
+        ERROR_FILE_NOT_FOUND | ERROR_PATH_NOT_FOUND => Err(SystemError::NotFound(format!("Registry entry not found: {}", context))),
+        ERROR_ACCESS_DENIED => Err(SystemError::PermissionDenied(format!("Registry access denied: {}", context))),
+        _ => Err(SystemError::Io(format!("Registry operation on {} failed with code {}", context, code.0))),
     }
-} 
\ No newline at end of file
+}
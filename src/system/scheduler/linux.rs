@@ -0,0 +1,340 @@
+// Linux 任务调度
+// 通过当前用户的 crontab 管理任务，每个任务前有一行 `# lycrex-task:<id> <name>` 标记
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use crate::system::common::*;
+
+const MARKER: &str = "# lycrex-task:";
+
+/// 写入任务，id 相同的已有任务会被替换
+pub fn schedule_task(task: &ScheduledTask) -> SystemResult<()> {
+    if task.run_as_user.is_some() {
+        return Err(SystemError::NotSupported(
+            "cron backend only manages the current user's crontab".to_string(),
+        ));
+    }
+    let entry = render_entry(task)?;
+    let (crontab, _) = remove_entry(&read_crontab()?, &task.id);
+    write_crontab(&append_entry(&crontab, &entry))
+}
+
+/// 删除任务
+pub fn remove_task(id: &str) -> SystemResult<()> {
+    let (crontab, removed) = remove_entry(&read_crontab()?, id);
+    if !removed {
+        return Err(SystemError::NotFound(format!("Scheduled task not found: {}", id)));
+    }
+    write_crontab(&crontab)
+}
+
+/// 列出由本库写入的任务
+pub fn list_tasks() -> SystemResult<Vec<ScheduledTask>> {
+    Ok(parse_tasks(&read_crontab()?))
+}
+
+fn read_crontab() -> SystemResult<String> {
+    let output = Command::new("crontab").arg("-l").output().map_err(crontab_error)?;
+    if output.status.success() {
+        return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+    }
+
+    // 用户还没有 crontab 时 `crontab -l` 以非零状态退出
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    if stderr.contains("no crontab") {
+        return Ok(String::new());
+    }
+    Err(SystemError::ProcessError(format!("crontab -l failed: {}", stderr.trim())))
+}
+
+fn write_crontab(content: &str) -> SystemResult<()> {
+    let mut child = Command::new("crontab")
+        .arg("-")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(crontab_error)?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(content.as_bytes())?;
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(SystemError::ProcessError(format!("crontab - failed: {}", stderr.trim())));
+    }
+    Ok(())
+}
+
+fn crontab_error(error: std::io::Error) -> SystemError {
+    match error.kind() {
+        std::io::ErrorKind::NotFound => SystemError::NotSupported("crontab command not available".to_string()),
+        _ => SystemError::ProcessError(format!("Failed to execute crontab: {}", error)),
+    }
+}
+
+/// 生成标记行和任务行，禁用的任务以注释形式保留
+fn render_entry(task: &ScheduledTask) -> SystemResult<String> {
+    if task.id.is_empty() || task.id.chars().any(char::is_whitespace) {
+        return Err(SystemError::InvalidArgument(format!("Invalid task id: {:?}", task.id)));
+    }
+    if task.command.trim().is_empty() || task.command.contains('\n') {
+        return Err(SystemError::InvalidArgument("Task command cannot be empty or multi-line".to_string()));
+    }
+
+    let mut command_line = shell_quote(&task.command);
+    for argument in &task.arguments {
+        if argument.contains('\n') {
+            return Err(SystemError::InvalidArgument("Task arguments cannot contain newlines".to_string()));
+        }
+        command_line.push(' ');
+        command_line.push_str(&shell_quote(argument));
+    }
+    // crontab 中未转义的 % 会被替换为换行
+    let command_line = command_line.replace('%', "\\%");
+
+    let name = task.name.replace(['\r', '\n'], " ");
+    let disabled = if task.enabled { "" } else { "# " };
+    Ok(format!(
+        "{}{} {}\n{}{} {}\n",
+        MARKER,
+        task.id,
+        name.trim(),
+        disabled,
+        cron_expression(&task.schedule)?,
+        command_line
+    ))
+}
+
+/// 将调度规则转换为 cron 表达式
+fn cron_expression(schedule: &TaskSchedule) -> SystemResult<String> {
+    let check = |value: u8, min: u8, max: u8, field: &str| {
+        if value < min || value > max {
+            return Err(SystemError::InvalidArgument(format!("{} out of range: {}", field, value)));
+        }
+        Ok(value)
+    };
+
+    match schedule {
+        TaskSchedule::Daily(hour, minute) => Ok(format!(
+            "{} {} * * *",
+            check(*minute, 0, 59, "minute")?,
+            check(*hour, 0, 23, "hour")?
+        )),
+        TaskSchedule::Weekly(weekday, hour, minute) => Ok(format!(
+            "{} {} * * {}",
+            check(*minute, 0, 59, "minute")?,
+            check(*hour, 0, 23, "hour")?,
+            check(*weekday, 0, 7, "weekday")?
+        )),
+        TaskSchedule::Monthly(day, hour, minute) => Ok(format!(
+            "{} {} {} * *",
+            check(*minute, 0, 59, "minute")?,
+            check(*hour, 0, 23, "hour")?,
+            check(*day, 1, 31, "day")?
+        )),
+        TaskSchedule::Interval(seconds) => interval_expression(*seconds),
+        TaskSchedule::Cron(expression) => {
+            let expression = expression.trim();
+            if expression.contains('\n')
+                || !(expression.starts_with('@') || expression.split_whitespace().count() == 5)
+            {
+                return Err(SystemError::InvalidArgument(format!("Invalid cron expression: {:?}", expression)));
+            }
+            Ok(expression.to_string())
+        }
+        TaskSchedule::OnBoot => Ok("@reboot".to_string()),
+        TaskSchedule::Once(_) => Err(SystemError::NotSupported("cron cannot express one-time tasks".to_string())),
+        TaskSchedule::OnLogin => Err(SystemError::NotSupported("cron cannot run tasks on login".to_string())),
+    }
+}
+
+/// cron 的步长只能整除上一级单位，无法表示的间隔返回 InvalidArgument
+fn interval_expression(seconds: u64) -> SystemResult<String> {
+    let invalid = || SystemError::InvalidArgument(format!("Interval of {} seconds cannot be expressed in cron", seconds));
+    if seconds == 0 || !seconds.is_multiple_of(60) {
+        return Err(invalid());
+    }
+
+    let minutes = seconds / 60;
+    match minutes {
+        1 => Ok("* * * * *".to_string()),
+        m if m < 60 && 60u64.is_multiple_of(m) => Ok(format!("*/{} * * * *", m)),
+        60 => Ok("0 * * * *".to_string()),
+        m if m.is_multiple_of(60) && m < 1440 && 24u64.is_multiple_of(m / 60) => Ok(format!("0 */{} * * *", m / 60)),
+        1440 => Ok("0 0 * * *".to_string()),
+        _ => Err(invalid()),
+    }
+}
+
+/// 从 cron 表达式还原调度规则，无法识别的形式保留为 Cron
+fn parse_schedule(expression: &str) -> TaskSchedule {
+    if expression == "@reboot" {
+        return TaskSchedule::OnBoot;
+    }
+
+    let fields: Vec<&str> = expression.split_whitespace().collect();
+    let number = |field: &str| field.parse::<u8>().ok();
+    let step = |field: &str| field.strip_prefix("*/")?.parse::<u64>().ok();
+
+    match fields.as_slice() {
+        ["*", "*", "*", "*", "*"] => TaskSchedule::Interval(60),
+        [m, "*", "*", "*", "*"] if step(m).is_some() => TaskSchedule::Interval(step(m).unwrap_or(1) * 60),
+        ["0", "*", "*", "*", "*"] => TaskSchedule::Interval(3600),
+        ["0", h, "*", "*", "*"] if step(h).is_some() => TaskSchedule::Interval(step(h).unwrap_or(1) * 3600),
+        [m, h, "*", "*", "*"] if number(m).is_some() && number(h).is_some() => {
+            TaskSchedule::Daily(number(h).unwrap_or(0), number(m).unwrap_or(0))
+        }
+        [m, h, "*", "*", d] if number(m).is_some() && number(h).is_some() && number(d).is_some() => {
+            TaskSchedule::Weekly(number(d).unwrap_or(0), number(h).unwrap_or(0), number(m).unwrap_or(0))
+        }
+        [m, h, d, "*", "*"] if number(m).is_some() && number(h).is_some() && number(d).is_some() => {
+            TaskSchedule::Monthly(number(d).unwrap_or(1), number(h).unwrap_or(0), number(m).unwrap_or(0))
+        }
+        _ => TaskSchedule::Cron(expression.to_string()),
+    }
+}
+
+/// 删除 id 对应的标记行及其后的任务行，返回新内容以及是否找到
+fn remove_entry(crontab: &str, id: &str) -> (String, bool) {
+    let mut result = String::with_capacity(crontab.len());
+    let mut removed = false;
+    let mut lines = crontab.lines();
+
+    while let Some(line) = lines.next() {
+        if marker_id(line).is_some_and(|(marker, _)| marker == id) {
+            removed = true;
+            lines.next();
+            continue;
+        }
+        result.push_str(line);
+        result.push('\n');
+    }
+    (result, removed)
+}
+
+fn append_entry(crontab: &str, entry: &str) -> String {
+    let mut result = crontab.to_string();
+    if !result.is_empty() && !result.ends_with('\n') {
+        result.push('\n');
+    }
+    result.push_str(entry);
+    result
+}
+
+/// 解析标记行，返回 (id, name)
+fn marker_id(line: &str) -> Option<(&str, &str)> {
+    let rest = line.trim().strip_prefix(MARKER)?;
+    Some(rest.split_once(' ').unwrap_or((rest, "")))
+}
+
+fn parse_tasks(crontab: &str) -> Vec<ScheduledTask> {
+    let mut tasks = Vec::new();
+    let mut lines = crontab.lines();
+
+    while let Some(line) = lines.next() {
+        let Some((id, name)) = marker_id(line) else { continue };
+        let Some(entry) = lines.next() else { break };
+
+        let (enabled, entry) = match entry.trim().strip_prefix('#') {
+            Some(rest) => (false, rest.trim()),
+            None => (true, entry.trim()),
+        };
+        let (expression, command) = split_entry(entry);
+
+        tasks.push(ScheduledTask {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: None,
+            // 参数已经按 shell 规则拼接进命令行，这里不再拆分
+            command: command.replace("\\%", "%"),
+            arguments: Vec::new(),
+            schedule: parse_schedule(expression),
+            enabled,
+            last_run: None,
+            next_run: None,
+            run_as_user: None,
+        });
+    }
+    tasks
+}
+
+/// 拆分 cron 行的时间部分和命令部分
+fn split_entry(entry: &str) -> (&str, &str) {
+    let field_count = if entry.starts_with('@') { 1 } else { 5 };
+    let mut end = 0;
+    for _ in 0..field_count {
+        let rest = &entry[end..];
+        let start = end + (rest.len() - rest.trim_start().len());
+        end = entry[start..].find(char::is_whitespace).map_or(entry.len(), |offset| start + offset);
+    }
+    (entry[..end].trim(), entry[end..].trim())
+}
+
+/// 仅包含安全字符时原样输出，否则使用单引号包裹
+fn shell_quote(value: &str) -> String {
+    let safe = !value.is_empty()
+        && value.chars().all(|c| c.is_ascii_alphanumeric() || "_-./:=@,+".contains(c));
+    if safe {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: &str, schedule: TaskSchedule) -> ScheduledTask {
+        ScheduledTask {
+            id: id.to_string(),
+            name: "Backup job".to_string(),
+            description: None,
+            command: "/usr/bin/backup".to_string(),
+            arguments: vec!["--dest".to_string(), "my files".to_string(), "50%".to_string()],
+            schedule,
+            enabled: true,
+            last_run: None,
+            next_run: None,
+            run_as_user: None,
+        }
+    }
+
+    #[test]
+    fn test_crontab_entries() {
+        assert_eq!(cron_expression(&TaskSchedule::Weekly(1, 3, 30)).unwrap(), "30 3 * * 1");
+        assert_eq!(cron_expression(&TaskSchedule::Interval(900)).unwrap(), "*/15 * * * *");
+        assert_eq!(cron_expression(&TaskSchedule::Interval(6 * 3600)).unwrap(), "0 */6 * * *");
+        assert!(cron_expression(&TaskSchedule::Interval(90)).is_err());
+        assert!(cron_expression(&TaskSchedule::Daily(24, 0)).is_err());
+
+        let existing = "MAILTO=\"\"\n0 * * * * /usr/bin/other\n";
+        let entry = render_entry(&task("backup", TaskSchedule::Daily(2, 5))).unwrap();
+        assert_eq!(
+            entry,
+            "# lycrex-task:backup Backup job\n5 2 * * * /usr/bin/backup --dest 'my files' '50\\%'\n"
+        );
+
+        let mut disabled = task("hourly", TaskSchedule::Interval(3600));
+        disabled.enabled = false;
+        let crontab = append_entry(&append_entry(existing, &entry), &render_entry(&disabled).unwrap());
+
+        let tasks = parse_tasks(&crontab);
+        assert_eq!(tasks.len(), 2);
+        assert_eq!(tasks[0].id, "backup");
+        assert_eq!(tasks[0].name, "Backup job");
+        assert!(matches!(tasks[0].schedule, TaskSchedule::Daily(2, 5)));
+        assert_eq!(tasks[0].command, "/usr/bin/backup --dest 'my files' '50%'");
+        assert!(!tasks[1].enabled);
+        assert!(matches!(tasks[1].schedule, TaskSchedule::Interval(3600)));
+
+        let (remaining, removed) = remove_entry(&crontab, "backup");
+        assert!(removed);
+        assert!(remaining.starts_with(existing));
+        assert_eq!(parse_tasks(&remaining).len(), 1);
+        assert!(!remove_entry(&remaining, "backup").1);
+    }
+}
@@ -0,0 +1,124 @@
+// macOS 任务调度
+// 每个任务对应 ~/Library/LaunchAgents/<id>.plist，由 launchd 按 StartInterval / StartCalendarInterval 触发
+
+use std::fs;
+use std::path::PathBuf;
+
+use crate::system::common::macos::{launchctl_load, launchctl_unload, user_launch_agents_dir, CalendarInterval, LaunchPlist};
+use crate::system::common::*;
+
+/// 写入 LaunchAgent 并加载，id 相同的已有任务会被替换
+pub fn schedule_task(task: &ScheduledTask) -> SystemResult<()> {
+    if task.run_as_user.is_some() {
+        return Err(SystemError::NotSupported(
+            "launchd backend only manages the current user's LaunchAgents".to_string(),
+        ));
+    }
+    if task.command.trim().is_empty() {
+        return Err(SystemError::InvalidArgument("Task command cannot be empty".to_string()));
+    }
+
+    let mut plist = LaunchPlist {
+        label: task.id.clone(),
+        program: task.command.clone(),
+        program_arguments: task.arguments.clone(),
+        ..Default::default()
+    };
+    match &task.schedule {
+        TaskSchedule::Interval(seconds) => {
+            let seconds = u32::try_from(*seconds)
+                .ok()
+                .filter(|&seconds| seconds > 0)
+                .ok_or_else(|| SystemError::InvalidArgument(format!("Invalid interval: {} seconds", seconds)))?;
+            plist.start_interval = Some(seconds);
+        }
+        TaskSchedule::OnLogin => plist.run_at_load = true,
+        TaskSchedule::OnBoot => {
+            return Err(SystemError::NotSupported("LaunchAgents cannot run before login".to_string()))
+        }
+        TaskSchedule::Once(_) => {
+            return Err(SystemError::NotSupported("launchd cannot express one-time tasks".to_string()))
+        }
+        schedule => plist.start_calendar_interval = Some(calendar_interval(schedule)?),
+    }
+
+    let plist_path = plist_path(&task.id)?;
+    // 先卸载旧任务，否则 launchd 仍使用旧的配置
+    if plist_path.exists() {
+        launchctl_unload(&plist_path);
+    }
+    fs::write(&plist_path, plist.to_plist_xml())?;
+
+    if task.enabled {
+        launchctl_load(&plist_path)?;
+    }
+    Ok(())
+}
+
+/// 卸载并删除任务
+pub fn remove_task(id: &str) -> SystemResult<()> {
+    let plist_path = plist_path(id)?;
+    if !plist_path.exists() {
+        return Err(SystemError::NotFound(format!("Scheduled task not found: {}", id)));
+    }
+    launchctl_unload(&plist_path);
+    fs::remove_file(&plist_path)?;
+    Ok(())
+}
+
+fn plist_path(id: &str) -> SystemResult<PathBuf> {
+    if id.is_empty() || id.contains(['/', '\\']) || id.starts_with('.') {
+        return Err(SystemError::InvalidArgument(format!("Invalid task id: {:?}", id)));
+    }
+    Ok(user_launch_agents_dir()?.join(format!("{}.plist", id)))
+}
+
+/// 将日历类调度规则转换为 StartCalendarInterval
+/// Cron 表达式只支持每个字段为数字或 `*` 的简单形式
+fn calendar_interval(schedule: &TaskSchedule) -> SystemResult<CalendarInterval> {
+    let check = |value: u8, min: u8, max: u8, field: &str| {
+        if value < min || value > max {
+            return Err(SystemError::InvalidArgument(format!("{} out of range: {}", field, value)));
+        }
+        Ok(Some(value))
+    };
+
+    match schedule {
+        TaskSchedule::Daily(hour, minute) => Ok(CalendarInterval {
+            minute: check(*minute, 0, 59, "minute")?,
+            hour: check(*hour, 0, 23, "hour")?,
+            ..Default::default()
+        }),
+        TaskSchedule::Weekly(weekday, hour, minute) => Ok(CalendarInterval {
+            minute: check(*minute, 0, 59, "minute")?,
+            hour: check(*hour, 0, 23, "hour")?,
+            weekday: check(*weekday, 0, 7, "weekday")?,
+            ..Default::default()
+        }),
+        TaskSchedule::Monthly(day, hour, minute) => Ok(CalendarInterval {
+            minute: check(*minute, 0, 59, "minute")?,
+            hour: check(*hour, 0, 23, "hour")?,
+            day: check(*day, 1, 31, "day")?,
+            ..Default::default()
+        }),
+        TaskSchedule::Cron(expression) => {
+            let invalid = || SystemError::NotSupported(format!("Unsupported cron expression for launchd: {:?}", expression));
+            let fields: Vec<&str> = expression.split_whitespace().collect();
+            let [minute, hour, day, month, weekday] = fields.as_slice() else {
+                return Err(invalid());
+            };
+            let field = |value: &str, min: u8, max: u8, name: &str| match value {
+                "*" => Ok(None),
+                value => check(value.parse().map_err(|_| invalid())?, min, max, name),
+            };
+            Ok(CalendarInterval {
+                minute: field(minute, 0, 59, "minute")?,
+                hour: field(hour, 0, 23, "hour")?,
+                day: field(day, 1, 31, "day")?,
+                month: field(month, 1, 12, "month")?,
+                weekday: field(weekday, 0, 7, "weekday")?,
+            })
+        }
+        _ => Err(SystemError::InvalidArgument(format!("Not a calendar schedule: {:?}", schedule))),
+    }
+}
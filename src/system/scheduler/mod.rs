@@ -1,10 +1,50 @@
 // 任务调度模块
 use crate::system::common::*;
 
+#[cfg(target_os = "linux")]
+mod linux;
+#[cfg(target_os = "macos")]
+mod macos;
+
 pub struct TaskScheduler;
 
 impl TaskScheduler {
-    pub fn schedule_task(&self, _task: &ScheduledTask) -> SystemResult<()> {
+    /// 添加计划任务，id 相同的已有任务会被替换
+    pub fn schedule_task(&self, task: &ScheduledTask) -> SystemResult<()> {
+        #[cfg(target_os = "linux")]
+        return linux::schedule_task(task);
+
+        #[cfg(target_os = "macos")]
+        return macos::schedule_task(task);
+
+        #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+        {
+            let _ = task;
+            Err(SystemError::NotSupported("Not implemented yet".to_string()))
+        }
+    }
+
+    /// 删除计划任务
+    pub fn remove_task(&self, id: &str) -> SystemResult<()> {
+        #[cfg(target_os = "linux")]
+        return linux::remove_task(id);
+
+        #[cfg(target_os = "macos")]
+        return macos::remove_task(id);
+
+        #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+        {
+            let _ = id;
+            Err(SystemError::NotSupported("Not implemented yet".to_string()))
+        }
+    }
+
+    /// 列出由本库创建的计划任务
+    pub fn list_tasks(&self) -> SystemResult<Vec<ScheduledTask>> {
+        #[cfg(target_os = "linux")]
+        return linux::list_tasks();
+
+        #[cfg(not(target_os = "linux"))]
         Err(SystemError::NotSupported("Not implemented yet".to_string()))
     }
-} 
\ No newline at end of file
+}
@@ -0,0 +1,594 @@
+// 哈希算法实现
+// 纯 Rust 实现的流式哈希，支持分块输入，适合处理大文件和进程内存
+// BLAKE3 需要启用 `crypto` feature
+
+/// 哈希算法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HashAlgorithm {
+    Sha256,
+    Sha512,
+    /// 需要启用 `crypto` feature
+    #[cfg(feature = "crypto")]
+    Blake3,
+}
+
+/// 流式哈希计算器
+pub enum Hasher {
+    Sha256(Sha256),
+    Sha512(Sha512),
+    #[cfg(feature = "crypto")]
+    Blake3(Blake3),
+}
+
+impl Hasher {
+    pub fn new(algo: HashAlgorithm) -> Self {
+        match algo {
+            HashAlgorithm::Sha256 => Hasher::Sha256(Sha256::new()),
+            HashAlgorithm::Sha512 => Hasher::Sha512(Sha512::new()),
+            #[cfg(feature = "crypto")]
+            HashAlgorithm::Blake3 => Hasher::Blake3(Blake3::new()),
+        }
+    }
+
+    /// 追加数据
+    pub fn update(&mut self, data: &[u8]) {
+        match self {
+            Hasher::Sha256(hasher) => hasher.update(data),
+            Hasher::Sha512(hasher) => hasher.update(data),
+            #[cfg(feature = "crypto")]
+            Hasher::Blake3(hasher) => hasher.update(data),
+        }
+    }
+
+    /// 结束计算并返回摘要
+    pub fn finalize(self) -> Vec<u8> {
+        match self {
+            Hasher::Sha256(hasher) => hasher.finalize().to_vec(),
+            Hasher::Sha512(hasher) => hasher.finalize().to_vec(),
+            #[cfg(feature = "crypto")]
+            Hasher::Blake3(hasher) => hasher.finalize().to_vec(),
+        }
+    }
+
+    /// 结束计算并返回小写十六进制摘要
+    pub fn finalize_hex(self) -> String {
+        to_hex(&self.finalize())
+    }
+}
+
+/// 转换为小写十六进制字符串
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+const SHA256_K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// SHA-256
+#[derive(Clone)]
+pub struct Sha256 {
+    state: [u32; 8],
+    buffer: [u8; 64],
+    buffer_len: usize,
+    total_len: u64,
+}
+
+impl Default for Sha256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sha256 {
+    pub fn new() -> Self {
+        Self {
+            state: [
+                0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+            ],
+            buffer: [0; 64],
+            buffer_len: 0,
+            total_len: 0,
+        }
+    }
+
+    pub fn update(&mut self, mut data: &[u8]) {
+        self.total_len = self.total_len.wrapping_add(data.len() as u64);
+
+        if self.buffer_len > 0 {
+            let take = (64 - self.buffer_len).min(data.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&data[..take]);
+            self.buffer_len += take;
+            data = &data[take..];
+            if self.buffer_len < 64 {
+                return;
+            }
+            let block = self.buffer;
+            self.compress(&block);
+            self.buffer_len = 0;
+        }
+
+        let mut blocks = data.chunks_exact(64);
+        for block in &mut blocks {
+            self.compress(block);
+        }
+
+        let rest = blocks.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffer_len = rest.len();
+    }
+
+    pub fn finalize(mut self) -> [u8; 32] {
+        let bit_len = self.total_len.wrapping_mul(8);
+
+        let mut padding = vec![0x80u8];
+        let pad_zeros = (64 + 56 - (self.buffer_len + 1) % 64) % 64;
+        padding.resize(1 + pad_zeros, 0);
+        padding.extend_from_slice(&bit_len.to_be_bytes());
+        let total_len = self.total_len;
+        self.update(&padding);
+        self.total_len = total_len;
+
+        let mut digest = [0u8; 32];
+        for (chunk, word) in digest.chunks_exact_mut(4).zip(self.state.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+
+    fn compress(&mut self, block: &[u8]) {
+        let mut w = [0u32; 64];
+        for (i, chunk) in block.chunks_exact(4).enumerate() {
+            w[i] = u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
+        }
+        for i in 16..64 {
+            let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+            let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+            w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = self.state;
+        for (k, w) in SHA256_K.iter().zip(w.iter()) {
+            let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+            let ch = (e & f) ^ (!e & g);
+            let temp1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(*k).wrapping_add(*w);
+            let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let temp2 = s0.wrapping_add(maj);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+
+        for (state, value) in self.state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *state = state.wrapping_add(value);
+        }
+    }
+}
+
+const SHA512_K: [u64; 80] = [
+    0x428a2f98d728ae22, 0x7137449123ef65cd, 0xb5c0fbcfec4d3b2f, 0xe9b5dba58189dbbc, 0x3956c25bf348b538,
+    0x59f111f1b605d019, 0x923f82a4af194f9b, 0xab1c5ed5da6d8118, 0xd807aa98a3030242, 0x12835b0145706fbe,
+    0x243185be4ee4b28c, 0x550c7dc3d5ffb4e2, 0x72be5d74f27b896f, 0x80deb1fe3b1696b1, 0x9bdc06a725c71235,
+    0xc19bf174cf692694, 0xe49b69c19ef14ad2, 0xefbe4786384f25e3, 0x0fc19dc68b8cd5b5, 0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275, 0x4a7484aa6ea6e483, 0x5cb0a9dcbd41fbd4, 0x76f988da831153b5, 0x983e5152ee66dfab,
+    0xa831c66d2db43210, 0xb00327c898fb213f, 0xbf597fc7beef0ee4, 0xc6e00bf33da88fc2, 0xd5a79147930aa725,
+    0x06ca6351e003826f, 0x142929670a0e6e70, 0x27b70a8546d22ffc, 0x2e1b21385c26c926, 0x4d2c6dfc5ac42aed,
+    0x53380d139d95b3df, 0x650a73548baf63de, 0x766a0abb3c77b2a8, 0x81c2c92e47edaee6, 0x92722c851482353b,
+    0xa2bfe8a14cf10364, 0xa81a664bbc423001, 0xc24b8b70d0f89791, 0xc76c51a30654be30, 0xd192e819d6ef5218,
+    0xd69906245565a910, 0xf40e35855771202a, 0x106aa07032bbd1b8, 0x19a4c116b8d2d0c8, 0x1e376c085141ab53,
+    0x2748774cdf8eeb99, 0x34b0bcb5e19b48a8, 0x391c0cb3c5c95a63, 0x4ed8aa4ae3418acb, 0x5b9cca4f7763e373,
+    0x682e6ff3d6b2b8a3, 0x748f82ee5defb2fc, 0x78a5636f43172f60, 0x84c87814a1f0ab72, 0x8cc702081a6439ec,
+    0x90befffa23631e28, 0xa4506cebde82bde9, 0xbef9a3f7b2c67915, 0xc67178f2e372532b, 0xca273eceea26619c,
+    0xd186b8c721c0c207, 0xeada7dd6cde0eb1e, 0xf57d4f7fee6ed178, 0x06f067aa72176fba, 0x0a637dc5a2c898a6,
+    0x113f9804bef90dae, 0x1b710b35131c471b, 0x28db77f523047d84, 0x32caab7b40c72493, 0x3c9ebe0a15c9bebc,
+    0x431d67c49c100d4c, 0x4cc5d4becb3e42b6, 0x597f299cfc657e2a, 0x5fcb6fab3ad6faec, 0x6c44198c4a475817,
+];
+
+/// SHA-512
+#[derive(Clone)]
+pub struct Sha512 {
+    state: [u64; 8],
+    buffer: [u8; 128],
+    buffer_len: usize,
+    total_len: u128,
+}
+
+impl Default for Sha512 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sha512 {
+    pub fn new() -> Self {
+        Self {
+            state: [
+                0x6a09e667f3bcc908, 0xbb67ae8584caa73b, 0x3c6ef372fe94f82b, 0xa54ff53a5f1d36f1,
+                0x510e527fade682d1, 0x9b05688c2b3e6c1f, 0x1f83d9abfb41bd6b, 0x5be0cd19137e2179,
+            ],
+            buffer: [0; 128],
+            buffer_len: 0,
+            total_len: 0,
+        }
+    }
+
+    pub fn update(&mut self, mut data: &[u8]) {
+        self.total_len = self.total_len.wrapping_add(data.len() as u128);
+
+        if self.buffer_len > 0 {
+            let take = (128 - self.buffer_len).min(data.len());
+            self.buffer[self.buffer_len..self.buffer_len + take].copy_from_slice(&data[..take]);
+            self.buffer_len += take;
+            data = &data[take..];
+            if self.buffer_len < 128 {
+                return;
+            }
+            let block = self.buffer;
+            self.compress(&block);
+            self.buffer_len = 0;
+        }
+
+        let mut blocks = data.chunks_exact(128);
+        for block in &mut blocks {
+            self.compress(block);
+        }
+
+        let rest = blocks.remainder();
+        self.buffer[..rest.len()].copy_from_slice(rest);
+        self.buffer_len = rest.len();
+    }
+
+    pub fn finalize(mut self) -> [u8; 64] {
+        let bit_len = self.total_len.wrapping_mul(8);
+
+        let mut padding = vec![0x80u8];
+        let pad_zeros = (128 + 112 - (self.buffer_len + 1) % 128) % 128;
+        padding.resize(1 + pad_zeros, 0);
+        padding.extend_from_slice(&bit_len.to_be_bytes());
+        let total_len = self.total_len;
+        self.update(&padding);
+        self.total_len = total_len;
+
+        let mut digest = [0u8; 64];
+        for (chunk, word) in digest.chunks_exact_mut(8).zip(self.state.iter()) {
+            chunk.copy_from_slice(&word.to_be_bytes());
+        }
+        digest
+    }
+
+    fn compress(&mut self, block: &[u8]) {
+        let mut w = [0u64; 80];
+        for (i, chunk) in block.chunks_exact(8).enumerate() {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(chunk);
+            w[i] = u64::from_be_bytes(bytes);
+        }
+        for i in 16..80 {
+            let s0 = w[i - 15].rotate_right(1) ^ w[i - 15].rotate_right(8) ^ (w[i - 15] >> 7);
+            let s1 = w[i - 2].rotate_right(19) ^ w[i - 2].rotate_right(61) ^ (w[i - 2] >> 6);
+            w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+        }
+
+        let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = self.state;
+        for (k, w) in SHA512_K.iter().zip(w.iter()) {
+            let s1 = e.rotate_right(14) ^ e.rotate_right(18) ^ e.rotate_right(41);
+            let ch = (e & f) ^ (!e & g);
+            let temp1 = h.wrapping_add(s1).wrapping_add(ch).wrapping_add(*k).wrapping_add(*w);
+            let s0 = a.rotate_right(28) ^ a.rotate_right(34) ^ a.rotate_right(39);
+            let maj = (a & b) ^ (a & c) ^ (b & c);
+            let temp2 = s0.wrapping_add(maj);
+
+            h = g;
+            g = f;
+            f = e;
+            e = d.wrapping_add(temp1);
+            d = c;
+            c = b;
+            b = a;
+            a = temp1.wrapping_add(temp2);
+        }
+
+        for (state, value) in self.state.iter_mut().zip([a, b, c, d, e, f, g, h]) {
+            *state = state.wrapping_add(value);
+        }
+    }
+}
+
+#[cfg(feature = "crypto")]
+const BLAKE3_IV: [u32; 8] = [
+    0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+];
+
+#[cfg(feature = "crypto")]
+const BLAKE3_MSG_PERMUTATION: [usize; 16] = [2, 6, 3, 10, 7, 0, 4, 13, 1, 11, 12, 5, 9, 14, 15, 8];
+
+#[cfg(feature = "crypto")]
+const BLAKE3_CHUNK_LEN: usize = 1024;
+
+#[cfg(feature = "crypto")]
+const BLAKE3_CHUNK_START: u32 = 1 << 0;
+#[cfg(feature = "crypto")]
+const BLAKE3_CHUNK_END: u32 = 1 << 1;
+#[cfg(feature = "crypto")]
+const BLAKE3_PARENT: u32 = 1 << 2;
+#[cfg(feature = "crypto")]
+const BLAKE3_ROOT: u32 = 1 << 3;
+
+#[cfg(feature = "crypto")]
+fn blake3_g(state: &mut [u32; 16], a: usize, b: usize, c: usize, d: usize, mx: u32, my: u32) {
+    state[a] = state[a].wrapping_add(state[b]).wrapping_add(mx);
+    state[d] = (state[d] ^ state[a]).rotate_right(16);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_right(12);
+    state[a] = state[a].wrapping_add(state[b]).wrapping_add(my);
+    state[d] = (state[d] ^ state[a]).rotate_right(8);
+    state[c] = state[c].wrapping_add(state[d]);
+    state[b] = (state[b] ^ state[c]).rotate_right(7);
+}
+
+#[cfg(feature = "crypto")]
+fn blake3_compress(cv: &[u32; 8], block: &[u32; 16], counter: u64, block_len: u32, flags: u32) -> [u32; 16] {
+    let mut state = [
+        cv[0], cv[1], cv[2], cv[3], cv[4], cv[5], cv[6], cv[7],
+        BLAKE3_IV[0], BLAKE3_IV[1], BLAKE3_IV[2], BLAKE3_IV[3],
+        counter as u32, (counter >> 32) as u32, block_len, flags,
+    ];
+    let mut m = *block;
+
+    for round in 0..7 {
+        blake3_g(&mut state, 0, 4, 8, 12, m[0], m[1]);
+        blake3_g(&mut state, 1, 5, 9, 13, m[2], m[3]);
+        blake3_g(&mut state, 2, 6, 10, 14, m[4], m[5]);
+        blake3_g(&mut state, 3, 7, 11, 15, m[6], m[7]);
+        blake3_g(&mut state, 0, 5, 10, 15, m[8], m[9]);
+        blake3_g(&mut state, 1, 6, 11, 12, m[10], m[11]);
+        blake3_g(&mut state, 2, 7, 8, 13, m[12], m[13]);
+        blake3_g(&mut state, 3, 4, 9, 14, m[14], m[15]);
+        if round < 6 {
+            m = BLAKE3_MSG_PERMUTATION.map(|i| m[i]);
+        }
+    }
+
+    for i in 0..8 {
+        state[i] ^= state[i + 8];
+        state[i + 8] ^= cv[i];
+    }
+    state
+}
+
+#[cfg(feature = "crypto")]
+fn blake3_words(block: &[u8; 64]) -> [u32; 16] {
+    let mut words = [0u32; 16];
+    for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+    }
+    words
+}
+
+#[cfg(feature = "crypto")]
+fn blake3_first_8(words: [u32; 16]) -> [u32; 8] {
+    [words[0], words[1], words[2], words[3], words[4], words[5], words[6], words[7]]
+}
+
+/// 尚未压缩的最后一个块，可以作为子节点输出链值，也可以作为根节点输出摘要
+#[cfg(feature = "crypto")]
+struct Blake3Output {
+    input_cv: [u32; 8],
+    block: [u32; 16],
+    counter: u64,
+    block_len: u32,
+    flags: u32,
+}
+
+#[cfg(feature = "crypto")]
+impl Blake3Output {
+    fn parent(left: [u32; 8], right: [u32; 8]) -> Self {
+        let mut block = [0u32; 16];
+        block[..8].copy_from_slice(&left);
+        block[8..].copy_from_slice(&right);
+        Self { input_cv: BLAKE3_IV, block, counter: 0, block_len: 64, flags: BLAKE3_PARENT }
+    }
+
+    fn chaining_value(&self) -> [u32; 8] {
+        blake3_first_8(blake3_compress(&self.input_cv, &self.block, self.counter, self.block_len, self.flags))
+    }
+
+    fn root_hash(&self) -> [u8; 32] {
+        let words = blake3_compress(&self.input_cv, &self.block, 0, self.block_len, self.flags | BLAKE3_ROOT);
+        let mut digest = [0u8; 32];
+        for (chunk, word) in digest.chunks_exact_mut(4).zip(words.iter()) {
+            chunk.copy_from_slice(&word.to_le_bytes());
+        }
+        digest
+    }
+}
+
+/// 单个 1 KiB 分块的压缩状态
+#[cfg(feature = "crypto")]
+#[derive(Clone)]
+struct Blake3ChunkState {
+    cv: [u32; 8],
+    counter: u64,
+    block: [u8; 64],
+    block_len: usize,
+    blocks_compressed: usize,
+}
+
+#[cfg(feature = "crypto")]
+impl Blake3ChunkState {
+    fn new(counter: u64) -> Self {
+        Self { cv: BLAKE3_IV, counter, block: [0; 64], block_len: 0, blocks_compressed: 0 }
+    }
+
+    fn len(&self) -> usize {
+        64 * self.blocks_compressed + self.block_len
+    }
+
+    fn start_flag(&self) -> u32 {
+        if self.blocks_compressed == 0 { BLAKE3_CHUNK_START } else { 0 }
+    }
+
+    fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            // 块满且还有后续输入时才压缩，最后一个块要留给 output 加上 CHUNK_END 标志
+            if self.block_len == 64 {
+                let words = blake3_words(&self.block);
+                self.cv = blake3_first_8(blake3_compress(&self.cv, &words, self.counter, 64, self.start_flag()));
+                self.blocks_compressed += 1;
+                self.block = [0; 64];
+                self.block_len = 0;
+            }
+
+            let take = (64 - self.block_len).min(data.len());
+            self.block[self.block_len..self.block_len + take].copy_from_slice(&data[..take]);
+            self.block_len += take;
+            data = &data[take..];
+        }
+    }
+
+    fn output(&self) -> Blake3Output {
+        Blake3Output {
+            input_cv: self.cv,
+            block: blake3_words(&self.block),
+            counter: self.counter,
+            block_len: self.block_len as u32,
+            flags: self.start_flag() | BLAKE3_CHUNK_END,
+        }
+    }
+}
+
+/// BLAKE3（默认哈希模式，输出 32 字节）
+#[cfg(feature = "crypto")]
+#[derive(Clone)]
+pub struct Blake3 {
+    chunk: Blake3ChunkState,
+    // 已完成分块构成的子树链值，栈深度不超过 54
+    cv_stack: Vec<[u32; 8]>,
+}
+
+#[cfg(feature = "crypto")]
+impl Default for Blake3 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "crypto")]
+impl Blake3 {
+    pub fn new() -> Self {
+        Self { chunk: Blake3ChunkState::new(0), cv_stack: Vec::new() }
+    }
+
+    pub fn update(&mut self, mut data: &[u8]) {
+        while !data.is_empty() {
+            if self.chunk.len() == BLAKE3_CHUNK_LEN {
+                let chunk_cv = self.chunk.output().chaining_value();
+                let total_chunks = self.chunk.counter + 1;
+                self.push_chunk_cv(chunk_cv, total_chunks);
+                self.chunk = Blake3ChunkState::new(total_chunks);
+            }
+
+            let take = (BLAKE3_CHUNK_LEN - self.chunk.len()).min(data.len());
+            self.chunk.update(&data[..take]);
+            data = &data[take..];
+        }
+    }
+
+    /// 按完成的分块总数合并子树：总数末尾有几个 0，就合并几次
+    fn push_chunk_cv(&mut self, mut cv: [u32; 8], mut total_chunks: u64) {
+        while total_chunks & 1 == 0 {
+            let left = self.cv_stack.pop().expect("BLAKE3 chaining value stack underflow");
+            cv = Blake3Output::parent(left, cv).chaining_value();
+            total_chunks >>= 1;
+        }
+        self.cv_stack.push(cv);
+    }
+
+    pub fn finalize(self) -> [u8; 32] {
+        let mut output = self.chunk.output();
+        for left in self.cv_stack.iter().rev() {
+            output = Blake3Output::parent(*left, output.chaining_value());
+        }
+        output.root_hash()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex_digest(algo: HashAlgorithm, chunks: &[&[u8]]) -> String {
+        let mut hasher = Hasher::new(algo);
+        for chunk in chunks {
+            hasher.update(chunk);
+        }
+        hasher.finalize_hex()
+    }
+
+    #[test]
+    fn test_sha256_known_vectors() {
+        assert_eq!(
+            hex_digest(HashAlgorithm::Sha256, &[b""]),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            hex_digest(HashAlgorithm::Sha256, &[b"abc"]),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        // 分块输入应与一次性输入结果一致
+        let data = [0x5au8; 1000];
+        assert_eq!(
+            hex_digest(HashAlgorithm::Sha256, &[&data[..1], &data[1..100], &data[100..]]),
+            hex_digest(HashAlgorithm::Sha256, &[&data])
+        );
+    }
+
+    #[test]
+    fn test_sha512_known_vectors() {
+        assert_eq!(
+            hex_digest(HashAlgorithm::Sha512, &[b"abc"]),
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        );
+        let data = [0xa5u8; 1000];
+        assert_eq!(
+            hex_digest(HashAlgorithm::Sha512, &[&data[..127], &data[127..]]),
+            hex_digest(HashAlgorithm::Sha512, &[&data])
+        );
+    }
+
+    #[cfg(feature = "crypto")]
+    #[test]
+    fn test_blake3_known_vectors() {
+        assert_eq!(
+            hex_digest(HashAlgorithm::Blake3, &[b""]),
+            "af1349b9f5f9a1a6a0404dea36dcc9499bcb25c9adc112b7cc9a93cae41f3262"
+        );
+        assert_eq!(
+            hex_digest(HashAlgorithm::Blake3, &[b"abc"]),
+            "6437b3ac38465133ffb63b75273a8db548c558465d79db03fd359c6cd5bd9d85"
+        );
+        // 官方测试向量的输入为 i % 251，1025 字节跨越两个分块
+        let data: Vec<u8> = (0..1025).map(|i| (i % 251) as u8).collect();
+        assert!(hex_digest(HashAlgorithm::Blake3, &[&data]).starts_with("d00278ae47eb27b34faecf67b4fe263f82d5412916c1ffd97c8cb7fb814b8444"));
+        assert_eq!(
+            hex_digest(HashAlgorithm::Blake3, &[&data[..1], &data[1..1024], &data[1024..]]),
+            hex_digest(HashAlgorithm::Blake3, &[&data])
+        );
+    }
+}
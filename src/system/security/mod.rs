@@ -1,10 +1,86 @@
 // 安全工具模块
 use crate::system::common::*;
+use std::io::Read;
+
+// 哈希算法
+mod hash;
+pub use hash::*;
+
+// 密钥材料与随机数
+mod secure;
+pub use secure::*;
 
 pub struct SecurityTools;
 
 impl SecurityTools {
-    pub fn encrypt_data(&self, _data: &[u8], _key: &[u8]) -> SystemResult<Vec<u8>> {
+    /// 加密数据，密钥可以是 `&[u8]` 或 `&SecureBytes`
+    pub fn encrypt_data<K: AsRef<[u8]> + ?Sized>(&self, _data: &[u8], _key: &K) -> SystemResult<Vec<u8>> {
+        Err(SystemError::NotSupported("Not implemented yet".to_string()))
+    }
+
+    /// 解密数据，密钥可以是 `&[u8]` 或 `&SecureBytes`
+    pub fn decrypt_data<K: AsRef<[u8]> + ?Sized>(&self, _data: &[u8], _key: &K) -> SystemResult<Vec<u8>> {
         Err(SystemError::NotSupported("Not implemented yet".to_string()))
     }
-} 
\ No newline at end of file
+
+    /// 计算字节数据的哈希值（小写十六进制）
+    pub fn hash_bytes(&self, data: &[u8], algo: HashAlgorithm) -> String {
+        let mut hasher = Hasher::new(algo);
+        hasher.update(data);
+        hasher.finalize_hex()
+    }
+
+    /// 计算文件的哈希值（小写十六进制）
+    /// 分块流式读取，不会把整个文件读入内存；文件不存在时返回 NotFound
+    pub fn hash_file(&self, path: &str, algo: HashAlgorithm) -> SystemResult<String> {
+        const HASH_BUFFER_SIZE: usize = 64 * 1024;
+
+        let mut file = std::fs::File::open(path).map_err(|e| match e.kind() {
+            std::io::ErrorKind::NotFound => SystemError::NotFound(format!("File not found: {}", path)),
+            _ => e.into(),
+        })?;
+
+        let mut hasher = Hasher::new(algo);
+        let mut buffer = vec![0u8; HASH_BUFFER_SIZE];
+        loop {
+            let read = match file.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e.into()),
+            };
+            hasher.update(&buffer[..read]);
+        }
+        Ok(hasher.finalize_hex())
+    }
+
+    /// 生成 `n` 字节的密码学安全随机数，可用作密钥或 nonce
+    pub fn random_bytes(n: usize) -> SystemResult<SecureBytes> {
+        let mut bytes = SecureBytes::zeroed(n);
+        fill_random(bytes.as_mut_bytes())?;
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_file_matches_hash_bytes() {
+        let tools = SecurityTools;
+        let path = std::env::temp_dir().join(format!("lycrex_hash_file_{}", std::process::id()));
+        // 大于读取缓冲区，覆盖多次读取的情况
+        let data: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        std::fs::write(&path, &data).unwrap();
+
+        let result = tools.hash_file(path.to_str().unwrap(), HashAlgorithm::Sha256);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(result.unwrap(), tools.hash_bytes(&data, HashAlgorithm::Sha256));
+
+        assert!(matches!(
+            tools.hash_file("/nonexistent/lycrex/file", HashAlgorithm::Sha512),
+            Err(SystemError::NotFound(_))
+        ));
+    }
+}
@@ -0,0 +1,160 @@
+// 密钥材料的安全存放
+// SecureBytes 在释放时用 volatile 写清零内存，避免密钥残留在已释放的堆内存中
+// 随机数直接来自操作系统的 CSPRNG，不依赖额外的库
+
+use crate::system::common::*;
+use std::fmt;
+use std::sync::atomic::{compiler_fence, Ordering};
+
+/// 释放时自动清零的字节缓冲区，用于保存密钥、随机数等敏感数据
+/// 不提供会导致重新分配的方法，避免旧缓冲区中的数据被遗留
+#[derive(Clone, Default)]
+pub struct SecureBytes(Vec<u8>);
+
+impl SecureBytes {
+    /// 接管已有的缓冲区
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self(bytes)
+    }
+
+    /// 复制一份切片
+    pub fn from_slice(bytes: &[u8]) -> Self {
+        Self(bytes.to_vec())
+    }
+
+    /// 创建长度为 `len` 的全零缓冲区
+    pub fn zeroed(len: usize) -> Self {
+        Self(vec![0u8; len])
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn as_mut_bytes(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+
+    /// 立即清零（长度保持不变）
+    pub fn zeroize(&mut self) {
+        zeroize_slice(&mut self.0);
+    }
+}
+
+impl Drop for SecureBytes {
+    fn drop(&mut self) {
+        // 连同未使用的容量一起清零
+        let capacity = self.0.capacity();
+        let ptr = self.0.as_mut_ptr();
+        for i in 0..capacity {
+            unsafe { std::ptr::write_volatile(ptr.add(i), 0) };
+        }
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+impl AsRef<[u8]> for SecureBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl From<Vec<u8>> for SecureBytes {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl fmt::Debug for SecureBytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecureBytes([REDACTED; {}])", self.0.len())
+    }
+}
+
+/// 用 volatile 写清零切片，防止被编译器当作无用写入优化掉
+pub fn zeroize_slice(bytes: &mut [u8]) {
+    for byte in bytes.iter_mut() {
+        unsafe { std::ptr::write_volatile(byte, 0) };
+    }
+    compiler_fence(Ordering::SeqCst);
+}
+
+/// 用操作系统的 CSPRNG 填充缓冲区
+pub fn fill_random(buffer: &mut [u8]) -> SystemResult<()> {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        let mut filled = 0;
+        while filled < buffer.len() {
+            let remaining = &mut buffer[filled..];
+            let ret = unsafe { libc::getrandom(remaining.as_mut_ptr().cast(), remaining.len(), 0) };
+            if ret < 0 {
+                let error = std::io::Error::last_os_error();
+                if error.kind() == std::io::ErrorKind::Interrupted {
+                    continue;
+                }
+                return Err(error.into());
+            }
+            filled += ret as usize;
+        }
+        Ok(())
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        // getentropy 单次最多 256 字节
+        for chunk in buffer.chunks_mut(256) {
+            if unsafe { libc::getentropy(chunk.as_mut_ptr().cast(), chunk.len()) } != 0 {
+                return Err(std::io::Error::last_os_error().into());
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        use windows::Win32::Security::Cryptography::{BCryptGenRandom, BCRYPT_USE_SYSTEM_PREFERRED_RNG};
+        unsafe { BCryptGenRandom(None, buffer, BCRYPT_USE_SYSTEM_PREFERRED_RNG) }
+            .ok()
+            .map_err(|e| SystemError::Internal(format!("BCryptGenRandom failed: {e}")))
+    }
+
+    #[cfg(all(unix, not(any(target_os = "linux", target_os = "android", target_os = "macos"))))]
+    {
+        use std::io::Read;
+        std::fs::File::open("/dev/urandom")?.read_exact(buffer)?;
+        Ok(())
+    }
+
+    #[cfg(not(any(unix, target_os = "windows")))]
+    {
+        let _ = buffer;
+        Err(SystemError::NotSupported("Platform not supported".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::system::security::SecurityTools;
+
+    #[test]
+    fn test_random_secure_bytes() {
+        let first = SecurityTools::random_bytes(32).unwrap();
+        let second = SecurityTools::random_bytes(32).unwrap();
+        assert_eq!(first.len(), 32);
+        assert_ne!(first.as_bytes(), second.as_bytes());
+        assert_eq!(format!("{first:?}"), "SecureBytes([REDACTED; 32])");
+
+        let mut key = SecureBytes::from_slice(b"secret");
+        key.zeroize();
+        assert_eq!(key.as_bytes(), [0u8; 6]);
+    }
+}
@@ -0,0 +1,194 @@
+// Linux 服务控制
+// 通过 systemctl 管理 systemd 服务单元
+
+use std::collections::HashMap;
+use std::process::Output;
+
+use super::ServiceInfo;
+use crate::system::common::linux::systemctl;
+use crate::system::common::*;
+
+/// 列出所有服务单元（包括未运行的）
+pub fn list_services() -> SystemResult<Vec<ServiceInfo>> {
+    let units = systemctl(&["list-units", "--type=service", "--all", "--no-legend", "--plain"], "list-units")?;
+    // 启动类型通过一次 list-unit-files 批量获取，取值与 is-enabled 相同
+    let files = systemctl(&["list-unit-files", "--type=service", "--no-legend", "--plain"], "list-unit-files")?;
+
+    let states = parse_unit_files(&stdout(&files));
+    Ok(parse_units(&stdout(&units))
+        .into_iter()
+        .map(|(unit, active, description)| {
+            let start_type = states.get(&unit).map_or(ServiceStartType::Manual, |state| map_start_type(state));
+            service_info(&unit, &active, start_type, &description)
+        })
+        .collect())
+}
+
+/// 查询单个服务的状态
+pub fn service_status(service_name: &str) -> SystemResult<ServiceInfo> {
+    let unit = unit_name(service_name)?;
+    let output = systemctl(
+        &["show", "--property=Id,LoadState,ActiveState,UnitFileState,Description", &unit],
+        &unit,
+    )?;
+
+    let text = stdout(&output);
+    let property = |key: &str| {
+        text.lines()
+            .filter_map(|line| line.split_once('='))
+            .find(|(name, _)| *name == key)
+            .map(|(_, value)| value.trim().to_string())
+            .unwrap_or_default()
+    };
+
+    // systemctl show 对不存在的单元同样返回成功，只能通过 LoadState 判断
+    if property("LoadState") == "not-found" {
+        return Err(SystemError::NotFound(format!("Service not found: {}", service_name)));
+    }
+
+    let id = property("Id");
+    Ok(service_info(
+        if id.is_empty() { &unit } else { &id },
+        &property("ActiveState"),
+        map_start_type(&property("UnitFileState")),
+        &property("Description"),
+    ))
+}
+
+/// 启动服务
+pub fn start_service(service_name: &str) -> SystemResult<()> {
+    control("start", service_name)
+}
+
+/// 停止服务
+pub fn stop_service(service_name: &str) -> SystemResult<()> {
+    control("stop", service_name)
+}
+
+/// 重启服务，未运行时会直接启动
+pub fn restart_service(service_name: &str) -> SystemResult<()> {
+    control("restart", service_name)
+}
+
+fn control(action: &str, service_name: &str) -> SystemResult<()> {
+    let unit = unit_name(service_name)?;
+    // 非交互模式：没有权限时直接失败，而不是等待 polkit 认证
+    systemctl(&[action, "--no-ask-password", &unit], &unit).map(|_| ())
+}
+
+/// 补全 `.service` 后缀，并拒绝可能被 systemctl 当作选项的名称
+fn unit_name(service_name: &str) -> SystemResult<String> {
+    if service_name.is_empty() || service_name.starts_with('-') || service_name.chars().any(char::is_whitespace) {
+        return Err(SystemError::InvalidArgument(format!("Invalid service name: {:?}", service_name)));
+    }
+    if service_name.contains('.') {
+        Ok(service_name.to_string())
+    } else {
+        Ok(format!("{}.service", service_name))
+    }
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+/// 解析 list-units 输出：`UNIT LOAD ACTIVE SUB DESCRIPTION...`
+/// 返回 (单元名, ACTIVE 列, 描述)
+fn parse_units(output: &str) -> Vec<(String, String, String)> {
+    output
+        .lines()
+        .filter_map(|line| {
+            // 较旧的 systemd 即使指定 --plain 也可能在失败的单元前输出 `●`
+            let line = line.trim_start().trim_start_matches(['●', '*']).trim_start();
+            let mut fields = line.split_whitespace();
+            let unit = fields.next()?;
+            let _load = fields.next()?;
+            let active = fields.next()?;
+            let _sub = fields.next();
+            let description = fields.collect::<Vec<_>>().join(" ");
+            Some((unit.to_string(), active.to_string(), description))
+        })
+        .collect()
+}
+
+/// 解析 list-unit-files 输出：`UNIT STATE [PRESET]`
+fn parse_unit_files(output: &str) -> HashMap<String, String> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            Some((fields.next()?.to_string(), fields.next()?.to_string()))
+        })
+        .collect()
+}
+
+fn service_info(unit: &str, active: &str, start_type: ServiceStartType, description: &str) -> ServiceInfo {
+    ServiceInfo {
+        name: unit.strip_suffix(".service").unwrap_or(unit).to_string(),
+        display_name: unit.to_string(),
+        status: map_status(active),
+        start_type,
+        description: if description.is_empty() { None } else { Some(description.to_string()) },
+    }
+}
+
+fn map_status(active: &str) -> ServiceStatus {
+    match active {
+        "active" | "reloading" => ServiceStatus::Running,
+        "inactive" | "failed" => ServiceStatus::Stopped,
+        "activating" => ServiceStatus::Starting,
+        "deactivating" => ServiceStatus::Stopping,
+        _ => ServiceStatus::Unknown,
+    }
+}
+
+/// 映射 is-enabled 的状态；static、indirect 等只能由其他单元拉起，视为手动启动
+fn map_start_type(state: &str) -> ServiceStartType {
+    match state {
+        "enabled" | "enabled-runtime" | "alias" => ServiceStartType::Automatic,
+        "disabled" | "masked" | "masked-runtime" => ServiceStartType::Disabled,
+        _ => ServiceStartType::Manual,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_systemctl_output() {
+        let units = "cron.service loaded active running Regular background program processing daemon\n\
+            ssh.service loaded inactive dead OpenBSD Secure Shell server\n\
+            ● nginx.service loaded failed failed A high performance web server\n";
+        let files = "cron.service enabled enabled\nssh.service disabled enabled\n";
+
+        let units = parse_units(units);
+        assert_eq!(units.len(), 3);
+        assert_eq!(units[0].0, "cron.service");
+        assert_eq!(units[0].2, "Regular background program processing daemon");
+        assert_eq!(units[2].0, "nginx.service");
+        assert_eq!(map_status(&units[1].1), ServiceStatus::Stopped);
+        assert_eq!(map_status(&units[2].1), ServiceStatus::Stopped);
+
+        let files = parse_unit_files(files);
+        assert_eq!(map_start_type(&files["cron.service"]), ServiceStartType::Automatic);
+        assert_eq!(map_start_type(&files["ssh.service"]), ServiceStartType::Disabled);
+        assert_eq!(map_start_type("static"), ServiceStartType::Manual);
+
+        let info = service_info("cron.service", "active", ServiceStartType::Automatic, "");
+        assert_eq!(info.name, "cron");
+        assert_eq!(info.status, ServiceStatus::Running);
+        assert_eq!(info.description, None);
+
+        assert!(matches!(
+            crate::system::common::linux::systemctl_error("foo.service", "Failed to start foo.service: Unit foo.service not found."),
+            SystemError::NotFound(_)
+        ));
+        assert!(matches!(
+            crate::system::common::linux::systemctl_error("cron.service", "Failed to stop cron.service: Interactive authentication required."),
+            SystemError::PermissionDenied(_)
+        ));
+        assert!(matches!(unit_name("--force"), Err(SystemError::InvalidArgument(_))));
+        assert_eq!(unit_name("cron").unwrap(), "cron.service");
+    }
+}
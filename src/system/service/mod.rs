@@ -2,23 +2,82 @@
 // 管理系统服务，启动/停止/重启服务等
 use crate::system::common::*;
 
+#[cfg(target_os = "windows")]
+mod windows;
+#[cfg(target_os = "linux")]
+mod linux;
+
 /// 服务管理器
 pub struct ServiceManager;
 
 impl ServiceManager {
     /// 获取所有服务列表
     pub fn list_services(&self) -> SystemResult<Vec<ServiceInfo>> {
-        Ok(Vec::new())
+        #[cfg(target_os = "windows")]
+        return windows::list_services();
+
+        #[cfg(target_os = "linux")]
+        return linux::list_services();
+
+        #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+        Err(SystemError::NotSupported("Not implemented yet".to_string()))
     }
     
     /// 启动服务
-    pub fn start_service(&self, _service_name: &str) -> SystemResult<()> {
-        Err(SystemError::NotSupported("Not implemented yet".to_string()))
+    pub fn start_service(&self, service_name: &str) -> SystemResult<()> {
+        #[cfg(target_os = "windows")]
+        return windows::start_service(service_name);
+
+        #[cfg(target_os = "linux")]
+        return linux::start_service(service_name);
+
+        #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+        {
+            let _ = service_name;
+            Err(SystemError::NotSupported("Not implemented yet".to_string()))
+        }
     }
     
     /// 停止服务
-    pub fn stop_service(&self, _service_name: &str) -> SystemResult<()> {
-        Err(SystemError::NotSupported("Not implemented yet".to_string()))
+    pub fn stop_service(&self, service_name: &str) -> SystemResult<()> {
+        #[cfg(target_os = "windows")]
+        return windows::stop_service(service_name);
+
+        #[cfg(target_os = "linux")]
+        return linux::stop_service(service_name);
+
+        #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+        {
+            let _ = service_name;
+            Err(SystemError::NotSupported("Not implemented yet".to_string()))
+        }
+    }
+
+    /// 重启服务
+    pub fn restart_service(&self, service_name: &str) -> SystemResult<()> {
+        #[cfg(target_os = "linux")]
+        return linux::restart_service(service_name);
+
+        #[cfg(not(target_os = "linux"))]
+        {
+            let _ = service_name;
+            Err(SystemError::NotSupported("Not implemented yet".to_string()))
+        }
+    }
+
+    /// 查询单个服务的状态
+    pub fn service_status(&self, service_name: &str) -> SystemResult<ServiceInfo> {
+        #[cfg(target_os = "windows")]
+        return windows::service_status(service_name);
+
+        #[cfg(target_os = "linux")]
+        return linux::service_status(service_name);
+
+        #[cfg(not(any(target_os = "windows", target_os = "linux")))]
+        {
+            let _ = service_name;
+            Err(SystemError::NotSupported("Not implemented yet".to_string()))
+        }
     }
 }
 
@@ -0,0 +1,228 @@
+// Windows 服务控制
+// 通过服务控制管理器（SCM）枚举、启动和停止服务
+
+use windows::core::{HRESULT, PCWSTR};
+use windows::Win32::Foundation::{
+    ERROR_ACCESS_DENIED, ERROR_INSUFFICIENT_BUFFER, ERROR_MORE_DATA, ERROR_SERVICE_ALREADY_RUNNING,
+    ERROR_SERVICE_DOES_NOT_EXIST, ERROR_SERVICE_NOT_ACTIVE,
+};
+use windows::Win32::System::Services::{
+    CloseServiceHandle, ControlService, EnumServicesStatusExW, OpenSCManagerW, OpenServiceW, QueryServiceConfig2W,
+    QueryServiceConfigW, StartServiceW, ENUM_SERVICE_STATUS_PROCESSW, QUERY_SERVICE_CONFIGW, SC_ENUM_PROCESS_INFO,
+    SC_HANDLE, SC_MANAGER_CONNECT, SC_MANAGER_ENUMERATE_SERVICE, SERVICE_AUTO_START, SERVICE_BOOT_START, SERVICE_CONFIG,
+    SERVICE_CONFIG_DELAYED_AUTO_START_INFO, SERVICE_CONFIG_DESCRIPTION, SERVICE_CONTINUE_PENDING,
+    SERVICE_CONTROL_STOP, SERVICE_DELAYED_AUTO_START_INFO, SERVICE_DESCRIPTIONW,
+    SERVICE_DISABLED, SERVICE_PAUSED, SERVICE_PAUSE_PENDING, SERVICE_QUERY_CONFIG, SERVICE_RUNNING, SERVICE_START,
+    SERVICE_START_PENDING, SERVICE_STATE_ALL, SERVICE_STATUS, SERVICE_STATUS_CURRENT_STATE, SERVICE_STOP,
+    SERVICE_STOPPED, SERVICE_STOP_PENDING, SERVICE_SYSTEM_START, SERVICE_WIN32,
+};
+
+use super::ServiceInfo;
+use crate::system::common::*;
+
+/// SCM 句柄，释放时关闭
+struct ScHandle(SC_HANDLE);
+
+impl Drop for ScHandle {
+    fn drop(&mut self) {
+        unsafe { CloseServiceHandle(self.0) }.ok();
+    }
+}
+
+/// 枚举所有 Win32 服务
+/// 单个服务的配置或描述无法读取时使用默认值，不影响整体结果
+pub fn list_services() -> SystemResult<Vec<ServiceInfo>> {
+    let manager = open_manager(SC_MANAGER_ENUMERATE_SERVICE)?;
+
+    // 以 u64 为单位分配，保证 ENUM_SERVICE_STATUS_PROCESSW 的对齐
+    let mut buffer: Vec<u64> = Vec::new();
+    let mut resume_handle = 0u32;
+    let mut services = Vec::new();
+
+    loop {
+        let mut bytes_needed = 0u32;
+        let mut returned = 0u32;
+        let result = unsafe {
+            EnumServicesStatusExW(
+                manager.0,
+                SC_ENUM_PROCESS_INFO,
+                SERVICE_WIN32,
+                SERVICE_STATE_ALL,
+                if buffer.is_empty() {
+                    None
+                } else {
+                    Some(std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, buffer.len() * 8))
+                },
+                &mut bytes_needed,
+                &mut returned,
+                Some(&mut resume_handle),
+                PCWSTR::null(),
+            )
+        };
+
+        let more_data = match result {
+            Ok(()) => false,
+            Err(e) if e.code() == HRESULT::from_win32(ERROR_MORE_DATA.0) => true,
+            Err(e) => return Err(service_error(e, "EnumServicesStatusExW")),
+        };
+
+        let entries = unsafe {
+            std::slice::from_raw_parts(buffer.as_ptr() as *const ENUM_SERVICE_STATUS_PROCESSW, returned as usize)
+        };
+        for entry in entries {
+            let name = unsafe { entry.lpServiceName.to_string() }.unwrap_or_default();
+            let display_name = unsafe { entry.lpDisplayName.to_string() }.unwrap_or_default();
+            let (start_type, description) = query_config(&manager, &name);
+            services.push(ServiceInfo {
+                name,
+                display_name,
+                status: map_status(entry.ServiceStatusProcess.dwCurrentState),
+                start_type,
+                description,
+            });
+        }
+
+        if !more_data {
+            break;
+        }
+        // 缓冲区不足：按需要的大小扩容后从 resume_handle 继续枚举
+        buffer.resize(buffer.len() + (bytes_needed as usize).div_ceil(8), 0);
+    }
+
+    Ok(services)
+}
+
+/// 查询单个服务，服务名不区分大小写
+pub fn service_status(service_name: &str) -> SystemResult<ServiceInfo> {
+    list_services()?
+        .into_iter()
+        .find(|service| service.name.eq_ignore_ascii_case(service_name))
+        .ok_or_else(|| SystemError::NotFound(format!("Service not found: {}", service_name)))
+}
+
+/// 启动服务，已在运行时视为成功
+pub fn start_service(service_name: &str) -> SystemResult<()> {
+    let manager = open_manager(SC_MANAGER_CONNECT)?;
+    let service = open_service(&manager, service_name, SERVICE_START)?;
+
+    match unsafe { StartServiceW(service.0, None) } {
+        Ok(()) => Ok(()),
+        Err(e) if e.code() == HRESULT::from_win32(ERROR_SERVICE_ALREADY_RUNNING.0) => Ok(()),
+        Err(e) => Err(service_error(e, service_name)),
+    }
+}
+
+/// 发送停止控制码，服务未运行时视为成功
+pub fn stop_service(service_name: &str) -> SystemResult<()> {
+    let manager = open_manager(SC_MANAGER_CONNECT)?;
+    let service = open_service(&manager, service_name, SERVICE_STOP)?;
+
+    let mut status = SERVICE_STATUS::default();
+    match unsafe { ControlService(service.0, SERVICE_CONTROL_STOP, &mut status) } {
+        Ok(()) => Ok(()),
+        Err(e) if e.code() == HRESULT::from_win32(ERROR_SERVICE_NOT_ACTIVE.0) => Ok(()),
+        Err(e) => Err(service_error(e, service_name)),
+    }
+}
+
+fn open_manager(access: u32) -> SystemResult<ScHandle> {
+    unsafe { OpenSCManagerW(PCWSTR::null(), PCWSTR::null(), access) }
+        .map(ScHandle)
+        .map_err(|e| service_error(e, "service control manager"))
+}
+
+fn open_service(manager: &ScHandle, service_name: &str, access: u32) -> SystemResult<ScHandle> {
+    let name: Vec<u16> = service_name.encode_utf16().chain(std::iter::once(0)).collect();
+    unsafe { OpenServiceW(manager.0, PCWSTR::from_raw(name.as_ptr()), access) }
+        .map(ScHandle)
+        .map_err(|e| service_error(e, service_name))
+}
+
+/// 读取启动类型和描述
+fn query_config(manager: &ScHandle, service_name: &str) -> (ServiceStartType, Option<String>) {
+    let Ok(service) = open_service(manager, service_name, SERVICE_QUERY_CONFIG) else {
+        return (ServiceStartType::Manual, None);
+    };
+
+    let mut start_type = ServiceStartType::Manual;
+    if let Some(buffer) = query_with_buffer(|buffer, size, needed| unsafe {
+        QueryServiceConfigW(service.0, buffer.map(|b| b.as_mut_ptr() as *mut QUERY_SERVICE_CONFIGW), size, needed)
+    }) {
+        let config = unsafe { &*(buffer.as_ptr() as *const QUERY_SERVICE_CONFIGW) };
+        start_type = match config.dwStartType {
+            SERVICE_AUTO_START | SERVICE_BOOT_START | SERVICE_SYSTEM_START => ServiceStartType::Automatic,
+            SERVICE_DISABLED => ServiceStartType::Disabled,
+            _ => ServiceStartType::Manual,
+        };
+    }
+
+    if start_type == ServiceStartType::Automatic {
+        if let Some(buffer) = query_config2(&service, SERVICE_CONFIG_DELAYED_AUTO_START_INFO) {
+            let info = unsafe { &*(buffer.as_ptr() as *const SERVICE_DELAYED_AUTO_START_INFO) };
+            if info.fDelayedAutostart.as_bool() {
+                start_type = ServiceStartType::AutomaticDelayed;
+            }
+        }
+    }
+
+    let description = query_config2(&service, SERVICE_CONFIG_DESCRIPTION).and_then(|buffer| {
+        let info = unsafe { &*(buffer.as_ptr() as *const SERVICE_DESCRIPTIONW) };
+        if info.lpDescription.is_null() {
+            return None;
+        }
+        unsafe { info.lpDescription.to_string() }.ok().filter(|text| !text.is_empty())
+    });
+
+    (start_type, description)
+}
+
+fn query_config2(service: &ScHandle, level: SERVICE_CONFIG) -> Option<Vec<u64>> {
+    query_with_buffer(|buffer, _size, needed| unsafe {
+        QueryServiceConfig2W(
+            service.0,
+            level,
+            buffer.map(|b| std::slice::from_raw_parts_mut(b.as_mut_ptr() as *mut u8, b.len() * 8)),
+            needed,
+        )
+    })
+}
+
+/// 先查询所需大小再分配缓冲区的两段式调用
+/// 缓冲区以 u64 为单位分配，保证结构体按 8 字节对齐
+fn query_with_buffer(
+    mut query: impl FnMut(Option<&mut [u64]>, u32, &mut u32) -> windows::core::Result<()>,
+) -> Option<Vec<u64>> {
+    let mut needed = 0u32;
+    match query(None, 0, &mut needed) {
+        Ok(()) => return None,
+        Err(e) if e.code() == HRESULT::from_win32(ERROR_INSUFFICIENT_BUFFER.0) => {}
+        Err(_) => return None,
+    }
+
+    let mut buffer = vec![0u64; (needed as usize).div_ceil(8)];
+    let size = (buffer.len() * 8) as u32;
+    query(Some(&mut buffer), size, &mut needed).ok()?;
+    Some(buffer)
+}
+
+fn map_status(state: SERVICE_STATUS_CURRENT_STATE) -> ServiceStatus {
+    match state {
+        SERVICE_RUNNING => ServiceStatus::Running,
+        SERVICE_STOPPED => ServiceStatus::Stopped,
+        SERVICE_PAUSED => ServiceStatus::Paused,
+        SERVICE_START_PENDING | SERVICE_CONTINUE_PENDING => ServiceStatus::Starting,
+        SERVICE_STOP_PENDING | SERVICE_PAUSE_PENDING => ServiceStatus::Stopping,
+        _ => ServiceStatus::Unknown,
+    }
+}
+
+fn service_error(error: windows::core::Error, context: &str) -> SystemError {
+    let code = error.code();
+    if code == HRESULT::from_win32(ERROR_SERVICE_DOES_NOT_EXIST.0) {
+        SystemError::NotFound(format!("Service not found: {}", context))
+    } else if code == HRESULT::from_win32(ERROR_ACCESS_DENIED.0) {
+        SystemError::PermissionDenied(format!("Access denied: {}", context))
+    } else {
+        SystemError::ProcessError(format!("{}: {}", context, error))
+    }
+}
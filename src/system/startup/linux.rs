@@ -1,9 +1,28 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::system::common::linux::systemctl;
 use crate::system::common::*;
 use crate::system::startup::types::*;
 
 /// 获取所有启动项
+/// 目前只列出桌面自启动项（~/.config/autostart），systemd 单元可以通过 ServiceManager 查询
 pub fn list_all_startup_entries() -> SystemResult<Vec<StartupEntry>> {
-    Ok(vec![])
+    let dir = autostart_dir()?;
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries = Vec::new();
+    for dir_entry in fs::read_dir(&dir)? {
+        let path = dir_entry?.path();
+        if path.extension().is_some_and(|ext| ext == "desktop") {
+            if let Ok(entry) = parse_desktop_file(&path) {
+                entries.push(entry);
+            }
+        }
+    }
+    Ok(entries)
 }
 
 /// 添加启动项
@@ -14,4 +33,168 @@ pub fn add_startup_entry(_entry: &StartupEntry) -> SystemResult<()> {
 /// 移除启动项
 pub fn remove_startup_entry(_id: &str, _startup_type: StartupType) -> SystemResult<()> {
     Err(SystemError::NotSupported("Linux startup management not yet implemented".to_string()))
-} 
\ No newline at end of file
+}
+
+/// 启用或禁用启动项，保留原有配置
+pub fn set_startup_entry_enabled(id: &str, startup_type: StartupType, enabled: bool) -> SystemResult<()> {
+    let action = if enabled { "enable" } else { "disable" };
+    match startup_type {
+        StartupType::SystemdUser => {
+            let unit = unit_name(id)?;
+            systemctl(&["--user", action, &unit], &unit).map(|_| ())
+        }
+        StartupType::SystemdSystem => {
+            let unit = unit_name(id)?;
+            systemctl(&[action, "--no-ask-password", &unit], &unit).map(|_| ())
+        }
+        StartupType::DesktopAutostart => {
+            let path = desktop_file_path(id)?;
+            let content = fs::read_to_string(&path).map_err(|e| match e.kind() {
+                std::io::ErrorKind::NotFound => SystemError::NotFound(format!("Startup entry not found: {}", id)),
+                _ => e.into(),
+            })?;
+            fs::write(&path, set_desktop_enabled(&content, enabled))?;
+            Ok(())
+        }
+        _ => Err(SystemError::NotSupported(format!("Unsupported startup type: {:?}", startup_type))),
+    }
+}
+
+/// 桌面自启动目录，遵循 XDG_CONFIG_HOME
+fn autostart_dir() -> SystemResult<PathBuf> {
+    if let Some(config) = std::env::var_os("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()) {
+        return Ok(PathBuf::from(config).join("autostart"));
+    }
+    let home = std::env::var("HOME")
+        .map_err(|_| SystemError::Configuration("Cannot get user home directory".to_string()))?;
+    Ok(Path::new(&home).join(".config/autostart"))
+}
+
+fn desktop_file_path(id: &str) -> SystemResult<PathBuf> {
+    if id.is_empty() || id.contains('/') || id.starts_with('.') {
+        return Err(SystemError::InvalidArgument(format!("Invalid startup entry id: {:?}", id)));
+    }
+    let file_name = if id.ends_with(".desktop") { id.to_string() } else { format!("{}.desktop", id) };
+    Ok(autostart_dir()?.join(file_name))
+}
+
+/// 补全 `.service` 后缀，并拒绝可能被 systemctl 当作选项的名称
+fn unit_name(id: &str) -> SystemResult<String> {
+    if id.is_empty() || id.starts_with('-') || id.chars().any(char::is_whitespace) {
+        return Err(SystemError::InvalidArgument(format!("Invalid unit name: {:?}", id)));
+    }
+    Ok(if id.contains('.') { id.to_string() } else { format!("{}.service", id) })
+}
+
+/// 解析 .desktop 文件中 [Desktop Entry] 段的键值
+fn desktop_entry_values(content: &str) -> Vec<(&str, &str)> {
+    let mut in_entry = false;
+    let mut values = Vec::new();
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_entry = line == "[Desktop Entry]";
+        } else if in_entry {
+            if let Some((key, value)) = line.split_once('=') {
+                values.push((key.trim(), value.trim()));
+            }
+        }
+    }
+    values
+}
+
+/// Hidden=true 或 X-GNOME-Autostart-enabled=false 都表示已禁用
+fn desktop_enabled(values: &[(&str, &str)]) -> bool {
+    let value = |name: &str| values.iter().find(|(key, _)| *key == name).map(|(_, value)| *value);
+    value("Hidden") != Some("true") && value("X-GNOME-Autostart-enabled") != Some("false")
+}
+
+fn parse_desktop_file(path: &Path) -> SystemResult<StartupEntry> {
+    let content = fs::read_to_string(path)?;
+    let values = desktop_entry_values(&content);
+    let value = |name: &str| values.iter().find(|(key, _)| *key == name).map(|(_, value)| value.to_string());
+
+    let exec = value("Exec").ok_or_else(|| SystemError::Parse(format!("Missing Exec in {}", path.display())))?;
+    let mut parts = exec.split_whitespace().map(str::to_string);
+    let command = parts.next().unwrap_or_default();
+    let id = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+
+    Ok(StartupEntry {
+        name: value("Name").unwrap_or_else(|| id.clone()),
+        id,
+        command,
+        arguments: parts.collect(),
+        description: value("Comment"),
+        startup_type: StartupType::DesktopAutostart,
+        enabled: desktop_enabled(&values),
+        run_as_admin: false,
+        delay_seconds: value("X-GNOME-Autostart-Delay").and_then(|delay| delay.parse().ok()),
+        working_directory: value("Path"),
+        environment_variables: None,
+        created_time: None,
+        last_modified: fs::metadata(path).and_then(|m| m.modified()).ok(),
+    })
+}
+
+/// 在 [Desktop Entry] 段中写入 Hidden，已有的 X-GNOME-Autostart-enabled 同步更新
+fn set_desktop_enabled(content: &str, enabled: bool) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    let mut in_entry = false;
+    let mut hidden_written = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            if in_entry && !hidden_written {
+                lines.push(format!("Hidden={}", !enabled));
+                hidden_written = true;
+            }
+            in_entry = trimmed == "[Desktop Entry]";
+        } else if in_entry {
+            match trimmed.split_once('=').map(|(key, _)| key.trim()) {
+                Some("Hidden") => {
+                    lines.push(format!("Hidden={}", !enabled));
+                    hidden_written = true;
+                    continue;
+                }
+                Some("X-GNOME-Autostart-enabled") => {
+                    lines.push(format!("X-GNOME-Autostart-enabled={}", enabled));
+                    continue;
+                }
+                _ => {}
+            }
+        }
+        lines.push(line.to_string());
+    }
+    if !hidden_written {
+        // 文件以 [Desktop Entry] 段结尾，或者根本没有该段
+        if !in_entry {
+            lines.push("[Desktop Entry]".to_string());
+        }
+        lines.push(format!("Hidden={}", !enabled));
+    }
+
+    let mut result = lines.join("\n");
+    result.push('\n');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_desktop_enabled() {
+        let content = "[Desktop Entry]\nType=Application\nName=Sync\nExec=/usr/bin/sync-client --tray\n\
+            X-GNOME-Autostart-enabled=true\n\n[Desktop Action Quit]\nExec=/usr/bin/sync-client --quit\n";
+
+        let disabled = set_desktop_enabled(content, false);
+        assert!(!desktop_enabled(&desktop_entry_values(&disabled)));
+        assert!(disabled.contains("X-GNOME-Autostart-enabled=false"));
+        // Hidden 必须写在 [Desktop Entry] 段内
+        assert!(disabled.find("Hidden=true").unwrap() < disabled.find("[Desktop Action Quit]").unwrap());
+
+        let enabled = set_desktop_enabled(&disabled, true);
+        assert!(desktop_enabled(&desktop_entry_values(&enabled)));
+        assert_eq!(enabled.matches("Hidden=").count(), 1);
+    }
+}
@@ -2,98 +2,13 @@
 // 支持 LaunchAgent、LaunchDaemon 和 LoginItems
 
 use crate::system::common::error::{SystemResult, SystemError};
+use crate::system::common::macos::{launchctl_load, launchctl_unload, user_launch_agents_dir, LaunchPlist};
 use super::types::{StartupEntry, StartupType};
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::process::Command;
 use std::collections::HashMap;
 
-/// plist 文件内容结构
-#[derive(Debug)]
-struct LaunchPlist {
-    label: String,
-    program: String,
-    program_arguments: Vec<String>,
-    run_at_load: bool,
-    keep_alive: bool,
-    working_directory: Option<String>,
-    environment_variables: Option<HashMap<String, String>>,
-    start_interval: Option<u32>,
-}
-
-impl LaunchPlist {
-    fn to_plist_xml(&self) -> String {
-        let mut xml = String::new();
-        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
-        xml.push_str("<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">\n");
-        xml.push_str("<plist version=\"1.0\">\n<dict>\n");
-        
-        // Label
-        xml.push_str(&format!("\t<key>Label</key>\n\t<string>{}</string>\n", self.label));
-        
-        // Program
-        xml.push_str(&format!("\t<key>Program</key>\n\t<string>{}</string>\n", self.program));
-        
-        // ProgramArguments
-        if !self.program_arguments.is_empty() {
-            xml.push_str("\t<key>ProgramArguments</key>\n\t<array>\n");
-            xml.push_str(&format!("\t\t<string>{}</string>\n", self.program));
-            for arg in &self.program_arguments {
-                xml.push_str(&format!("\t\t<string>{}</string>\n", arg));
-            }
-            xml.push_str("\t</array>\n");
-        }
-        
-        // RunAtLoad
-        if self.run_at_load {
-            xml.push_str("\t<key>RunAtLoad</key>\n\t<true/>\n");
-        }
-        
-        // KeepAlive
-        if self.keep_alive {
-            xml.push_str("\t<key>KeepAlive</key>\n\t<true/>\n");
-        }
-        
-        // WorkingDirectory
-        if let Some(ref wd) = self.working_directory {
-            xml.push_str(&format!("\t<key>WorkingDirectory</key>\n\t<string>{}</string>\n", wd));
-        }
-        
-        // EnvironmentVariables
-        if let Some(ref env_vars) = self.environment_variables {
-            if !env_vars.is_empty() {
-                xml.push_str("\t<key>EnvironmentVariables</key>\n\t<dict>\n");
-                for (key, value) in env_vars {
-                    xml.push_str(&format!("\t\t<key>{}</key>\n\t\t<string>{}</string>\n", key, value));
-                }
-                xml.push_str("\t</dict>\n");
-            }
-        }
-        
-        // StartInterval (for delayed start)
-        if let Some(interval) = self.start_interval {
-            xml.push_str(&format!("\t<key>StartInterval</key>\n\t<integer>{}</integer>\n", interval));
-        }
-        
-        xml.push_str("</dict>\n</plist>\n");
-        xml
-    }
-}
-
-/// 获取用户 LaunchAgents 目录
-fn get_user_launch_agents_dir() -> SystemResult<PathBuf> {
-    let home_dir = std::env::var("HOME")
-        .map_err(|_| SystemError::Configuration("Cannot get user home directory".to_string()))?;
-    let launch_agents_dir = Path::new(&home_dir).join("Library/LaunchAgents");
-    
-    // 确保目录存在
-    if !launch_agents_dir.exists() {
-        fs::create_dir_all(&launch_agents_dir)?;
-    }
-    
-    Ok(launch_agents_dir)
-}
-
 /// 获取系统 LaunchDaemons 目录
 fn get_system_launch_daemons_dir() -> PathBuf {
     PathBuf::from("/Library/LaunchDaemons")
@@ -334,7 +249,7 @@ fn list_login_items() -> SystemResult<Vec<StartupEntry>> {
 pub fn list_all_startup_entries() -> SystemResult<Vec<StartupEntry>> {
     let mut all_entries = Vec::new();
     
-    match get_user_launch_agents_dir() {
+    match user_launch_agents_dir() {
         Ok(agents_dir) => {
             match list_plist_files_in_dir(&agents_dir, StartupType::LaunchAgent) {
                 Ok(mut entries) => all_entries.append(&mut entries),
@@ -373,7 +288,7 @@ pub fn add_startup_entry(entry: &StartupEntry) -> SystemResult<()> {
 
 /// 添加 LaunchAgent
 fn add_launch_agent(entry: &StartupEntry) -> SystemResult<()> {
-    let agents_dir = get_user_launch_agents_dir()?;
+    let agents_dir = user_launch_agents_dir()?;
     let plist_path = agents_dir.join(format!("{}.plist", entry.id));
     
     let plist = LaunchPlist {
@@ -385,19 +300,13 @@ fn add_launch_agent(entry: &StartupEntry) -> SystemResult<()> {
         working_directory: entry.working_directory.clone(),
         environment_variables: entry.environment_variables.clone(),
         start_interval: entry.delay_seconds,
+        start_calendar_interval: None,
     };
     
     fs::write(&plist_path, plist.to_plist_xml())?;
     
     if entry.enabled {
-        let output = Command::new("launchctl")
-            .args(&["load", plist_path.to_string_lossy().as_ref()])
-            .output()?;
-        
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(SystemError::SystemCall(format!("加载 LaunchAgent 失败: {}", stderr), None));
-        }
+        launchctl_load(&plist_path)?;
     }
     
     Ok(())
@@ -420,6 +329,7 @@ fn add_launch_daemon(entry: &StartupEntry) -> SystemResult<()> {
         working_directory: entry.working_directory.clone(),
         environment_variables: entry.environment_variables.clone(),
         start_interval: entry.delay_seconds,
+        start_calendar_interval: None,
     };
     
     fs::write(&plist_path, plist.to_plist_xml())?;
@@ -433,14 +343,7 @@ fn add_launch_daemon(entry: &StartupEntry) -> SystemResult<()> {
         .output()?;
     
     if entry.enabled {
-        let output = Command::new("launchctl")
-            .args(&["load", plist_path.to_string_lossy().as_ref()])
-            .output()?;
-        
-        if !output.status.success() {
-            let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(SystemError::SystemCall(format!("加载 LaunchDaemon 失败: {}", stderr), None));
-        }
+        launchctl_load(&plist_path)?;
     }
     
     Ok(())
@@ -478,13 +381,11 @@ pub fn remove_startup_entry(id: &str, startup_type: StartupType) -> SystemResult
 
 /// 移除 LaunchAgent
 fn remove_launch_agent(id: &str) -> SystemResult<()> {
-    let agents_dir = get_user_launch_agents_dir()?;
+    let agents_dir = user_launch_agents_dir()?;
     let plist_path = agents_dir.join(format!("{}.plist", id));
     
     if plist_path.exists() {
-        let _ = Command::new("launchctl")
-            .args(&["unload", plist_path.to_string_lossy().as_ref()])
-            .output();
+        launchctl_unload(&plist_path);
         
         fs::remove_file(&plist_path)?;
     }
@@ -502,9 +403,7 @@ fn remove_launch_daemon(id: &str) -> SystemResult<()> {
     }
     
     if plist_path.exists() {
-        let _ = Command::new("launchctl")
-            .args(&["unload", plist_path.to_string_lossy().as_ref()])
-            .output();
+        launchctl_unload(&plist_path);
         
         fs::remove_file(&plist_path)?;
     }
@@ -512,6 +411,56 @@ fn remove_launch_daemon(id: &str) -> SystemResult<()> {
     Ok(())
 }
 
+/// 启用或禁用启动项：修改 plist 中的 RunAtLoad，并通过 launchctl 加载或卸载
+pub fn set_startup_entry_enabled(id: &str, startup_type: StartupType, enabled: bool) -> SystemResult<()> {
+    let plist_path = match startup_type {
+        StartupType::LaunchAgent => user_launch_agents_dir()?.join(format!("{}.plist", id)),
+        StartupType::LaunchDaemon => {
+            if std::env::var("USER").unwrap_or_default() != "root" {
+                return Err(SystemError::PermissionDenied("Modifying system-level startup items requires root privileges".to_string()));
+            }
+            get_system_launch_daemons_dir().join(format!("{}.plist", id))
+        }
+        _ => return Err(SystemError::NotSupported(format!("Unsupported startup type: {:?}", startup_type))),
+    };
+
+    if !plist_path.exists() {
+        return Err(SystemError::NotFound(format!("Startup entry not found: {}", id)));
+    }
+
+    let content = fs::read_to_string(&plist_path)?;
+    fs::write(&plist_path, set_run_at_load(&content, enabled))?;
+
+    // 重新加载使修改后的 plist 生效
+    launchctl_unload(&plist_path);
+    if enabled {
+        launchctl_load(&plist_path)?;
+    }
+    Ok(())
+}
+
+/// 修改 RunAtLoad 的值，不存在时插入到顶层 dict 的末尾
+fn set_run_at_load(content: &str, enabled: bool) -> String {
+    const KEY: &str = "<key>RunAtLoad</key>";
+    let value = if enabled { "<true/>" } else { "<false/>" };
+
+    if let Some(key_pos) = content.find(KEY) {
+        let after_key = key_pos + KEY.len();
+        let rest = &content[after_key..];
+        let value_start = after_key + (rest.len() - rest.trim_start().len());
+        for old in ["<true/>", "<false/>"] {
+            if content[value_start..].starts_with(old) {
+                return format!("{}{}{}", &content[..value_start], value, &content[value_start + old.len()..]);
+            }
+        }
+    }
+
+    match content.rfind("</dict>") {
+        Some(pos) => format!("{}\t{}\n\t{}\n{}", &content[..pos], KEY, value, &content[pos..]),
+        None => content.to_string(),
+    }
+}
+
 /// 移除登录项
 fn remove_login_item(id: &str) -> SystemResult<()> {
     let app_name = id.strip_prefix("loginitem_").unwrap_or(id);
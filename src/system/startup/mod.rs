@@ -4,8 +4,8 @@
 use crate::system::common::*;
 
 // 平台特定实现
-// #[cfg(target_os = "windows")]
-// pub mod windows;
+#[cfg(target_os = "windows")]
+pub mod windows;
 
 #[cfg(target_os = "linux")]
 pub mod linux;
@@ -27,9 +27,7 @@ impl StartupManager {
     pub fn list_all(&self) -> SystemResult<Vec<StartupEntry>> {
         // 根据平台调用相应实现
         #[cfg(target_os = "windows")]
-        // return windows::list_all_startup_entries();
-        return Err(SystemError::NotSupported("Unsupported platform".to_string()));
-
+        return windows::list_all_startup_entries();
         
         #[cfg(target_os = "linux")]
         return linux::list_all_startup_entries();
@@ -44,8 +42,7 @@ impl StartupManager {
     /// 添加启动项
     pub fn add_entry(&self, _entry: &StartupEntry) -> SystemResult<()> {
         #[cfg(target_os = "windows")]
-        // return windows::add_startup_entry(_entry);
-        return Err(SystemError::NotSupported("Unsupported platform".to_string()));
+        return windows::add_startup_entry(_entry);
         
         #[cfg(target_os = "linux")]
         return linux::add_startup_entry(_entry);
@@ -59,9 +56,8 @@ impl StartupManager {
     
     /// 移除启动项
     pub fn remove_entry(&self, _id: &str, _startup_type: StartupType) -> SystemResult<()> {
-        // #[cfg(target_os = "windows")]
-        // return windows::remove_startup_entry(_id, _startup_type);
-        return Err(SystemError::NotSupported("Unsupported platform".to_string()));
+        #[cfg(target_os = "windows")]
+        return windows::remove_startup_entry(_id, _startup_type);
         
         #[cfg(target_os = "linux")]
         return linux::remove_startup_entry(_id, _startup_type);
@@ -73,6 +69,18 @@ impl StartupManager {
         Err(SystemError::NotSupported("Unsupported platform".to_string()))
     }
     
+    /// 启用或禁用启动项，不删除其配置
+    pub fn set_enabled(&self, _id: &str, _startup_type: StartupType, _enabled: bool) -> SystemResult<()> {
+        #[cfg(target_os = "linux")]
+        return linux::set_startup_entry_enabled(_id, _startup_type, _enabled);
+        
+        #[cfg(target_os = "macos")]
+        return macos::set_startup_entry_enabled(_id, _startup_type, _enabled);
+        
+        #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+        Err(SystemError::NotSupported("Unsupported platform".to_string()))
+    }
+    
     /// 获取支持的启动类型
     pub fn get_supported_types(&self) -> Vec<StartupType> {
         #[cfg(target_os = "windows")]
@@ -0,0 +1,326 @@
+// Windows 启动管理模块
+// 支持注册表 Run 键（当前用户 / 本机）和启动文件夹中的快捷方式
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use windows::core::{Interface, PCWSTR};
+use windows::Win32::System::Com::{
+    CoCreateInstance, CoInitializeEx, CoUninitialize, IPersistFile, CLSCTX_INPROC_SERVER, COINIT_APARTMENTTHREADED,
+    STGM_READ,
+};
+use windows::Win32::UI::Shell::{IShellLinkW, ShellLink};
+
+use super::types::{StartupEntry, StartupType};
+use crate::system::common::error::{SystemError, SystemResult};
+use crate::system::registry::{RegistryTools, RegistryValue};
+
+const RUN_KEY: &str = "Software\\Microsoft\\Windows\\CurrentVersion\\Run";
+
+/// 获取所有启动项
+/// 某一来源无法读取（例如没有权限）时跳过，不影响其他来源
+pub fn list_all_startup_entries() -> SystemResult<Vec<StartupEntry>> {
+    let mut entries = Vec::new();
+
+    for startup_type in [StartupType::RegistryCurrentUser, StartupType::RegistryLocalMachine] {
+        if let Ok(mut items) = list_run_key(startup_type) {
+            entries.append(&mut items);
+        }
+    }
+    if let Ok(mut items) = list_startup_folder() {
+        entries.append(&mut items);
+    }
+
+    Ok(entries)
+}
+
+/// 添加启动项
+pub fn add_startup_entry(entry: &StartupEntry) -> SystemResult<()> {
+    // 验证启动项配置
+    entry.validate().map_err(SystemError::InvalidArgument)?;
+
+    match entry.startup_type {
+        StartupType::RegistryCurrentUser | StartupType::RegistryLocalMachine => add_run_value(entry),
+        StartupType::StartupFolder => add_shortcut(entry),
+        _ => Err(SystemError::NotSupported(format!("Unsupported startup type: {:?}", entry.startup_type))),
+    }
+}
+
+/// 移除启动项
+pub fn remove_startup_entry(id: &str, startup_type: StartupType) -> SystemResult<()> {
+    match startup_type {
+        StartupType::RegistryCurrentUser | StartupType::RegistryLocalMachine => {
+            RegistryTools.delete_value(run_hive(&startup_type), RUN_KEY, id)
+        }
+        StartupType::StartupFolder => {
+            let path = shortcut_path(id)?;
+            if !path.exists() {
+                return Err(SystemError::NotFound(format!("Startup entry not found: {}", id)));
+            }
+            fs::remove_file(&path)?;
+            Ok(())
+        }
+        _ => Err(SystemError::NotSupported(format!("Unsupported startup type: {:?}", startup_type))),
+    }
+}
+
+fn run_hive(startup_type: &StartupType) -> &'static str {
+    match startup_type {
+        StartupType::RegistryLocalMachine => "HKLM",
+        _ => "HKCU",
+    }
+}
+
+/// 读取 Run 键，值名作为 id 和名称
+fn list_run_key(startup_type: StartupType) -> SystemResult<Vec<StartupEntry>> {
+    let values = RegistryTools.list_values(run_hive(&startup_type), RUN_KEY)?;
+    Ok(values
+        .into_iter()
+        .filter_map(|(name, value)| {
+            let command_line = match value {
+                RegistryValue::String(s) | RegistryValue::ExpandString(s) => s,
+                _ => return None,
+            };
+            let (command, arguments) = split_command_line(&command_line);
+            Some(StartupEntry {
+                id: name.clone(),
+                name,
+                command,
+                arguments,
+                description: None,
+                startup_type: startup_type.clone(),
+                enabled: true,
+                run_as_admin: startup_type == StartupType::RegistryLocalMachine,
+                delay_seconds: None,
+                working_directory: None,
+                environment_variables: None,
+                created_time: None,
+                last_modified: None,
+            })
+        })
+        .collect())
+}
+
+/// 写入 Run 键，工作目录、延迟等 Run 键不支持的字段会被忽略
+fn add_run_value(entry: &StartupEntry) -> SystemResult<()> {
+    let mut command_line = quote_argument(&entry.command);
+    for argument in &entry.arguments {
+        command_line.push(' ');
+        command_line.push_str(&quote_argument(argument));
+    }
+    RegistryTools.write_value(
+        run_hive(&entry.startup_type),
+        RUN_KEY,
+        &entry.id,
+        &RegistryValue::String(command_line),
+    )
+}
+
+/// 当前用户的启动文件夹
+fn startup_folder() -> SystemResult<PathBuf> {
+    let app_data = std::env::var("APPDATA")
+        .map_err(|_| SystemError::Configuration("Cannot get APPDATA directory".to_string()))?;
+    Ok(Path::new(&app_data).join("Microsoft\\Windows\\Start Menu\\Programs\\Startup"))
+}
+
+fn shortcut_path(id: &str) -> SystemResult<PathBuf> {
+    if id.is_empty() || id.contains(['\\', '/', ':']) {
+        return Err(SystemError::InvalidArgument(format!("Invalid startup entry id: {:?}", id)));
+    }
+    Ok(startup_folder()?.join(format!("{}.lnk", id)))
+}
+
+/// 读取启动文件夹中的快捷方式，其他文件直接以文件路径作为命令
+fn list_startup_folder() -> SystemResult<Vec<StartupEntry>> {
+    let folder = startup_folder()?;
+    if !folder.exists() {
+        return Ok(Vec::new());
+    }
+
+    let _com = ComGuard::init();
+    let mut entries = Vec::new();
+    for dir_entry in fs::read_dir(&folder)? {
+        let path = dir_entry?.path();
+        if !path.is_file() || path.file_name().is_some_and(|name| name.eq_ignore_ascii_case("desktop.ini")) {
+            continue;
+        }
+
+        let id = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+        let is_shortcut = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("lnk"));
+        let shortcut = if is_shortcut { read_shortcut(&path).ok() } else { None };
+        let (command, arguments, working_directory, description) = match shortcut {
+            Some(shortcut) => shortcut,
+            None => (path.to_string_lossy().to_string(), Vec::new(), None, None),
+        };
+
+        entries.push(StartupEntry {
+            id: id.clone(),
+            name: id,
+            command,
+            arguments,
+            description,
+            startup_type: StartupType::StartupFolder,
+            enabled: true,
+            run_as_admin: false,
+            delay_seconds: None,
+            working_directory,
+            environment_variables: None,
+            created_time: fs::metadata(&path).and_then(|m| m.created()).ok(),
+            last_modified: fs::metadata(&path).and_then(|m| m.modified()).ok(),
+        });
+    }
+
+    Ok(entries)
+}
+
+/// 在启动文件夹中创建快捷方式
+fn add_shortcut(entry: &StartupEntry) -> SystemResult<()> {
+    let path = shortcut_path(&entry.id)?;
+    if let Some(folder) = path.parent() {
+        fs::create_dir_all(folder)?;
+    }
+
+    let arguments = entry.arguments.iter().map(|arg| quote_argument(arg)).collect::<Vec<_>>().join(" ");
+
+    let _com = ComGuard::init();
+    unsafe {
+        let link: IShellLinkW = CoCreateInstance(&ShellLink, None, CLSCTX_INPROC_SERVER).map_err(com_error)?;
+        link.SetPath(PCWSTR::from_raw(wide(&entry.command).as_ptr())).map_err(com_error)?;
+        link.SetArguments(PCWSTR::from_raw(wide(&arguments).as_ptr())).map_err(com_error)?;
+        if let Some(ref dir) = entry.working_directory {
+            link.SetWorkingDirectory(PCWSTR::from_raw(wide(dir).as_ptr())).map_err(com_error)?;
+        }
+        if let Some(ref description) = entry.description {
+            link.SetDescription(PCWSTR::from_raw(wide(description).as_ptr())).map_err(com_error)?;
+        }
+
+        let file: IPersistFile = link.cast().map_err(com_error)?;
+        file.Save(PCWSTR::from_raw(wide(&path.to_string_lossy()).as_ptr()), true).map_err(com_error)?;
+    }
+    Ok(())
+}
+
+type ShortcutInfo = (String, Vec<String>, Option<String>, Option<String>);
+
+/// 读取快捷方式的目标、参数、工作目录和描述
+fn read_shortcut(path: &Path) -> SystemResult<ShortcutInfo> {
+    let mut buffer = vec![0u16; 1024];
+    let text = |buffer: &[u16]| {
+        let end = buffer.iter().position(|&unit| unit == 0).unwrap_or(buffer.len());
+        String::from_utf16_lossy(&buffer[..end])
+    };
+    let non_empty = |s: String| if s.is_empty() { None } else { Some(s) };
+
+    unsafe {
+        let link: IShellLinkW = CoCreateInstance(&ShellLink, None, CLSCTX_INPROC_SERVER).map_err(com_error)?;
+        let file: IPersistFile = link.cast().map_err(com_error)?;
+        file.Load(PCWSTR::from_raw(wide(&path.to_string_lossy()).as_ptr()), STGM_READ).map_err(com_error)?;
+
+        link.GetPath(&mut buffer, std::ptr::null_mut(), 0).map_err(com_error)?;
+        let target = text(&buffer);
+        buffer.fill(0);
+        link.GetArguments(&mut buffer).map_err(com_error)?;
+        let arguments = split_arguments(&text(&buffer));
+        buffer.fill(0);
+        link.GetWorkingDirectory(&mut buffer).map_err(com_error)?;
+        let working_directory = non_empty(text(&buffer));
+        buffer.fill(0);
+        link.GetDescription(&mut buffer).map_err(com_error)?;
+        let description = non_empty(text(&buffer));
+
+        Ok((target, arguments, working_directory, description))
+    }
+}
+
+/// COM 初始化守卫，只有本次初始化成功时才在释放时反初始化
+struct ComGuard(bool);
+
+impl ComGuard {
+    fn init() -> Self {
+        ComGuard(unsafe { CoInitializeEx(None, COINIT_APARTMENTTHREADED) }.is_ok())
+    }
+}
+
+impl Drop for ComGuard {
+    fn drop(&mut self) {
+        if self.0 {
+            unsafe { CoUninitialize() };
+        }
+    }
+}
+
+fn com_error(error: windows::core::Error) -> SystemError {
+    SystemError::SystemCall(format!("Shell link operation failed: {}", error), Some(error.code().0))
+}
+
+/// 转换为以 NUL 结尾的 UTF-16
+fn wide(s: &str) -> Vec<u16> {
+    s.encode_utf16().chain(std::iter::once(0)).collect()
+}
+
+/// 含空白或引号的参数用双引号包裹
+fn quote_argument(argument: &str) -> String {
+    if !argument.is_empty() && !argument.contains([' ', '\t', '"']) {
+        return argument.to_string();
+    }
+    format!("\"{}\"", argument.replace('"', "\\\""))
+}
+
+/// 按 Windows 命令行规则拆分为程序和参数
+fn split_command_line(command_line: &str) -> (String, Vec<String>) {
+    let mut parts = split_arguments(command_line).into_iter();
+    let command = parts.next().unwrap_or_default();
+    (command, parts.collect())
+}
+
+/// 按空白拆分参数，支持双引号包裹和 `\"` 转义
+fn split_arguments(line: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+    let mut has_token = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&'"') => {
+                current.push('"');
+                chars.next();
+                has_token = true;
+            }
+            '"' => {
+                in_quotes = !in_quotes;
+                has_token = true;
+            }
+            c if c.is_whitespace() && !in_quotes => {
+                if has_token {
+                    parts.push(std::mem::take(&mut current));
+                    has_token = false;
+                }
+            }
+            c => {
+                current.push(c);
+                has_token = true;
+            }
+        }
+    }
+    if has_token {
+        parts.push(current);
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_line_round_trip() {
+        let (command, arguments) = split_command_line("\"C:\\Program Files\\App\\app.exe\" --minimized \"a \\\"b\\\"\"");
+        assert_eq!(command, "C:\\Program Files\\App\\app.exe");
+        assert_eq!(arguments, vec!["--minimized".to_string(), "a \"b\"".to_string()]);
+
+        let line = [quote_argument(&command), quote_argument(&arguments[0]), quote_argument(&arguments[1])].join(" ");
+        assert_eq!(split_command_line(&line), (command, arguments));
+    }
+}
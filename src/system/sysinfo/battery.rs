@@ -0,0 +1,273 @@
+// 电池与电源状态
+// Linux 读取 /sys/class/power_supply，macOS 使用 IOKit 的 IOPowerSources，Windows 使用 GetSystemPowerStatus
+
+use super::{BatteryInfo, PowerState};
+use crate::system::common::error::SystemResult;
+
+/// 读取电池状态，没有电池时返回 None
+pub(super) fn read_battery_info() -> SystemResult<Option<BatteryInfo>> {
+    platform::read_battery_info()
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::fs;
+    use std::path::Path;
+    use std::time::Duration;
+
+    use super::{BatteryInfo, PowerState};
+    use crate::system::common::error::SystemResult;
+
+    pub fn read_battery_info() -> SystemResult<Option<BatteryInfo>> {
+        read_power_supply(Path::new("/sys/class/power_supply"))
+    }
+
+    /// 单块电池的原始读数，能量单位为 µWh / µW，电量单位为 µAh / µA
+    struct Battery {
+        status: String,
+        now: Option<u64>,
+        full: Option<u64>,
+        rate: Option<u64>,
+        capacity: Option<f32>,
+    }
+
+    /// 汇总目录下所有系统电池；鼠标、键盘等外设电池（scope=Device）不计入
+    pub fn read_power_supply(root: &Path) -> SystemResult<Option<BatteryInfo>> {
+        if !root.exists() {
+            return Ok(None);
+        }
+
+        let mut batteries = Vec::new();
+        for entry in fs::read_dir(root)? {
+            let dir = entry?.path();
+            let read = |name: &str| fs::read_to_string(dir.join(name)).ok().map(|value| value.trim().to_string());
+            let number = |name: &str| read(name).and_then(|value| value.parse::<u64>().ok());
+
+            if read("type").as_deref() != Some("Battery")
+                || read("scope").as_deref() == Some("Device")
+                || read("present").as_deref() == Some("0")
+            {
+                continue;
+            }
+
+            // 优先使用能量读数，没有时退回电量读数
+            let (now, full, rate) = match number("energy_now") {
+                Some(now) => (Some(now), number("energy_full"), number("power_now")),
+                None => (number("charge_now"), number("charge_full"), number("current_now")),
+            };
+            batteries.push(Battery {
+                status: read("status").unwrap_or_default(),
+                now,
+                full,
+                rate,
+                capacity: read("capacity").and_then(|value| value.parse().ok()),
+            });
+        }
+
+        if batteries.is_empty() {
+            return Ok(None);
+        }
+
+        let state = if batteries.iter().any(|b| b.status == "Charging") {
+            PowerState::Charging
+        } else if batteries.iter().any(|b| b.status == "Discharging") {
+            PowerState::Discharging
+        } else if batteries.iter().all(|b| b.status == "Full") {
+            PowerState::Full
+        } else if batteries.iter().any(|b| b.status == "Not charging") {
+            PowerState::NotCharging
+        } else {
+            PowerState::Unknown
+        };
+
+        let sum = |field: fn(&Battery) -> Option<u64>| batteries.iter().map(field).sum::<Option<u64>>();
+        let (now, full, rate) = (sum(|b| b.now), sum(|b| b.full), sum(|b| b.rate));
+
+        let percentage = match (now, full) {
+            (Some(now), Some(full)) if full > 0 => now as f32 / full as f32 * 100.0,
+            _ => {
+                let capacities: Vec<f32> = batteries.iter().filter_map(|b| b.capacity).collect();
+                if capacities.is_empty() {
+                    0.0
+                } else {
+                    capacities.iter().sum::<f32>() / capacities.len() as f32
+                }
+            }
+        };
+
+        let time_remaining = match (now, full, rate) {
+            (Some(now), _, Some(rate)) if rate > 0 && state == PowerState::Discharging => {
+                Some(Duration::from_secs_f64(now as f64 / rate as f64 * 3600.0))
+            }
+            (Some(now), Some(full), Some(rate)) if rate > 0 && state == PowerState::Charging => {
+                Some(Duration::from_secs_f64(full.saturating_sub(now) as f64 / rate as f64 * 3600.0))
+            }
+            _ => None,
+        };
+
+        Ok(Some(BatteryInfo {
+            percentage: percentage.clamp(0.0, 100.0),
+            state,
+            time_remaining,
+        }))
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use std::time::Duration;
+
+    use super::{BatteryInfo, PowerState};
+    use crate::system::common::error::SystemResult;
+    use crate::system::sysinfo::core_foundation::{CFArrayGetCount, CFArrayGetValueAtIndex, CFDictionary, CFOwned, CFTypeRef};
+
+    #[link(name = "IOKit", kind = "framework")]
+    extern "C" {
+        fn IOPSCopyPowerSourcesInfo() -> CFTypeRef;
+        fn IOPSCopyPowerSourcesList(blob: CFTypeRef) -> CFTypeRef;
+        fn IOPSGetPowerSourceDescription(blob: CFTypeRef, ps: CFTypeRef) -> CFTypeRef;
+    }
+
+    pub fn read_battery_info() -> SystemResult<Option<BatteryInfo>> {
+        let blob = CFOwned(unsafe { IOPSCopyPowerSourcesInfo() });
+        if blob.is_null() {
+            return Ok(None);
+        }
+        let list = CFOwned(unsafe { IOPSCopyPowerSourcesList(blob.0) });
+        if list.is_null() {
+            return Ok(None);
+        }
+
+        for index in 0..unsafe { CFArrayGetCount(list.0) } {
+            let source = unsafe { CFArrayGetValueAtIndex(list.0, index) };
+            let description = CFDictionary(unsafe { IOPSGetPowerSourceDescription(blob.0, source) });
+            if description.string("Type").as_deref() != Some("InternalBattery") {
+                continue;
+            }
+
+            let current = description.number("Current Capacity").unwrap_or(0);
+            let max = description.number("Max Capacity").unwrap_or(100).max(1);
+            let on_ac = description.string("Power Source State").as_deref() == Some("AC Power");
+            let charging = description.bool("Is Charging").unwrap_or(false);
+
+            let state = if charging {
+                PowerState::Charging
+            } else if !on_ac {
+                PowerState::Discharging
+            } else if description.bool("Is Charged").unwrap_or(false) || current >= max {
+                PowerState::Full
+            } else {
+                PowerState::NotCharging
+            };
+
+            // 剩余时间以分钟为单位，-1 表示仍在估算
+            let minutes = match state {
+                PowerState::Charging => description.number("Time to Full Charge"),
+                PowerState::Discharging => description.number("Time to Empty"),
+                _ => None,
+            };
+
+            return Ok(Some(BatteryInfo {
+                percentage: (current as f32 / max as f32 * 100.0).clamp(0.0, 100.0),
+                state,
+                time_remaining: minutes.filter(|&m| m > 0).map(|m| Duration::from_secs(m as u64 * 60)),
+            }));
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use std::time::Duration;
+
+    use windows::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+    use super::{BatteryInfo, PowerState};
+    use crate::system::common::error::{SystemError, SystemResult};
+
+    const BATTERY_FLAG_CHARGING: u8 = 8;
+    const BATTERY_FLAG_NO_BATTERY: u8 = 128;
+    const UNKNOWN: u8 = 255;
+
+    pub fn read_battery_info() -> SystemResult<Option<BatteryInfo>> {
+        let mut status = SYSTEM_POWER_STATUS::default();
+        unsafe { GetSystemPowerStatus(&mut status) }
+            .map_err(|e| SystemError::SystemCall(format!("GetSystemPowerStatus failed: {}", e), Some(e.code().0)))?;
+
+        if status.BatteryFlag == UNKNOWN || status.BatteryFlag & BATTERY_FLAG_NO_BATTERY != 0 {
+            return Ok(None);
+        }
+
+        let percentage = if status.BatteryLifePercent == UNKNOWN { 0.0 } else { status.BatteryLifePercent as f32 };
+        let state = if status.BatteryFlag & BATTERY_FLAG_CHARGING != 0 {
+            PowerState::Charging
+        } else if status.ACLineStatus == 0 {
+            PowerState::Discharging
+        } else if status.ACLineStatus == 1 && percentage >= 100.0 {
+            PowerState::Full
+        } else if status.ACLineStatus == 1 {
+            PowerState::NotCharging
+        } else {
+            PowerState::Unknown
+        };
+
+        // BatteryLifeTime 只在使用电池供电时有效，未知时为 u32::MAX
+        let time_remaining = (state == PowerState::Discharging && status.BatteryLifeTime != u32::MAX)
+            .then(|| Duration::from_secs(status.BatteryLifeTime as u64));
+
+        Ok(Some(BatteryInfo { percentage: percentage.min(100.0), state, time_remaining }))
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+mod platform {
+    use super::BatteryInfo;
+    use crate::system::common::error::SystemResult;
+
+    pub fn read_battery_info() -> SystemResult<Option<BatteryInfo>> {
+        Ok(None)
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use std::fs;
+    use std::time::Duration;
+
+    use super::platform::read_power_supply;
+    use super::PowerState;
+
+    #[test]
+    fn test_read_power_supply() {
+        let root = std::env::temp_dir().join(format!("lycrex_power_supply_{}", std::process::id()));
+        let write = |dir: &str, files: &[(&str, &str)]| {
+            fs::create_dir_all(root.join(dir)).unwrap();
+            for (name, value) in files {
+                fs::write(root.join(dir).join(name), format!("{}\n", value)).unwrap();
+            }
+        };
+
+        write("AC", &[("type", "Mains"), ("online", "1")]);
+        assert!(read_power_supply(&root).unwrap().is_none());
+
+        write("hidpp_battery_0", &[("type", "Battery"), ("scope", "Device"), ("status", "Discharging"), ("capacity", "10")]);
+        write(
+            "BAT0",
+            &[
+                ("type", "Battery"),
+                ("status", "Discharging"),
+                ("energy_now", "30000000"),
+                ("energy_full", "40000000"),
+                ("power_now", "15000000"),
+            ],
+        );
+        let info = read_power_supply(&root).unwrap().unwrap();
+        assert_eq!(info.state, PowerState::Discharging);
+        assert_eq!(info.percentage, 75.0);
+        assert_eq!(info.time_remaining, Some(Duration::from_secs(2 * 3600)));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
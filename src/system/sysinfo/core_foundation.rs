@@ -0,0 +1,90 @@
+// CoreFoundation 辅助
+// 电池状态和磁盘 I/O 都需要从 IOKit 返回的 CFDictionary 中读取数值，所需的 FFI 集中声明在这里
+
+use std::ffi::{c_char, c_void, CStr, CString};
+
+pub(super) type CFTypeRef = *const c_void;
+pub(super) type CFIndex = isize;
+
+const K_CF_STRING_ENCODING_UTF8: u32 = 0x0800_0100;
+const K_CF_NUMBER_SINT64_TYPE: CFIndex = 4;
+
+#[link(name = "CoreFoundation", kind = "framework")]
+extern "C" {
+    fn CFRelease(cf: CFTypeRef);
+    pub(super) fn CFArrayGetCount(array: CFTypeRef) -> CFIndex;
+    pub(super) fn CFArrayGetValueAtIndex(array: CFTypeRef, index: CFIndex) -> CFTypeRef;
+    fn CFDictionaryGetValue(dict: CFTypeRef, key: CFTypeRef) -> CFTypeRef;
+    fn CFStringCreateWithCString(alloc: CFTypeRef, c_str: *const c_char, encoding: u32) -> CFTypeRef;
+    fn CFStringGetCString(string: CFTypeRef, buffer: *mut c_char, size: CFIndex, encoding: u32) -> u8;
+    fn CFNumberGetValue(number: CFTypeRef, number_type: CFIndex, value: *mut c_void) -> u8;
+    fn CFBooleanGetValue(boolean: CFTypeRef) -> u8;
+}
+
+/// 持有所有权的 CoreFoundation 对象（Create / Copy 规则返回的对象），释放时调用 CFRelease
+pub(super) struct CFOwned(pub CFTypeRef);
+
+impl CFOwned {
+    pub fn is_null(&self) -> bool {
+        self.0.is_null()
+    }
+}
+
+impl Drop for CFOwned {
+    fn drop(&mut self) {
+        if !self.0.is_null() {
+            unsafe { CFRelease(self.0) };
+        }
+    }
+}
+
+/// 创建 CFString，用作字典键或 IORegistry 属性名
+pub(super) fn cf_string(value: &str) -> Option<CFOwned> {
+    let value = CString::new(value).ok()?;
+    let string = CFOwned(unsafe { CFStringCreateWithCString(std::ptr::null(), value.as_ptr(), K_CF_STRING_ENCODING_UTF8) });
+    (!string.is_null()).then_some(string)
+}
+
+/// 将 CFString 转换为 String
+pub(super) fn to_string(value: CFTypeRef) -> Option<String> {
+    if value.is_null() {
+        return None;
+    }
+    let mut buffer = [0 as c_char; 256];
+    let ok = unsafe { CFStringGetCString(value, buffer.as_mut_ptr(), buffer.len() as CFIndex, K_CF_STRING_ENCODING_UTF8) };
+    (ok != 0).then(|| unsafe { CStr::from_ptr(buffer.as_ptr()) }.to_string_lossy().into_owned())
+}
+
+/// 不持有所有权的 CFDictionary，取出的值在字典存活期间有效
+pub(super) struct CFDictionary(pub CFTypeRef);
+
+impl CFDictionary {
+    pub fn value(&self, key: &str) -> CFTypeRef {
+        if self.0.is_null() {
+            return std::ptr::null();
+        }
+        match cf_string(key) {
+            Some(key) => unsafe { CFDictionaryGetValue(self.0, key.0) },
+            None => std::ptr::null(),
+        }
+    }
+
+    pub fn string(&self, key: &str) -> Option<String> {
+        to_string(self.value(key))
+    }
+
+    pub fn number(&self, key: &str) -> Option<i64> {
+        let value = self.value(key);
+        if value.is_null() {
+            return None;
+        }
+        let mut result = 0i64;
+        let ok = unsafe { CFNumberGetValue(value, K_CF_NUMBER_SINT64_TYPE, &mut result as *mut i64 as *mut c_void) };
+        (ok != 0).then_some(result)
+    }
+
+    pub fn bool(&self, key: &str) -> Option<bool> {
+        let value = self.value(key);
+        (!value.is_null()).then(|| unsafe { CFBooleanGetValue(value) } != 0)
+    }
+}
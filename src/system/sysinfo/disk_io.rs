@@ -0,0 +1,246 @@
+// 磁盘 I/O 计数器
+// 各平台读取的都是开机以来每块磁盘的累计读写字节数，速率由 SystemInfoManager 根据相邻两次采样的差值计算
+// Linux 读取 /proc/diskstats，Windows 读取 PhysicalDisk 性能计数器的原始值，macOS 读取 IOBlockStorageDriver 的统计信息
+
+use std::collections::HashMap;
+
+use crate::system::common::error::SystemResult;
+
+/// 按设备名索引的累计 (读取字节数, 写入字节数)
+pub(super) type DiskCounters = HashMap<String, (u64, u64)>;
+
+pub(super) fn read_disk_counters() -> SystemResult<DiskCounters> {
+    platform::read_disk_counters()
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::path::Path;
+
+    use super::DiskCounters;
+    use crate::system::common::error::SystemResult;
+    use crate::system::common::linux::read_proc_file;
+
+    /// /proc/diskstats 中的扇区固定按 512 字节计算，与设备实际的扇区大小无关
+    const SECTOR_SIZE: u64 = 512;
+
+    pub fn read_disk_counters() -> SystemResult<DiskCounters> {
+        let text = read_proc_file("diskstats")?;
+        Ok(parse_diskstats(&text).into_iter().filter(|(name, _)| is_whole_disk(name)).collect())
+    }
+
+    /// 只统计整块磁盘：分区的读写已计入所属磁盘，loop、ram 等虚拟设备没有实际 I/O
+    fn is_whole_disk(name: &str) -> bool {
+        !name.starts_with("loop") && !name.starts_with("ram") && Path::new("/sys/block").join(name).exists()
+    }
+
+    /// 每行格式：major minor name reads merged sectors_read ms_reading writes merged sectors_written ...
+    pub fn parse_diskstats(text: &str) -> DiskCounters {
+        text.lines()
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                let sectors_read = fields.get(5)?.parse::<u64>().ok()?;
+                let sectors_written = fields.get(9)?.parse::<u64>().ok()?;
+                Some((fields[2].to_string(), (sectors_read * SECTOR_SIZE, sectors_written * SECTOR_SIZE)))
+            })
+            .collect()
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use std::ffi::{c_char, CString};
+
+    use super::DiskCounters;
+    use crate::system::common::error::{SystemError, SystemResult};
+    use crate::system::sysinfo::core_foundation::{cf_string, to_string, CFDictionary, CFOwned, CFTypeRef};
+
+    #[allow(non_camel_case_types)]
+    type io_object_t = u32;
+
+    /// kIOMainPortDefault
+    const MAIN_PORT_DEFAULT: u32 = 0;
+
+    #[link(name = "IOKit", kind = "framework")]
+    extern "C" {
+        fn IOServiceMatching(name: *const c_char) -> CFTypeRef;
+        fn IOServiceGetMatchingServices(main_port: u32, matching: CFTypeRef, existing: *mut io_object_t) -> i32;
+        fn IOIteratorNext(iterator: io_object_t) -> io_object_t;
+        fn IOObjectRelease(object: io_object_t) -> i32;
+        fn IORegistryEntryGetChildEntry(entry: io_object_t, plane: *const c_char, child: *mut io_object_t) -> i32;
+        fn IORegistryEntryCreateCFProperty(entry: io_object_t, key: CFTypeRef, allocator: CFTypeRef, options: u32) -> CFTypeRef;
+    }
+
+    /// IOKit 对象的所有权守卫
+    struct IoObject(io_object_t);
+
+    impl Drop for IoObject {
+        fn drop(&mut self) {
+            if self.0 != 0 {
+                unsafe { IOObjectRelease(self.0) };
+            }
+        }
+    }
+
+    fn property(entry: io_object_t, key: &str) -> Option<CFOwned> {
+        let key = cf_string(key)?;
+        let value = CFOwned(unsafe { IORegistryEntryCreateCFProperty(entry, key.0, std::ptr::null(), 0) });
+        (!value.is_null()).then_some(value)
+    }
+
+    /// 每个 IOBlockStorageDriver 对应一块磁盘，其子节点 IOMedia 的 "BSD Name" 即设备名（如 disk0）
+    pub fn read_disk_counters() -> SystemResult<DiskCounters> {
+        let class = CString::new("IOBlockStorageDriver").unwrap_or_default();
+        // IOServiceGetMatchingServices 会接管 matching 字典的所有权，不需要释放
+        let matching = unsafe { IOServiceMatching(class.as_ptr()) };
+        let mut iterator = 0;
+        let status = unsafe { IOServiceGetMatchingServices(MAIN_PORT_DEFAULT, matching, &mut iterator) };
+        if status != 0 {
+            return Err(SystemError::SystemCall(
+                format!("IOServiceGetMatchingServices failed: {:#x}", status),
+                Some(status),
+            ));
+        }
+        let iterator = IoObject(iterator);
+        let plane = CString::new("IOService").unwrap_or_default();
+
+        let mut counters = DiskCounters::new();
+        loop {
+            let driver = IoObject(unsafe { IOIteratorNext(iterator.0) });
+            if driver.0 == 0 {
+                break;
+            }
+
+            let mut media = 0;
+            if unsafe { IORegistryEntryGetChildEntry(driver.0, plane.as_ptr(), &mut media) } != 0 {
+                continue;
+            }
+            let media = IoObject(media);
+            let Some(name) = property(media.0, "BSD Name").and_then(|name| to_string(name.0)) else { continue };
+            let Some(statistics) = property(driver.0, "Statistics") else { continue };
+
+            let statistics = CFDictionary(statistics.0);
+            let read = statistics.number("Bytes (Read)").unwrap_or(0).max(0) as u64;
+            let written = statistics.number("Bytes (Write)").unwrap_or(0).max(0) as u64;
+            counters.insert(name, (read, written));
+        }
+
+        Ok(counters)
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use windows::core::w;
+    use windows::Win32::System::Performance::{
+        PdhAddEnglishCounterW, PdhCloseQuery, PdhCollectQueryData, PdhGetRawCounterArrayW, PdhOpenQueryW, PDH_HCOUNTER,
+        PDH_HQUERY, PDH_MORE_DATA, PDH_RAW_COUNTER_ITEM_W,
+    };
+
+    use super::DiskCounters;
+    use crate::system::common::error::{SystemError, SystemResult};
+
+    /// PDH 查询句柄守卫
+    struct Query(PDH_HQUERY);
+
+    impl Drop for Query {
+        fn drop(&mut self) {
+            unsafe {
+                PdhCloseQuery(self.0);
+            }
+        }
+    }
+
+    fn pdh_error(function: &str, status: u32) -> SystemError {
+        SystemError::SystemCall(format!("{} failed: {:#x}", function, status), Some(status as i32))
+    }
+
+    /// "Disk Read Bytes/sec" 等计数器属于 PERF_COUNTER_BULK_COUNT 类型，其原始值就是累计字节数
+    /// 实例名形如 "0 C:"，"_Total" 实例会被跳过
+    pub fn read_disk_counters() -> SystemResult<DiskCounters> {
+        unsafe {
+            let mut query = PDH_HQUERY::default();
+            let status = PdhOpenQueryW(None, 0, &mut query);
+            if status != 0 {
+                return Err(pdh_error("PdhOpenQueryW", status));
+            }
+            let query = Query(query);
+
+            let mut read = PDH_HCOUNTER::default();
+            let mut written = PDH_HCOUNTER::default();
+            let status = PdhAddEnglishCounterW(query.0, w!("\\PhysicalDisk(*)\\Disk Read Bytes/sec"), 0, &mut read);
+            if status != 0 {
+                return Err(pdh_error("PdhAddEnglishCounterW", status));
+            }
+            let status = PdhAddEnglishCounterW(query.0, w!("\\PhysicalDisk(*)\\Disk Write Bytes/sec"), 0, &mut written);
+            if status != 0 {
+                return Err(pdh_error("PdhAddEnglishCounterW", status));
+            }
+            let status = PdhCollectQueryData(query.0);
+            if status != 0 {
+                return Err(pdh_error("PdhCollectQueryData", status));
+            }
+
+            let mut counters = DiskCounters::new();
+            for (name, value) in raw_values(read)? {
+                counters.entry(name).or_default().0 = value;
+            }
+            for (name, value) in raw_values(written)? {
+                counters.entry(name).or_default().1 = value;
+            }
+            Ok(counters)
+        }
+    }
+
+    /// 读取通配计数器每个实例的原始值
+    unsafe fn raw_values(counter: PDH_HCOUNTER) -> SystemResult<Vec<(String, u64)>> {
+        let mut size = 0u32;
+        let mut count = 0u32;
+        let status = PdhGetRawCounterArrayW(counter, &mut size, &mut count, None);
+        if status != PDH_MORE_DATA {
+            return Err(pdh_error("PdhGetRawCounterArrayW", status));
+        }
+
+        // 缓冲区中条目之后还存放着实例名字符串，按字节数分配
+        let item_size = std::mem::size_of::<PDH_RAW_COUNTER_ITEM_W>();
+        let mut buffer = vec![PDH_RAW_COUNTER_ITEM_W::default(); (size as usize).div_ceil(item_size)];
+        let status = PdhGetRawCounterArrayW(counter, &mut size, &mut count, Some(buffer.as_mut_ptr()));
+        if status != 0 {
+            return Err(pdh_error("PdhGetRawCounterArrayW", status));
+        }
+
+        Ok(buffer[..count as usize]
+            .iter()
+            .filter_map(|item| {
+                let name = item.szName.to_string().ok()?;
+                (name != "_Total").then(|| (name, item.RawValue.FirstValue.max(0) as u64))
+            })
+            .collect())
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+mod platform {
+    use super::DiskCounters;
+    use crate::system::common::error::{SystemError, SystemResult};
+
+    pub fn read_disk_counters() -> SystemResult<DiskCounters> {
+        Err(SystemError::NotSupported("Disk I/O statistics are not supported on this platform".to_string()))
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::platform::parse_diskstats;
+
+    #[test]
+    fn test_parse_diskstats() {
+        let text = "   7       0 loop0 52 0 2130 12 0 0 0 0 0 20 12 0 0 0 0 0 0\n \
+                     259       0 nvme0n1 183740 59650 11573298 30591 269128 165366 14427112 143120 0 181464 180224 0 0 0 0 10203 6511\n \
+                     259       1 nvme0n1p1 330 1115 15594 59 2 0 2 0 0 92 59 0 0 0 0 0 0\n";
+        let counters = parse_diskstats(text);
+        assert_eq!(counters.len(), 3);
+        assert_eq!(counters["nvme0n1"], (11573298 * 512, 14427112 * 512));
+        assert_eq!(counters["nvme0n1p1"], (15594 * 512, 2 * 512));
+    }
+}
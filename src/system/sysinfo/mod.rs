@@ -2,17 +2,39 @@
 // 使用 sysinfo 库获取跨平台系统信息
 
 use crate::system::common::error::{SystemResult, SystemError};
-use sysinfo::{System, Pid, DiskUsage, Disks, Networks};
+use sysinfo::{System, Pid, DiskUsage, Disks, Networks, Components, ProcessRefreshKind, ProcessesToUpdate};
+use std::collections::HashMap;
 use std::sync::{Mutex, Arc};
 use std::time::{Duration, Instant};
 
+#[cfg(target_os = "windows")]
+mod windows_load;
+
+mod battery;
+mod disk_io;
+mod handles;
+mod users;
+
+#[cfg(target_os = "macos")]
+mod core_foundation;
+
+#[cfg(feature = "serde")]
+mod serde_support;
+
 /// 系统信息管理器
+///
+/// 内部状态分别由独立的互斥锁保护，需要同时持有多把锁时必须按以下顺序获取：
+/// `system` → `disks` → `networks` → `components` → `last_refresh`
+/// 任何方法都不得在持有靠后的锁时再去获取靠前的锁，否则可能与 `refresh_all_coordinated` 死锁
+/// `refresh_interval` 和 `disk_io_sample` 只单独持有，不与其他锁嵌套
 pub struct SystemInfoManager {
     system: Arc<Mutex<System>>,
     disks: Arc<Mutex<Disks>>,
     networks: Arc<Mutex<Networks>>,
+    components: Arc<Mutex<Components>>,
     last_refresh: Arc<Mutex<Instant>>,
-    refresh_interval: Duration,
+    refresh_interval: Arc<Mutex<Duration>>,
+    disk_io_sample: Arc<Mutex<Option<(Instant, disk_io::DiskCounters)>>>,
 }
 
 impl SystemInfoManager {
@@ -22,22 +44,26 @@ impl SystemInfoManager {
             system: Arc::new(Mutex::new(System::new_all())),
             disks: Arc::new(Mutex::new(Disks::new_with_refreshed_list())),
             networks: Arc::new(Mutex::new(Networks::new_with_refreshed_list())),
+            components: Arc::new(Mutex::new(Components::new_with_refreshed_list())),
             last_refresh: Arc::new(Mutex::new(Instant::now())),
-            refresh_interval: Duration::from_secs(2), // 默认2秒刷新间隔
+            refresh_interval: Arc::new(Mutex::new(Duration::from_secs(2))), // 默认2秒刷新间隔
+            disk_io_sample: Arc::new(Mutex::new(None)),
         }
     }
     
     /// 创建带自定义刷新间隔的系统信息管理器    
     pub fn with_refresh_interval(refresh_interval: Duration) -> Self {
-        let mut manager = Self::new();
-        manager.refresh_interval = refresh_interval;
+        let manager = Self::new();
+        manager.set_refresh_interval(refresh_interval);
         manager
     }
     
     /// 检查是否需要刷新数据
     fn should_refresh(&self) -> bool {
+        // 先读出间隔再获取 last_refresh，避免同时持有两把锁
+        let interval = self.get_refresh_interval();
         if let Ok(last_refresh) = self.last_refresh.lock() {
-            last_refresh.elapsed() >= self.refresh_interval
+            last_refresh.elapsed() >= interval
         } else {
             true // 如果无法获取锁，则强制刷新
         }
@@ -71,8 +97,36 @@ impl SystemInfoManager {
         if let Ok(mut networks) = self.networks.lock() {
             networks.refresh(true);
         }
+        if let Ok(mut components) = self.components.lock() {
+            components.refresh(true);
+        }
         self.update_refresh_time();
     }
+
+    /// 按规定的锁顺序一次性持有全部状态并刷新
+    /// 刷新期间其他线程看不到部分更新的数据（例如进程已刷新而磁盘尚未刷新）
+    pub fn refresh_all_coordinated(&self) -> SystemResult<()> {
+        self.refresh_and_read(|_, _, _| ())
+    }
+
+    /// 按 system → disks → networks → components → last_refresh 的顺序持有全部锁并刷新，
+    /// 在释放锁之前用刷新结果调用 `read`，读到的数据与本次刷新一一对应
+    fn refresh_and_read<T>(&self, read: impl FnOnce(&System, &Disks, &Networks) -> T) -> SystemResult<T> {
+        let lock_error = |name: &str| SystemError::Internal(format!("Failed to lock {}", name));
+
+        let mut system = self.system.lock().map_err(|_| lock_error("system info"))?;
+        let mut disks = self.disks.lock().map_err(|_| lock_error("disk info"))?;
+        let mut networks = self.networks.lock().map_err(|_| lock_error("network info"))?;
+        let mut components = self.components.lock().map_err(|_| lock_error("sensor info"))?;
+        let mut last_refresh = self.last_refresh.lock().map_err(|_| lock_error("refresh time"))?;
+
+        system.refresh_all();
+        disks.refresh(true);
+        networks.refresh(true);
+        components.refresh(true);
+        *last_refresh = Instant::now();
+        Ok(read(&system, &disks, &networks))
+    }
     
     /// 仅刷新网络信息（用于需要实时网络数据的场景）
     pub fn refresh_networks(&self) {
@@ -127,6 +181,10 @@ impl SystemInfoManager {
             SystemError::Internal("Failed to lock system info".to_string())
         })?;
         
+        Ok(Self::collect_memory_info(&system))
+    }
+
+    fn collect_memory_info(system: &System) -> MemoryInfo {
         let total = system.total_memory();
         let used = system.used_memory();
         let available = system.available_memory();
@@ -136,17 +194,21 @@ impl SystemInfoManager {
             0.0
         };
         
-        Ok(MemoryInfo {
+        MemoryInfo {
             total,
             used,
             available,
             usage_percent,
             swap_total: system.total_swap(),
             swap_used: system.used_swap(),
-        })
+        }
     }
     
     /// 获取CPU信息
+    ///
+    /// 注意：sysinfo 的 CPU 使用率是两次刷新之间的差值，两次刷新至少需要间隔
+    /// `sysinfo::MINIMUM_CPU_UPDATE_INTERVAL`。本方法只刷新一次，距离上次刷新过近时
+    /// （例如刚创建管理器后的第一次调用）`usage` 可能为 0 或不准确，需要准确值时请使用 `get_cpu_info_sampled`
     pub fn get_cpu_info(&self) -> SystemResult<Vec<CpuInfo>> {
         // 智能刷新CPU数据
         self.refresh_cpu();
@@ -155,7 +217,20 @@ impl SystemInfoManager {
             SystemError::Internal("Failed to lock system info".to_string())
         })?;
         
-        let cpus: Vec<CpuInfo> = system.cpus().iter().enumerate().map(|(index, cpu)| {
+        Ok(Self::collect_cpu_info(&system))
+    }
+
+    /// 采样两次后获取CPU信息，保证使用率有效
+    /// 先刷新一次，等待 sample_delay（不足 `sysinfo::MINIMUM_CPU_UPDATE_INTERVAL` 时按该值计算）后再刷新，
+    /// 使用率即为这段时间内的平均值。等待期间不持有锁
+    pub fn get_cpu_info_sampled(&self, sample_delay: Duration) -> SystemResult<Vec<CpuInfo>> {
+        self.refresh_cpu();
+        std::thread::sleep(sample_delay.max(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL));
+        self.get_cpu_info()
+    }
+
+    fn collect_cpu_info(system: &System) -> Vec<CpuInfo> {
+        system.cpus().iter().enumerate().map(|(index, cpu)| {
             CpuInfo {
                 name: cpu.name().to_string(),
                 brand: cpu.brand().to_string(),
@@ -164,9 +239,7 @@ impl SystemInfoManager {
                 vendor_id: cpu.vendor_id().to_string(),
                 core_index: index,
             }
-        }).collect();
-        
-        Ok(cpus)
+        }).collect()
     }
     
     /// 获取磁盘信息
@@ -178,7 +251,11 @@ impl SystemInfoManager {
             SystemError::Internal("Failed to lock disk info".to_string())
         })?;
         
-        let disk_list: Vec<DiskInfo> = disks.iter().map(|disk| {
+        Ok(Self::collect_disk_info(&disks))
+    }
+
+    fn collect_disk_info(disks: &Disks) -> Vec<DiskInfo> {
+        disks.iter().map(|disk| {
             DiskInfo {
                 name: disk.name().to_string_lossy().to_string(),
                 mount_point: disk.mount_point().to_string_lossy().to_string(),
@@ -189,9 +266,7 @@ impl SystemInfoManager {
                 is_removable: disk.is_removable(),
                 disk_type: format!("{:?}", disk.kind()),
             }
-        }).collect();
-        
-        Ok(disk_list)
+        }).collect()
     }
     
     /// 获取网络接口信息（自动刷新以获取最新数据）
@@ -203,7 +278,11 @@ impl SystemInfoManager {
             SystemError::Internal("Failed to lock network info".to_string())
         })?;
         
-        let network_list: Vec<NetworkInterfaceInfo> = networks.iter().map(|(name, network)| {
+        Ok(Self::collect_network_info(&networks))
+    }
+
+    fn collect_network_info(networks: &Networks) -> Vec<NetworkInterfaceInfo> {
+        networks.iter().map(|(name, network)| {
             NetworkInterfaceInfo {
                 name: name.clone(),
                 bytes_received: network.received(),
@@ -214,9 +293,7 @@ impl SystemInfoManager {
                 errors_on_transmitted: network.errors_on_transmitted(),
                 mac_address: network.mac_address().to_string(),
             }
-        }).collect();
-        
-        Ok(network_list)
+        }).collect()
     }
     
     /// 获取活跃的网络接口信息（过滤掉没有活动的接口）
@@ -226,12 +303,7 @@ impl SystemInfoManager {
         // 过滤出有网络活动的接口
         let active_networks: Vec<NetworkInterfaceInfo> = all_networks
             .into_iter()
-            .filter(|net| {
-                net.bytes_received > 0 || 
-                net.bytes_transmitted > 0 || 
-                net.packets_received > 0 || 
-                net.packets_transmitted > 0
-            })
+            .filter(NetworkInterfaceInfo::has_activity)
             .collect();
         
         Ok(active_networks)
@@ -250,6 +322,67 @@ impl SystemInfoManager {
         Ok(primary)
     }
     
+    /// 获取每块磁盘的读写速率
+    /// 速率为本次与上一次调用之间的平均值；第一次调用没有可比较的采样，速率均为 0
+    pub fn get_disk_io(&self) -> SystemResult<Vec<DiskIoStats>> {
+        let counters = disk_io::read_disk_counters()?;
+        let now = Instant::now();
+
+        let mut sample = self.disk_io_sample.lock().map_err(|_| {
+            SystemError::Internal("Failed to lock disk I/O sample".to_string())
+        })?;
+
+        let mut stats: Vec<DiskIoStats> = counters.iter().map(|(device, &(read, written))| {
+            let previous = sample.as_ref().and_then(|(at, previous)| {
+                previous.get(device).map(|&(prev_read, prev_written)| (now.duration_since(*at), prev_read, prev_written))
+            });
+            let (read_bytes_per_sec, write_bytes_per_sec) = match previous {
+                Some((elapsed, prev_read, prev_written)) if !elapsed.is_zero() => {
+                    let seconds = elapsed.as_secs_f64();
+                    // 计数器在设备重新挂载时可能归零，此时按 0 计算
+                    (
+                        read.saturating_sub(prev_read) as f64 / seconds,
+                        written.saturating_sub(prev_written) as f64 / seconds,
+                    )
+                }
+                _ => (0.0, 0.0),
+            };
+            DiskIoStats { device: device.clone(), read_bytes_per_sec, write_bytes_per_sec }
+        }).collect();
+        stats.sort_by(|a, b| a.device.cmp(&b.device));
+
+        *sample = Some((now, counters));
+        Ok(stats)
+    }
+
+    /// 获取温度传感器读数
+    /// 传感器是否可用取决于平台和权限（例如虚拟机、容器中通常没有传感器，
+    /// 部分 Linux 发行版需要加载 hwmon 驱动），没有可用传感器时返回空列表而不是错误
+    pub fn get_temperatures(&self) -> SystemResult<Vec<SensorInfo>> {
+        // 智能刷新传感器数据
+        self.smart_refresh()?;
+
+        let components = self.components.lock().map_err(|_| {
+            SystemError::Internal("Failed to lock sensor info".to_string())
+        })?;
+
+        let sensors: Vec<SensorInfo> = components.iter().map(|component| {
+            SensorInfo {
+                label: component.label().to_string(),
+                temperature: component.temperature(),
+                max: component.max(),
+                critical: component.critical(),
+            }
+        }).collect();
+
+        Ok(sensors)
+    }
+    
+    /// 获取电池与电源状态，台式机等没有电池的设备返回 None
+    pub fn get_battery_info(&self) -> SystemResult<Option<BatteryInfo>> {
+        battery::read_battery_info()
+    }
+    
     /// 获取进程列表
     pub fn get_processes(&self) -> SystemResult<Vec<ProcessInfo>> {
         // 智能刷新系统数据
@@ -279,14 +412,28 @@ impl SystemInfoManager {
                 start_time: Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(process.start_time())),
                 run_time: process.run_time(),
                 user: process.user_id().map(|uid| uid.to_string()),
-                status: ProcessStatus::Running, // sysinfo doesn't provide detailed status
+                status: process.status().into(),
                 disk_usage: process.disk_usage(),
+                open_handles: None,
             }
         }).collect();
         
         Ok(processes)
     }
     
+    /// 获取进程列表，并统计每个进程打开的文件描述符 / 句柄数量
+    /// 每个进程需要一次额外的系统调用，不需要句柄数时应使用 `get_processes`
+    ///
+    /// 权限要求：Linux 上只能统计同一用户的进程（root 除外）；macOS 上查询其他用户的进程需要 root；
+    /// Windows 上受保护进程和其他会话的系统进程需要管理员权限。无法统计时 `open_handles` 为 None
+    pub fn get_processes_with_handles(&self) -> SystemResult<Vec<ProcessInfo>> {
+        let mut processes = self.get_processes()?;
+        for process in &mut processes {
+            process.open_handles = handles::count_open_handles(process.pid);
+        }
+        Ok(processes)
+    }
+    
     /// 获取前N个占用内存最多的进程
     pub fn get_top_memory_processes(&self, limit: usize) -> SystemResult<Vec<ProcessInfo>> {
         let mut processes = self.get_processes()?;
@@ -314,6 +461,62 @@ impl SystemInfoManager {
         Ok(processes)
     }
     
+    /// 采样进程在 interval 时间内的磁盘读写速率
+    /// 速率按两次采样实际间隔的时间计算，而不是请求的 interval（sleep 和刷新本身都会多花时间）
+    pub fn get_process_io_rates(&self, pid: u32, interval: Duration) -> SystemResult<ProcessIoRates> {
+        let target = [Pid::from(pid as usize)];
+        let (started, before) = self.sample_disk_totals(ProcessesToUpdate::Some(&target))?;
+        std::thread::sleep(interval);
+        let (finished, after) = self.sample_disk_totals(ProcessesToUpdate::Some(&target))?;
+
+        let (Some(start), Some(end)) = (before.get(&pid), after.get(&pid)) else {
+            return Err(SystemError::NotFound(format!("Process not found: {}", pid)));
+        };
+        Ok(ProcessIoRates::between(pid, start, end, finished - started))
+    }
+
+    /// 获取前N个磁盘读写速率（读+写）最高的进程
+    pub fn get_top_io_processes(&self, limit: usize, interval: Duration) -> SystemResult<Vec<ProcessIoRates>> {
+        let (started, before) = self.sample_disk_totals(ProcessesToUpdate::All)?;
+        std::thread::sleep(interval);
+        let (finished, after) = self.sample_disk_totals(ProcessesToUpdate::All)?;
+
+        let elapsed = finished - started;
+        let mut rates: Vec<ProcessIoRates> = after.iter()
+            .filter_map(|(pid, end)| {
+                before.get(pid).map(|start| ProcessIoRates::between(*pid, start, end, elapsed))
+            })
+            .collect();
+
+        // 按总读写速率排序
+        rates.sort_by(|a, b| {
+            b.total_bytes_per_sec().partial_cmp(&a.total_bytes_per_sec())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        rates.truncate(limit);
+        Ok(rates)
+    }
+
+    /// 刷新磁盘使用数据并记录各进程的累计读写字节数，同时返回刷新完成的时间
+    fn sample_disk_totals(&self, processes: ProcessesToUpdate<'_>) -> SystemResult<(Instant, HashMap<u32, DiskSample>)> {
+        let mut system = self.system.lock().map_err(|_| {
+            SystemError::Internal("Failed to lock system info".to_string())
+        })?;
+
+        system.refresh_processes_specifics(processes, true, ProcessRefreshKind::nothing().with_disk_usage());
+        let sampled_at = Instant::now();
+
+        Ok((sampled_at, system.processes().iter().map(|(pid, process)| {
+            let usage = process.disk_usage();
+            (pid.as_u32(), DiskSample {
+                name: process.name().to_string_lossy().to_string(),
+                total_read_bytes: usage.total_read_bytes,
+                total_written_bytes: usage.total_written_bytes,
+            })
+        }).collect()))
+    }
+    
     /// 根据PID获取进程信息
     pub fn get_process_by_pid(&self, pid: u32) -> SystemResult<Option<ProcessInfo>> {
         self.smart_refresh()?;
@@ -341,14 +544,29 @@ impl SystemInfoManager {
                 start_time: Some(std::time::UNIX_EPOCH + std::time::Duration::from_secs(process.start_time())),
                 run_time: process.run_time(),
                 user: process.user_id().map(|uid| uid.to_string()),
-                status: ProcessStatus::Running,
+                status: process.status().into(),
                 disk_usage: process.disk_usage(),
+                open_handles: None,
             }))
         } else {
             Ok(None)
         }
     }
     
+    /// 仅刷新指定进程的内存和 CPU 数据（比全量刷新轻得多），返回进程是否仍存在
+    pub fn refresh_process(&self, pid: u32) -> SystemResult<bool> {
+        let mut system = self.system.lock().map_err(|_| {
+            SystemError::Internal("Failed to lock system info".to_string())
+        })?;
+        let pid = Pid::from(pid as usize);
+        system.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[pid]),
+            true,
+            ProcessRefreshKind::nothing().with_memory().with_cpu(),
+        );
+        Ok(system.process(pid).is_some())
+    }
+
     /// 根据进程名搜索进程
     pub fn find_processes_by_name(&self, name: &str) -> SystemResult<Vec<ProcessInfo>> {
         let processes = self.get_processes()?;
@@ -359,15 +577,88 @@ impl SystemInfoManager {
         
         Ok(matching_processes)
     }
+
+    /// 获取指定进程的直接子进程
+    pub fn get_process_children(&self, pid: u32) -> SystemResult<Vec<ProcessInfo>> {
+        let processes = self.get_processes()?;
+        Ok(processes
+            .into_iter()
+            .filter(|p| p.parent_pid == Some(pid) && p.pid != pid)
+            .collect())
+    }
+
+    /// 以 root_pid 为根构建进程树，所有节点来自同一次刷新的进程列表
+    /// PID 复用可能让某个进程看起来是自己的祖先，已出现在树中的 PID 不会被再次展开
+    pub fn get_process_tree(&self, root_pid: u32) -> SystemResult<ProcessTreeNode> {
+        let mut by_parent: HashMap<u32, Vec<ProcessInfo>> = HashMap::new();
+        let mut root = None;
+        for process in self.get_processes()? {
+            if process.pid == root_pid {
+                root = Some(process.clone());
+            }
+            if let Some(parent) = process.parent_pid.filter(|&parent| parent != process.pid) {
+                by_parent.entry(parent).or_default().push(process);
+            }
+        }
+
+        let root = root.ok_or_else(|| SystemError::NotFound(format!("Process not found: {}", root_pid)))?;
+        let mut visited = std::collections::HashSet::new();
+        Ok(ProcessTreeNode::build(root, &mut by_parent, &mut visited))
+    }
+
+    /// 统计名称包含 name（不区分大小写）的进程数量，不构造 ProcessInfo
+    pub fn count_processes_by_name(&self, name: &str) -> SystemResult<usize> {
+        self.smart_refresh()?;
+
+        let system = self.system.lock().map_err(|_| {
+            SystemError::Internal("Failed to lock system info".to_string())
+        })?;
+
+        let name = name.to_lowercase();
+        Ok(system.processes().values()
+            .filter(|process| Self::process_name_matches(process, &name))
+            .count())
+    }
+
+    /// 是否存在名称包含 name（不区分大小写）的进程，找到第一个即返回
+    pub fn any_process_by_name(&self, name: &str) -> SystemResult<bool> {
+        self.smart_refresh()?;
+
+        let system = self.system.lock().map_err(|_| {
+            SystemError::Internal("Failed to lock system info".to_string())
+        })?;
+
+        let name = name.to_lowercase();
+        Ok(system.processes().values().any(|process| Self::process_name_matches(process, &name)))
+    }
+
+    /// 与 find_processes_by_name 相同的匹配规则，name 需已转换为小写
+    fn process_name_matches(process: &sysinfo::Process, name: &str) -> bool {
+        process.name().to_string_lossy().to_lowercase().contains(name)
+    }
     
     /// 获取系统负载信息
+    /// Windows 没有原生负载值，使用处理器队列长度的后台采样模拟，仅为近似值
     pub fn get_load_average(&self) -> SystemResult<LoadAverage> {
-        let load_avg = System::load_average();
-        Ok(LoadAverage {
-            one_minute: load_avg.one,
-            five_minutes: load_avg.five,
-            fifteen_minutes: load_avg.fifteen,
-        })
+        #[cfg(target_os = "windows")]
+        {
+            let (one, five, fifteen) = windows_load::load_average();
+            Ok(LoadAverage {
+                one_minute: one,
+                five_minutes: five,
+                fifteen_minutes: fifteen,
+            })
+        }
+
+        #[cfg(not(target_os = "windows"))]
+        {
+            let load_avg = System::load_average();
+            Ok(LoadAverage {
+                one_minute: load_avg.one,
+                five_minutes: load_avg.five,
+                fifteen_minutes: load_avg.fifteen,
+            })
+        }
     }
     
     /// 获取系统性能摘要
@@ -377,36 +668,52 @@ impl SystemInfoManager {
         let load = self.get_load_average()?;
         let active_networks = self.get_active_network_info()?;
         
-        // 计算平均CPU使用率
-        let avg_cpu_usage = if !cpus.is_empty() {
-            cpus.iter().map(|cpu| cpu.usage).sum::<f32>() / cpus.len() as f32
-        } else {
-            0.0
-        };
-        
-        // 计算总网络流量
-        let total_network_rx = active_networks.iter().map(|n| n.bytes_received).sum();
-        let total_network_tx = active_networks.iter().map(|n| n.bytes_transmitted).sum();
-        
-        Ok(PerformanceSummary {
-            cpu_usage_percent: avg_cpu_usage,
-            memory_usage_percent: memory.usage_percent,
-            memory_total: memory.total,
-            memory_used: memory.used,
-            cpu_core_count: cpus.len(),
-            load_average_1min: load.one_minute,
-            active_network_interfaces: active_networks.len(),
-            total_network_received: total_network_rx,
-            total_network_transmitted: total_network_tx,
-            uptime: System::uptime(),
+        Ok(PerformanceSummary::from_parts(&memory, &cpus, &load, &active_networks))
+    }
+
+    /// 获取完整的系统快照
+    /// 与 `refresh_all_coordinated` 相同，按规定顺序持有全部状态锁并刷新，且在释放锁之前读取内存、CPU、
+    /// 磁盘和网络数据，其他线程的 `smart_refresh` 等刷新无法插入其间，这些字段来自同一次刷新。
+    /// 基本信息和系统负载直接读取操作系统，不经过这些状态。CPU 使用率是本次与上一次刷新之间的差值，
+    /// 两次刷新间隔不足 `sysinfo::MINIMUM_CPU_UPDATE_INTERVAL` 时可能不准确
+    pub fn snapshot(&self) -> SystemResult<SystemSnapshot> {
+        // 无法确定当前用户时基本信息获取失败，此时该字段为 None
+        let basic = self.get_basic_info().ok();
+        let load = self.get_load_average()?;
+
+        let (memory, cpus, disks, networks) = self.refresh_and_read(|system, disks, networks| {
+            let networks: Vec<NetworkInterfaceInfo> = Self::collect_network_info(networks)
+                .into_iter()
+                .filter(NetworkInterfaceInfo::has_activity)
+                .collect();
+            (Self::collect_memory_info(system), Self::collect_cpu_info(system), Self::collect_disk_info(disks), networks)
+        })?;
+        let performance = PerformanceSummary::from_parts(&memory, &cpus, &load, &networks);
+
+        Ok(SystemSnapshot {
+            timestamp: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|elapsed| elapsed.as_secs())
+                .unwrap_or(0),
+            basic,
+            memory,
+            cpus,
+            disks,
+            networks,
+            performance,
         })
     }
     
-    /// 获取用户列表
+    /// 获取本地用户列表，不包括系统账户
     pub fn get_users(&self) -> SystemResult<Vec<UserInfo>> {
-        // sysinfo 0.36 中 users() 方法可能已经移除或改变
-        // 暂时返回空列表，或者使用其他方式获取用户信息
-        Ok(vec![])
+        self.get_users_filtered(false)
+    }
+
+    /// 获取本地用户列表，include_system 为 true 时同时返回系统账户
+    /// 系统账户的判断：Linux 上 UID < 1000 或 nobody，macOS 上 UID < 501 或以 `_` 开头，
+    /// Windows 上 RID < 1000 的内置账户（Administrator、Guest 等）
+    pub fn get_users_filtered(&self, include_system: bool) -> SystemResult<Vec<UserInfo>> {
+        users::list_users(include_system)
     }
     
     /// 检查是否有管理员权限
@@ -421,12 +728,18 @@ impl SystemInfoManager {
     
     /// 获取刷新间隔
     pub fn get_refresh_interval(&self) -> Duration {
-        self.refresh_interval
+        match self.refresh_interval.lock() {
+            Ok(interval) => *interval,
+            Err(poisoned) => *poisoned.into_inner(),
+        }
     }
     
-    /// 设置刷新间隔
-    pub fn set_refresh_interval(&mut self, interval: Duration) {
-        self.refresh_interval = interval;
+    /// 设置刷新间隔，可以通过共享的 `Arc<SystemInfoManager>` 在运行时调整
+    pub fn set_refresh_interval(&self, interval: Duration) {
+        match self.refresh_interval.lock() {
+            Ok(mut current) => *current = interval,
+            Err(poisoned) => *poisoned.into_inner() = interval,
+        }
     }
     
     /// 获取上次刷新时间距现在的时长
@@ -447,6 +760,7 @@ impl Default for SystemInfoManager {
 
 /// 基本系统信息
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BasicSystemInfo {
     pub os_name: String,         // 操作系统名称
     pub os_version: String,      // 操作系统版本
@@ -461,6 +775,7 @@ pub struct BasicSystemInfo {
 
 /// 内存信息
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryInfo {
     pub total: u64,             // 总内存（字节）
     pub used: u64,              // 已使用内存（字节）
@@ -472,6 +787,7 @@ pub struct MemoryInfo {
 
 /// CPU信息
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CpuInfo {
     pub name: String,           // CPU名称
     pub brand: String,          // CPU品牌
@@ -483,6 +799,7 @@ pub struct CpuInfo {
 
 /// 磁盘信息
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DiskInfo {
     pub name: String,           // 磁盘名称
     pub mount_point: String,    // 挂载点
@@ -494,8 +811,48 @@ pub struct DiskInfo {
     pub disk_type: String,      // 磁盘类型
 }
 
+/// 磁盘读写速率
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DiskIoStats {
+    pub device: String,             // 设备名（Linux 如 sda，macOS 如 disk0，Windows 如 "0 C:"）
+    pub read_bytes_per_sec: f64,    // 每秒读取字节数
+    pub write_bytes_per_sec: f64,   // 每秒写入字节数
+}
+
+/// 温度传感器信息
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SensorInfo {
+    pub label: String,              // 传感器名称
+    pub temperature: Option<f32>,   // 当前温度（摄氏度）
+    pub max: Option<f32>,           // 运行以来的最高温度（摄氏度）
+    pub critical: Option<f32>,      // 临界温度（摄氏度）
+}
+
+/// 电源状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PowerState {
+    Charging,       // 充电中
+    Discharging,    // 使用电池供电
+    Full,           // 已充满
+    NotCharging,    // 接通电源但未充电（例如设置了充电阈值）
+    Unknown,
+}
+
+/// 电池信息
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BatteryInfo {
+    pub percentage: f32,                    // 电量百分比
+    pub state: PowerState,                  // 电源状态
+    pub time_remaining: Option<std::time::Duration>, // 放电时为剩余续航，充电时为充满所需时间
+}
+
 /// 网络接口信息
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetworkInterfaceInfo {
     pub name: String,                    // 接口名称
     pub bytes_received: u64,             // 接收字节数
@@ -507,8 +864,19 @@ pub struct NetworkInterfaceInfo {
     pub mac_address: String,             // MAC地址
 }
 
+impl NetworkInterfaceInfo {
+    /// 是否有过收发流量
+    fn has_activity(&self) -> bool {
+        self.bytes_received > 0 ||
+        self.bytes_transmitted > 0 ||
+        self.packets_received > 0 ||
+        self.packets_transmitted > 0
+    }
+}
+
 /// 进程信息（扩展版本）
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ProcessInfo {
     pub pid: u32,
     pub name: String,
@@ -518,18 +886,136 @@ pub struct ProcessInfo {
     pub memory_usage: Option<u64>,       // 物理内存使用（字节）
     pub virtual_memory: Option<u64>,     // 虚拟内存使用（字节）
     pub cpu_usage: Option<f32>,          // CPU使用率百分比
+    #[cfg_attr(feature = "serde", serde(with = "serde_support::unix_time"))]
     pub start_time: Option<std::time::SystemTime>,
     pub run_time: u64,                   // 运行时间（秒）
     pub user: Option<String>,
     pub status: ProcessStatus,
+    #[cfg_attr(feature = "serde", serde(with = "serde_support::disk_usage"))]
     pub disk_usage: DiskUsage,           // 磁盘使用情况
+    /// 打开的文件描述符（Windows 上为句柄）数量，只由 `get_processes_with_handles` 填充
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub open_handles: Option<u64>,
+}
+
+/// 进程树节点
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProcessTreeNode {
+    pub process: ProcessInfo,
+    pub children: Vec<ProcessTreeNode>,
+}
+
+impl ProcessTreeNode {
+    /// 递归构建子树；每个 PID 只展开一次，从而保证在出现环时也能结束
+    fn build(
+        process: ProcessInfo,
+        by_parent: &mut HashMap<u32, Vec<ProcessInfo>>,
+        visited: &mut std::collections::HashSet<u32>,
+    ) -> Self {
+        visited.insert(process.pid);
+        let children = by_parent
+            .remove(&process.pid)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|child| {
+                (!visited.contains(&child.pid)).then(|| Self::build(child, by_parent, visited))
+            })
+            .collect();
+        Self { process, children }
+    }
+
+    /// 树中的进程总数（包括根节点）
+    pub fn count(&self) -> usize {
+        1 + self.children.iter().map(Self::count).sum::<usize>()
+    }
+
+    /// 在子树中按 PID 查找节点
+    pub fn find(&self, pid: u32) -> Option<&ProcessTreeNode> {
+        if self.process.pid == pid {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.find(pid))
+    }
 }
 
 // 导入 ProcessStatus
 use crate::system::common::types::ProcessStatus;
 
+impl From<sysinfo::ProcessStatus> for ProcessStatus {
+    fn from(status: sysinfo::ProcessStatus) -> Self {
+        match status {
+            sysinfo::ProcessStatus::Run | sysinfo::ProcessStatus::Waking => ProcessStatus::Running,
+            sysinfo::ProcessStatus::Sleep
+            | sysinfo::ProcessStatus::Parked
+            | sysinfo::ProcessStatus::LockBlocked
+            | sysinfo::ProcessStatus::Wakekill => ProcessStatus::Sleeping,
+            sysinfo::ProcessStatus::UninterruptibleDiskSleep => ProcessStatus::DiskSleep,
+            sysinfo::ProcessStatus::Idle => ProcessStatus::Idle,
+            sysinfo::ProcessStatus::Stop => ProcessStatus::Stopped,
+            sysinfo::ProcessStatus::Tracing => ProcessStatus::Tracing,
+            sysinfo::ProcessStatus::Zombie => ProcessStatus::Zombie,
+            sysinfo::ProcessStatus::Dead => ProcessStatus::Dead,
+            sysinfo::ProcessStatus::Unknown(_) => ProcessStatus::Unknown,
+        }
+    }
+}
+
+impl From<ProcessInfo> for crate::system::common::types::ProcessInfo {
+    fn from(process: ProcessInfo) -> Self {
+        Self {
+            pid: process.pid,
+            name: process.name,
+            executable_path: process.executable_path,
+            command_line: process.command_line,
+            parent_pid: process.parent_pid,
+            memory_usage: process.memory_usage,
+            cpu_usage: process.cpu_usage,
+            start_time: process.start_time,
+            user: process.user,
+            status: process.status,
+        }
+    }
+}
+
+/// 进程磁盘读写速率
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProcessIoRates {
+    pub pid: u32,
+    pub name: String,
+    pub read_bytes_per_sec: f64,    // 每秒读取字节数
+    pub write_bytes_per_sec: f64,   // 每秒写入字节数
+}
+
+impl ProcessIoRates {
+    /// 读写速率之和
+    pub fn total_bytes_per_sec(&self) -> f64 {
+        self.read_bytes_per_sec + self.write_bytes_per_sec
+    }
+
+    fn between(pid: u32, start: &DiskSample, end: &DiskSample, elapsed: Duration) -> Self {
+        let seconds = elapsed.as_secs_f64().max(f64::EPSILON);
+        Self {
+            pid,
+            name: end.name.clone(),
+            read_bytes_per_sec: end.total_read_bytes.saturating_sub(start.total_read_bytes) as f64 / seconds,
+            write_bytes_per_sec: end.total_written_bytes.saturating_sub(start.total_written_bytes) as f64 / seconds,
+        }
+    }
+}
+
+/// 单次采样的进程累计磁盘读写量
+struct DiskSample {
+    name: String,
+    total_read_bytes: u64,
+    total_written_bytes: u64,
+}
+
 /// 系统负载平均值
+/// 在 Windows 上由后台采样线程根据处理器队列长度模拟，首次查询后需要几分钟才能趋于稳定
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LoadAverage {
     pub one_minute: f64,        // 1分钟负载
     pub five_minutes: f64,      // 5分钟负载
@@ -538,6 +1024,7 @@ pub struct LoadAverage {
 
 /// 用户信息
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct UserInfo {
     pub name: String,           // 用户名
     pub groups: Vec<String>,    // 所属组
@@ -545,6 +1032,7 @@ pub struct UserInfo {
 
 /// 系统性能摘要
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PerformanceSummary {
     pub cpu_usage_percent: f32,
     pub memory_usage_percent: f32,
@@ -556,4 +1044,324 @@ pub struct PerformanceSummary {
     pub total_network_received: u64,
     pub total_network_transmitted: u64,
     pub uptime: u64,
-} 
\ No newline at end of file
+}
+
+impl PerformanceSummary {
+    fn from_parts(memory: &MemoryInfo, cpus: &[CpuInfo], load: &LoadAverage, active_networks: &[NetworkInterfaceInfo]) -> Self {
+        // 计算平均CPU使用率
+        let avg_cpu_usage = if !cpus.is_empty() {
+            cpus.iter().map(|cpu| cpu.usage).sum::<f32>() / cpus.len() as f32
+        } else {
+            0.0
+        };
+        
+        // 计算总网络流量
+        let total_network_rx = active_networks.iter().map(|n| n.bytes_received).sum();
+        let total_network_tx = active_networks.iter().map(|n| n.bytes_transmitted).sum();
+        
+        Self {
+            cpu_usage_percent: avg_cpu_usage,
+            memory_usage_percent: memory.usage_percent,
+            memory_total: memory.total,
+            memory_used: memory.used,
+            cpu_core_count: cpus.len(),
+            load_average_1min: load.one_minute,
+            active_network_interfaces: active_networks.len(),
+            total_network_received: total_network_rx,
+            total_network_transmitted: total_network_tx,
+            uptime: System::uptime(),
+        }
+    }
+}
+
+/// 完整的系统快照，由 `SystemInfoManager::snapshot` 在一次刷新后生成
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SystemSnapshot {
+    pub timestamp: u64,                          // 生成时间（Unix时间戳，秒）
+    pub basic: Option<BasicSystemInfo>,          // 基本系统信息，无法确定当前用户时为 None
+    pub memory: MemoryInfo,
+    pub cpus: Vec<CpuInfo>,
+    pub disks: Vec<DiskInfo>,
+    pub networks: Vec<NetworkInterfaceInfo>,     // 仅包含有流量的接口
+    pub performance: PerformanceSummary,
+}
+
+impl SystemSnapshot {
+    /// 序列化为 JSON 字符串（需要开启 serde feature）
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> SystemResult<String> {
+        serde_json::to_string(self).map_err(|e| SystemError::Parse(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(unix)]
+    use std::process::Command;
+    use std::sync::mpsc;
+    use std::thread;
+
+    #[cfg(unix)]
+    fn status_of(manager: &SystemInfoManager, pid: u32) -> Option<ProcessStatus> {
+        manager.refresh();
+        manager.get_process_by_pid(pid).unwrap().map(|process| process.status)
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_child_process_status() {
+        let mut child = Command::new("sleep").arg("30").spawn().expect("failed to spawn sleep");
+        let pid = child.id();
+        let manager = SystemInfoManager::new();
+
+        // 状态变化由内核异步完成，这里短暂轮询
+        let wait_for = |expected: ProcessStatus| {
+            (0..50).any(|_| {
+                if status_of(&manager, pid) == Some(expected.clone()) {
+                    return true;
+                }
+                std::thread::sleep(Duration::from_millis(20));
+                false
+            })
+        };
+
+        unsafe { libc::kill(pid as libc::pid_t, libc::SIGSTOP) };
+        assert!(wait_for(ProcessStatus::Stopped), "stopped child not detected");
+
+        // 终止但不回收，子进程会停留在僵尸状态
+        unsafe { libc::kill(pid as libc::pid_t, libc::SIGKILL) };
+        assert!(wait_for(ProcessStatus::Zombie), "zombie child not detected");
+
+        child.wait().unwrap();
+    }
+
+    #[test]
+    fn test_set_refresh_interval_through_arc() {
+        let manager = Arc::new(SystemInfoManager::with_refresh_interval(Duration::MAX));
+        assert!(!manager.should_refresh());
+
+        let shared = Arc::clone(&manager);
+        thread::spawn(move || shared.set_refresh_interval(Duration::ZERO)).join().unwrap();
+        assert_eq!(manager.get_refresh_interval(), Duration::ZERO);
+        assert!(manager.should_refresh());
+    }
+
+    #[test]
+    fn test_cpu_info_sampled() {
+        let manager = SystemInfoManager::new();
+        // 采样期间保持一个核心忙碌，保证使用率大于 0
+        let stop = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let busy = {
+            let stop = Arc::clone(&stop);
+            thread::spawn(move || while !stop.load(std::sync::atomic::Ordering::Relaxed) {})
+        };
+
+        let cpus = manager.get_cpu_info_sampled(Duration::from_millis(100)).unwrap();
+        stop.store(true, std::sync::atomic::Ordering::Relaxed);
+        busy.join().unwrap();
+
+        assert!(!cpus.is_empty());
+        assert!(cpus.iter().any(|cpu| cpu.usage > 0.0));
+        assert!(cpus.iter().all(|cpu| (0.0..=100.0).contains(&cpu.usage)));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_disk_io_first_call_is_zero() {
+        let manager = SystemInfoManager::new();
+        let first = manager.get_disk_io().unwrap();
+        assert!(first.iter().all(|disk| disk.read_bytes_per_sec == 0.0 && disk.write_bytes_per_sec == 0.0));
+
+        std::thread::sleep(Duration::from_millis(10));
+        let second = manager.get_disk_io().unwrap();
+        assert_eq!(second.len(), first.len());
+        assert!(second.iter().all(|disk| disk.read_bytes_per_sec >= 0.0 && disk.write_bytes_per_sec >= 0.0));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_process_io_rates_of_self() {
+        // tmpfs 上的写入不计入块设备写入量，写到测试程序所在的目录
+        let path = std::env::current_exe().unwrap().with_file_name(format!("lycrex_io_rate_{}", std::process::id()));
+        let writer_path = path.clone();
+        let writer = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            let mut file = std::fs::File::create(&writer_path).unwrap();
+            std::io::Write::write_all(&mut file, &vec![0x5a; 4 * 1024 * 1024]).unwrap();
+            file.sync_all().unwrap();
+        });
+
+        let manager = SystemInfoManager::new();
+        let rates = manager.get_process_io_rates(std::process::id(), Duration::from_millis(500)).unwrap();
+        writer.join().unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(rates.pid, std::process::id());
+        assert!(rates.write_bytes_per_sec > 0.0, "no writes observed: {:?}", rates);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_process_tree() {
+        let mut child = Command::new("sleep").arg("30").spawn().expect("failed to spawn sleep");
+        let manager = SystemInfoManager::new();
+        let pid = std::process::id();
+
+        let children = manager.get_process_children(pid).unwrap();
+        assert!(children.iter().any(|p| p.pid == child.id()));
+        let tree = manager.get_process_tree(pid).unwrap();
+        assert_eq!(tree.process.pid, pid);
+        assert!(tree.find(child.id()).is_some());
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        assert!(matches!(manager.get_process_tree(u32::MAX), Err(SystemError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_process_tree_cycle() {
+        let process = |pid: u32, parent: u32| ProcessInfo {
+            pid,
+            name: format!("p{}", pid),
+            executable_path: None,
+            command_line: None,
+            parent_pid: Some(parent),
+            memory_usage: None,
+            virtual_memory: None,
+            cpu_usage: None,
+            start_time: None,
+            run_time: 0,
+            user: None,
+            status: ProcessStatus::Running,
+            disk_usage: DiskUsage::default(),
+            open_handles: None,
+        };
+        // 1 -> 2 -> 3 -> 1，PID 复用形成环
+        let mut by_parent = HashMap::new();
+        by_parent.insert(1, vec![process(2, 1)]);
+        by_parent.insert(2, vec![process(3, 2)]);
+        by_parent.insert(3, vec![process(1, 3)]);
+
+        let tree = ProcessTreeNode::build(process(1, 3), &mut by_parent, &mut std::collections::HashSet::new());
+        assert_eq!(tree.count(), 3);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "macos", target_os = "windows"))]
+    #[test]
+    fn test_processes_with_handles() {
+        let manager = SystemInfoManager::new();
+        let pid = std::process::id();
+        let current = manager.get_processes_with_handles().unwrap().into_iter().find(|p| p.pid == pid).unwrap();
+        // 至少有标准输入输出
+        assert!(current.open_handles.unwrap() > 0);
+
+        let plain = manager.get_process_by_pid(pid).unwrap().unwrap();
+        assert_eq!(plain.open_handles, None);
+    }
+
+    #[test]
+    fn test_concurrent_access_does_not_deadlock() {
+        let manager = Arc::new(SystemInfoManager::new());
+        let (done_tx, done_rx) = mpsc::channel();
+
+        let workers = 8;
+        for worker in 0..workers {
+            let manager = manager.clone();
+            let done_tx = done_tx.clone();
+            thread::spawn(move || {
+                for i in 0..5 {
+                    match (worker + i) % 5 {
+                        0 => manager.refresh(),
+                        1 => manager.refresh_all_coordinated().unwrap(),
+                        2 => { manager.get_memory_info().unwrap(); }
+                        3 => { manager.get_disk_info().unwrap(); }
+                        _ => { manager.get_network_info().unwrap(); }
+                    }
+                }
+                done_tx.send(()).unwrap();
+            });
+        }
+
+        // 发生死锁时线程永远不会完成，用超时代替无限等待
+        for _ in 0..workers {
+            done_rx
+                .recv_timeout(Duration::from_secs(120))
+                .expect("worker did not finish, possible deadlock");
+        }
+    }
+
+    #[test]
+    fn test_snapshot_is_consistent() {
+        let manager = SystemInfoManager::new();
+        let snapshot = manager.snapshot().unwrap();
+        assert!(snapshot.timestamp > 0);
+        assert!(!snapshot.cpus.is_empty());
+        assert_eq!(snapshot.performance.cpu_core_count, snapshot.cpus.len());
+        assert_eq!(snapshot.performance.memory_total, snapshot.memory.total);
+        assert_eq!(snapshot.performance.memory_used, snapshot.memory.used);
+        assert_eq!(snapshot.performance.active_network_interfaces, snapshot.networks.len());
+        assert!(snapshot.networks.iter().all(NetworkInterfaceInfo::has_activity));
+
+        #[cfg(feature = "serde")]
+        {
+            let value: serde_json::Value = serde_json::from_str(&snapshot.to_json().unwrap()).unwrap();
+            assert_eq!(value["memory"]["total"], snapshot.memory.total);
+            assert_eq!(value["cpus"].as_array().unwrap().len(), snapshot.cpus.len());
+        }
+    }
+
+    #[test]
+    fn test_snapshot_during_concurrent_refresh() {
+        let manager = Arc::new(SystemInfoManager::new());
+        let stop = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let refresher = {
+            let (manager, stop) = (manager.clone(), stop.clone());
+            thread::spawn(move || {
+                while !stop.load(std::sync::atomic::Ordering::Relaxed) {
+                    manager.refresh();
+                }
+            })
+        };
+
+        for _ in 0..3 {
+            let snapshot = manager.snapshot().unwrap();
+            assert_eq!(snapshot.performance.memory_used, snapshot.memory.used);
+            assert_eq!(snapshot.performance.active_network_interfaces, snapshot.networks.len());
+        }
+        stop.store(true, std::sync::atomic::Ordering::Relaxed);
+        refresher.join().unwrap();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_process_info_serde_round_trip() {
+        let process = ProcessInfo {
+            pid: 42,
+            name: "worker".to_string(),
+            executable_path: None,
+            command_line: Some("worker --once".to_string()),
+            parent_pid: Some(1),
+            memory_usage: Some(4096),
+            virtual_memory: Some(8192),
+            cpu_usage: Some(1.5),
+            start_time: Some(std::time::UNIX_EPOCH + Duration::from_secs(1_723_238_400)),
+            run_time: 10,
+            user: None,
+            status: ProcessStatus::Sleeping,
+            disk_usage: DiskUsage { total_read_bytes: 100, total_written_bytes: 200, read_bytes: 1, written_bytes: 2 },
+            open_handles: Some(12),
+        };
+
+        let value = serde_json::to_value(&process).unwrap();
+        assert_eq!(value["start_time"], 1_723_238_400u64);
+        assert_eq!(value["disk_usage"], serde_json::json!({ "total_read_bytes": 100, "total_written_bytes": 200 }));
+
+        let decoded: ProcessInfo = serde_json::from_value(value).unwrap();
+        assert_eq!(decoded.start_time, process.start_time);
+        assert_eq!(decoded.disk_usage.total_written_bytes, 200);
+        assert_eq!(decoded.status, ProcessStatus::Sleeping);
+        assert_eq!(decoded.open_handles, Some(12));
+    }
+}
@@ -0,0 +1,48 @@
+// serde 序列化辅助
+// SystemTime 按 Unix 时间戳（秒）输出，sysinfo 的 DiskUsage 只输出累计读写字节数两个字段
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// `Option<SystemTime>` <-> `Option<u64>`（Unix 时间戳，秒）
+pub(crate) mod unix_time {
+    use super::*;
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    pub(crate) fn serialize<S: Serializer>(time: &Option<SystemTime>, serializer: S) -> Result<S::Ok, S::Error> {
+        time.map(|time| time.duration_since(UNIX_EPOCH).map(|elapsed| elapsed.as_secs()).unwrap_or(0))
+            .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<SystemTime>, D::Error> {
+        Ok(Option::<u64>::deserialize(deserializer)?.map(|secs| UNIX_EPOCH + Duration::from_secs(secs)))
+    }
+}
+
+/// `sysinfo::DiskUsage` <-> `{ total_read_bytes, total_written_bytes }`
+pub(crate) mod disk_usage {
+    use super::*;
+    use sysinfo::DiskUsage;
+
+    #[derive(Serialize, Deserialize)]
+    struct DiskUsageFields {
+        total_read_bytes: u64,
+        total_written_bytes: u64,
+    }
+
+    pub(crate) fn serialize<S: Serializer>(usage: &DiskUsage, serializer: S) -> Result<S::Ok, S::Error> {
+        DiskUsageFields {
+            total_read_bytes: usage.total_read_bytes,
+            total_written_bytes: usage.total_written_bytes,
+        }
+        .serialize(serializer)
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<DiskUsage, D::Error> {
+        let fields = DiskUsageFields::deserialize(deserializer)?;
+        Ok(DiskUsage {
+            total_read_bytes: fields.total_read_bytes,
+            total_written_bytes: fields.total_written_bytes,
+            ..DiskUsage::default()
+        })
+    }
+}
@@ -0,0 +1,236 @@
+// 本地用户列表
+// Linux 解析 /etc/passwd 和 /etc/group，macOS 通过 dscl 查询本地目录服务，Windows 使用 NetUserEnum
+// 系统账户按各平台的惯例以 UID / RID 阈值区分
+
+use super::UserInfo;
+use crate::system::common::error::SystemResult;
+
+pub(super) fn list_users(include_system: bool) -> SystemResult<Vec<UserInfo>> {
+    platform::list_users(include_system)
+}
+
+#[cfg(target_os = "linux")]
+mod platform {
+    use std::collections::HashMap;
+
+    use super::UserInfo;
+    use crate::system::common::error::SystemResult;
+
+    /// 与多数发行版 /etc/login.defs 中的 UID_MIN 默认值一致
+    const UID_MIN: u32 = 1000;
+    /// nobody 的 UID 高于阈值，但属于系统账户
+    const NOBODY_UID: u32 = 65534;
+
+    pub fn list_users(include_system: bool) -> SystemResult<Vec<UserInfo>> {
+        let passwd = std::fs::read_to_string("/etc/passwd")?;
+        // 没有 /etc/group 时（例如精简容器）仍返回用户，只是没有组信息
+        let group = std::fs::read_to_string("/etc/group").unwrap_or_default();
+        Ok(parse_users(&passwd, &group, include_system))
+    }
+
+    /// passwd 每行为 name:password:uid:gid:gecos:home:shell，group 每行为 name:password:gid:members
+    /// 用户的组包括主组（passwd 中的 gid）和在 group 成员列表中出现的附加组
+    pub fn parse_users(passwd: &str, group: &str, include_system: bool) -> Vec<UserInfo> {
+        let mut group_names: HashMap<u32, &str> = HashMap::new();
+        let mut memberships: HashMap<&str, Vec<&str>> = HashMap::new();
+        for line in group.lines().filter(|line| !line.starts_with('#')) {
+            let fields: Vec<&str> = line.split(':').collect();
+            let (Some(&name), Some(gid)) = (fields.first(), fields.get(2).and_then(|gid| gid.parse().ok())) else {
+                continue;
+            };
+            group_names.insert(gid, name);
+            for member in fields.get(3).into_iter().flat_map(|members| members.split(',')).filter(|m| !m.is_empty()) {
+                memberships.entry(member).or_default().push(name);
+            }
+        }
+
+        passwd
+            .lines()
+            .filter(|line| !line.starts_with('#'))
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.split(':').collect();
+                let name = *fields.first()?;
+                let uid: u32 = fields.get(2)?.parse().ok()?;
+                let gid: Option<u32> = fields.get(3).and_then(|gid| gid.parse().ok());
+                if name.is_empty() || (!include_system && (uid < UID_MIN || uid == NOBODY_UID)) {
+                    return None;
+                }
+
+                let mut groups: Vec<String> = Vec::new();
+                let primary = gid.and_then(|gid| group_names.get(&gid));
+                for group in primary.into_iter().chain(memberships.get(name).into_iter().flatten()) {
+                    if !groups.iter().any(|g| g == group) {
+                        groups.push(group.to_string());
+                    }
+                }
+                Some(UserInfo { name: name.to_string(), groups })
+            })
+            .collect()
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use std::process::Command;
+
+    use super::UserInfo;
+    use crate::system::common::error::{SystemError, SystemResult};
+
+    /// macOS 普通用户的 UID 从 501 开始，系统账户（多以 `_` 开头）低于该值
+    const UID_MIN: u32 = 501;
+
+    pub fn list_users(include_system: bool) -> SystemResult<Vec<UserInfo>> {
+        let output = Command::new("dscl").args([".", "-list", "/Users", "UniqueID"]).output()?;
+        if !output.status.success() {
+            return Err(SystemError::SystemCall(
+                format!("dscl failed: {}", String::from_utf8_lossy(&output.stderr).trim()),
+                output.status.code(),
+            ));
+        }
+
+        // 每行为 "name   uid"
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(stdout
+            .lines()
+            .filter_map(|line| {
+                let mut parts = line.split_whitespace();
+                let name = parts.next()?;
+                let uid: i64 = parts.next()?.parse().ok()?;
+                if !include_system && (uid < UID_MIN as i64 || name.starts_with('_')) {
+                    return None;
+                }
+                Some(UserInfo { name: name.to_string(), groups: groups_of(name) })
+            })
+            .collect())
+    }
+
+    /// 通过 `id -Gn` 获取用户所属的全部组
+    fn groups_of(name: &str) -> Vec<String> {
+        match Command::new("id").args(["-Gn", name]).output() {
+            Ok(output) if output.status.success() => {
+                String::from_utf8_lossy(&output.stdout).split_whitespace().map(str::to_string).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+mod platform {
+    use windows::core::PCWSTR;
+    use windows::Win32::NetworkManagement::NetManagement::{
+        NetApiBufferFree, NetUserEnum, NetUserGetLocalGroups, FILTER_NORMAL_ACCOUNT, LG_INCLUDE_INDIRECT,
+        LOCALGROUP_USERS_INFO_0, MAX_PREFERRED_LENGTH, USER_INFO_3,
+    };
+
+    use super::UserInfo;
+    use crate::system::common::error::{SystemError, SystemResult};
+
+    /// 内置账户（Administrator、Guest、DefaultAccount 等）的 RID 小于 1000
+    const RID_MIN: u32 = 1000;
+    const NERR_SUCCESS: u32 = 0;
+    const ERROR_MORE_DATA: u32 = 234;
+
+    pub fn list_users(include_system: bool) -> SystemResult<Vec<UserInfo>> {
+        let mut users = Vec::new();
+        let mut resume = 0u32;
+
+        loop {
+            let mut buffer: *mut u8 = std::ptr::null_mut();
+            let mut read = 0u32;
+            let mut total = 0u32;
+            let status = unsafe {
+                NetUserEnum(
+                    PCWSTR::null(),
+                    3,
+                    FILTER_NORMAL_ACCOUNT,
+                    &mut buffer,
+                    MAX_PREFERRED_LENGTH,
+                    &mut read,
+                    &mut total,
+                    Some(&mut resume),
+                )
+            };
+            if status != NERR_SUCCESS && status != ERROR_MORE_DATA {
+                return Err(SystemError::SystemCall(format!("NetUserEnum failed: {}", status), Some(status as i32)));
+            }
+
+            if !buffer.is_null() {
+                let entries = unsafe { std::slice::from_raw_parts(buffer as *const USER_INFO_3, read as usize) };
+                for entry in entries {
+                    if !include_system && entry.usri3_user_id < RID_MIN {
+                        continue;
+                    }
+                    let Ok(name) = (unsafe { entry.usri3_name.to_string() }) else { continue };
+                    users.push(UserInfo { groups: local_groups(entry.usri3_name.0), name });
+                }
+                unsafe { NetApiBufferFree(Some(buffer as *const _)) };
+            }
+
+            if status != ERROR_MORE_DATA {
+                break;
+            }
+        }
+
+        Ok(users)
+    }
+
+    /// 用户所属的本地组（包括通过全局组间接加入的组）
+    fn local_groups(name: *mut u16) -> Vec<String> {
+        let mut buffer: *mut u8 = std::ptr::null_mut();
+        let mut read = 0u32;
+        let mut total = 0u32;
+        let status = unsafe {
+            NetUserGetLocalGroups(
+                PCWSTR::null(),
+                PCWSTR::from_raw(name),
+                0,
+                LG_INCLUDE_INDIRECT,
+                &mut buffer,
+                MAX_PREFERRED_LENGTH,
+                &mut read,
+                &mut total,
+            )
+        };
+        if status != NERR_SUCCESS || buffer.is_null() {
+            return Vec::new();
+        }
+
+        let entries = unsafe { std::slice::from_raw_parts(buffer as *const LOCALGROUP_USERS_INFO_0, read as usize) };
+        let groups = entries.iter().filter_map(|entry| unsafe { entry.lgrui0_name.to_string() }.ok()).collect();
+        unsafe { NetApiBufferFree(Some(buffer as *const _)) };
+        groups
+    }
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+mod platform {
+    use super::UserInfo;
+    use crate::system::common::error::{SystemError, SystemResult};
+
+    pub fn list_users(_include_system: bool) -> SystemResult<Vec<UserInfo>> {
+        Err(SystemError::NotSupported("User enumeration is not supported on this platform".to_string()))
+    }
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    use super::platform::parse_users;
+
+    #[test]
+    fn test_parse_users() {
+        let passwd = "root:x:0:0:root:/root:/bin/bash\n\
+                      nobody:x:65534:65534:nobody:/nonexistent:/usr/sbin/nologin\n\
+                      alice:x:1000:1000:Alice:/home/alice:/bin/bash\n\
+                      bob:x:1001:100::/home/bob:/bin/sh\n";
+        let group = "root:x:0:\nusers:x:100:\nalice:x:1000:\nsudo:x:27:alice\ndocker:x:998:alice,bob\n";
+
+        let users = parse_users(passwd, group, false);
+        let names: Vec<&str> = users.iter().map(|u| u.name.as_str()).collect();
+        assert_eq!(names, ["alice", "bob"]);
+        assert_eq!(users[0].groups, ["alice", "sudo", "docker"]);
+        assert_eq!(users[1].groups, ["users", "docker"]);
+
+        assert_eq!(parse_users(passwd, group, true).len(), 4);
+    }
+}
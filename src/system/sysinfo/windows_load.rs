@@ -0,0 +1,138 @@
+// Windows 负载平均值模拟
+// Windows 没有原生的 load average，这里通过后台采样线程读取性能计数器
+// 用 "\System\Processor Queue Length"（等待CPU的线程数）加上正在运行的估算值（CPU占用率 × 核心数）
+// 按 Unix 内核相同的指数衰减方式计算 1/5/15 分钟平均值
+// 注意：这只是一个近似值，与 Unix 的 load average 含义相近但并不完全等价
+
+use std::sync::{Arc, Mutex, OnceLock};
+use std::thread;
+use std::time::Duration;
+
+use windows::core::w;
+use windows::Win32::System::Performance::{
+    PdhAddEnglishCounterW, PdhCloseQuery, PdhCollectQueryData, PdhGetFormattedCounterValue, PdhOpenQueryW,
+    PDH_FMT_COUNTERVALUE, PDH_FMT_DOUBLE, PDH_HCOUNTER, PDH_HQUERY,
+};
+
+/// 采样间隔（与 Linux 内核一致为5秒）
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 采样器维护的负载值 (1, 5, 15分钟)
+#[derive(Debug, Clone, Copy, Default)]
+struct LoadState {
+    one: f64,
+    five: f64,
+    fifteen: f64,
+}
+
+impl LoadState {
+    /// 指数衰减更新：load = load * e^(-t/T) + n * (1 - e^(-t/T))
+    fn update(&mut self, sample: f64) {
+        let interval = SAMPLE_INTERVAL.as_secs_f64();
+        let decay = |load: f64, window: f64| {
+            let factor = (-interval / window).exp();
+            load * factor + sample * (1.0 - factor)
+        };
+        self.one = decay(self.one, 60.0);
+        self.five = decay(self.five, 300.0);
+        self.fifteen = decay(self.fifteen, 900.0);
+    }
+}
+
+static LOAD_STATE: OnceLock<Arc<Mutex<LoadState>>> = OnceLock::new();
+
+/// 获取模拟的负载平均值 (1, 5, 15分钟)
+/// 首次调用时启动后台采样线程，刚启动时的数值会从0逐渐逼近真实负载
+pub fn load_average() -> (f64, f64, f64) {
+    let state = LOAD_STATE.get_or_init(|| {
+        let state = Arc::new(Mutex::new(LoadState::default()));
+        spawn_sampler(state.clone());
+        state
+    });
+
+    match state.lock() {
+        Ok(load) => (load.one, load.five, load.fifteen),
+        Err(_) => (0.0, 0.0, 0.0),
+    }
+}
+
+fn spawn_sampler(state: Arc<Mutex<LoadState>>) {
+    let _ = thread::Builder::new()
+        .name("lycrex-load-sampler".to_string())
+        .spawn(move || {
+            let Some(counters) = PdhCounters::open() else {
+                return;
+            };
+            let cpu_count = thread::available_parallelism().map(|n| n.get()).unwrap_or(1) as f64;
+
+            // 第一次采集只用于建立基准，处理器时间需要两次采样才有意义
+            counters.collect();
+
+            loop {
+                thread::sleep(SAMPLE_INTERVAL);
+
+                if let Some((queue_length, processor_time)) = counters.collect() {
+                    let running = (processor_time / 100.0 * cpu_count).clamp(0.0, cpu_count);
+                    let sample = queue_length.max(0.0) + running;
+                    if let Ok(mut load) = state.lock() {
+                        load.update(sample);
+                    }
+                }
+            }
+        });
+}
+
+/// PDH 查询句柄，只在采样线程内部使用
+struct PdhCounters {
+    query: PDH_HQUERY,
+    queue_length: PDH_HCOUNTER,
+    processor_time: PDH_HCOUNTER,
+}
+
+impl PdhCounters {
+    fn open() -> Option<Self> {
+        unsafe {
+            let mut query = PDH_HQUERY::default();
+            if PdhOpenQueryW(None, 0, &mut query) != 0 {
+                return None;
+            }
+
+            let mut queue_length = PDH_HCOUNTER::default();
+            let mut processor_time = PDH_HCOUNTER::default();
+            if PdhAddEnglishCounterW(query, w!("\\System\\Processor Queue Length"), 0, &mut queue_length) != 0
+                || PdhAddEnglishCounterW(query, w!("\\Processor(_Total)\\% Processor Time"), 0, &mut processor_time) != 0
+            {
+                PdhCloseQuery(query);
+                return None;
+            }
+
+            Some(Self { query, queue_length, processor_time })
+        }
+    }
+
+    /// 采集一次数据，返回 (处理器队列长度, 总处理器时间百分比)
+    fn collect(&self) -> Option<(f64, f64)> {
+        unsafe {
+            if PdhCollectQueryData(self.query) != 0 {
+                return None;
+            }
+            Some((Self::read(self.queue_length)?, Self::read(self.processor_time)?))
+        }
+    }
+
+    unsafe fn read(counter: PDH_HCOUNTER) -> Option<f64> {
+        let mut value = PDH_FMT_COUNTERVALUE::default();
+        if PdhGetFormattedCounterValue(counter, PDH_FMT_DOUBLE, None, &mut value) != 0 {
+            return None;
+        }
+        Some(value.Anonymous.doubleValue)
+    }
+}
+
+impl Drop for PdhCounters {
+    fn drop(&mut self) {
+        unsafe {
+            PdhCloseQuery(self.query);
+        }
+    }
+}
@@ -1,7 +1,7 @@
 /// 基础数学函数
 
 /// 复数结构体
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub struct Complex {
     pub x: f64,  // 实
     pub y: f64,  // 虚
@@ -11,11 +11,66 @@ impl Complex {
     pub fn new(x: f64, y: f64) -> Self {
         Complex { x, y }
     }
+
+    /// 由模和辐角创建：r·e^(iθ)
+    pub fn from_polar(r: f64, theta: f64) -> Self {
+        Complex { x: r * theta.cos(), y: r * theta.sin() }
+    }
     
     /// 计算复数的模（绝对值）
     pub fn abs(&self) -> f64 {
         (self.x * self.x + self.y * self.y).sqrt()
     }
+
+    /// 辐角，范围 (-π, π]
+    pub fn arg(&self) -> f64 {
+        self.y.atan2(self.x)
+    }
+
+    /// 共轭复数
+    pub fn conj(&self) -> Self {
+        Complex { x: self.x, y: -self.y }
+    }
+
+    /// 数乘
+    pub fn scale(&self, factor: f64) -> Self {
+        Complex { x: self.x * factor, y: self.y * factor }
+    }
+}
+
+impl std::ops::Add for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        Complex { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl std::ops::Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        Complex { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl std::ops::Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: Complex) -> Complex {
+        Complex {
+            x: self.x * other.x - self.y * other.y,
+            y: self.x * other.y + self.y * other.x,
+        }
+    }
+}
+
+impl std::ops::Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex { x: -self.x, y: -self.y }
+    }
 }
 
 /// 异常结构体
@@ -389,6 +444,9 @@ pub fn fdimf(x: f32, y: f32) -> f32 {
 pub const EPSILON: f64 = f64::EPSILON;
 pub const MACHINE_EPSILON: f64 = 2.220446049250313e-16;
 
+/// `precise` 进行有理重建时允许的最大分母
+pub const PRECISE_MAX_DENOMINATOR: u64 = 1000;
+
 /// 精度处理 trait - 支持链式调用，类似于 "text".red() 的风格
 pub trait PrecisionExt {
     /// 智能精度修正 - 自动检测并修正到精确值
@@ -415,6 +473,19 @@ pub trait PrecisionExt {
     
     /// 使用自定义容差检查是否近似等于
     fn nearly_equals_with_tolerance(self, other: f64, tolerance: f64) -> bool;
+
+    /// 连分数有理重建 - 返回分母不超过 max_denominator、且与自身近似相等的最简分数 (分子, 分母)
+    /// 依次检查连分数的各个渐近分数，取第一个满足 `nearly_equals` 的；找不到或数值不是有限值时返回 None
+    ///
+    /// # Example
+    /// ```
+    /// use lycrex_tool::utils::math::*;
+    /// assert_eq!((1.0_f64 / 7.0).as_rational(100), Some((1, 7)));
+    /// assert_eq!((0.1_f64 + 0.2).as_rational(100), Some((3, 10)));
+    /// assert_eq!(M_PI.as_rational(1000), None);
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    fn as_rational(self, max_denominator: u64) -> Option<(i64, i64)>;
 }
 
 impl PrecisionExt for f64 {
@@ -444,7 +515,16 @@ impl PrecisionExt for f64 {
         }
         
         // 如果不是特殊值，应用智能舍入
-        smart_round(self, None)
+        let rounded = smart_round(self, None);
+        if rounded != self {
+            return rounded;
+        }
+
+        // 最后尝试还原为分母较小的有理数，例如 5/7 - 3/7 修正为 2/7
+        match self.as_rational(PRECISE_MAX_DENOMINATOR) {
+            Some((numerator, denominator)) => numerator as f64 / denominator as f64,
+            None => self,
+        }
     }
     
     fn precise_to(self, decimal_places: u32) -> f64 {
@@ -458,6 +538,10 @@ impl PrecisionExt for f64 {
     fn nearly_equals_with_tolerance(self, other: f64, tolerance: f64) -> bool {
         nearly_equal(self, other, Some(tolerance))
     }
+
+    fn as_rational(self, max_denominator: u64) -> Option<(i64, i64)> {
+        rational_approximation(self, max_denominator)
+    }
 }
 
 /// 角度值的精确三角函数计算 trait
@@ -619,6 +703,43 @@ fn round_to_rational(x: f64) -> f64 {
     best_match
 }
 
+/// 连分数展开 x = a0 + 1/(a1 + 1/(a2 + ...))，用递推 h_n = a_n·h_{n-1} + h_{n-2}（分母同理）生成渐近分数
+/// 渐近分数是同等大小分母下的最佳逼近，分母超过上限或分子溢出 i64 时停止
+fn rational_approximation(x: f64, max_denominator: u64) -> Option<(i64, i64)> {
+    if !x.is_finite() || max_denominator == 0 {
+        return None;
+    }
+    let sign = if x < 0.0 { -1 } else { 1 };
+    let mut rest = x.abs();
+    let (mut h_prev, mut h) = (0_i64, 1_i64);
+    let (mut k_prev, mut k) = (1_i64, 0_i64);
+
+    // f64 的连分数展开最多约 40 项即可精确到最后一位
+    for _ in 0..64 {
+        let a = rest.floor();
+        if a >= i64::MAX as f64 {
+            return None;
+        }
+        let a = a as i64;
+        let h_next = a.checked_mul(h)?.checked_add(h_prev)?;
+        let k_next = a.checked_mul(k)?.checked_add(k_prev)?;
+        if k_next as u64 > max_denominator {
+            return None;
+        }
+        (h_prev, h, k_prev, k) = (h, h_next, k, k_next);
+
+        if nearly_equal(x, sign as f64 * h as f64 / k as f64, None) {
+            return Some((sign * h, k));
+        }
+        let fraction = rest - a as f64;
+        if fraction == 0.0 {
+            return None;
+        }
+        rest = 1.0 / fraction;
+    }
+    None
+}
+
 /// 高精度比较函数
 pub fn nearly_equal(a: f64, b: f64, tolerance: Option<f64>) -> bool {
     let tol = tolerance.unwrap_or(MACHINE_EPSILON * 10.0);
@@ -839,3 +960,27 @@ pub fn exp_diff(a: f64, b: f64) -> f64 {
     
     sign * exp(max_val) * (1.0 - exp(min_val - max_val))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::math::M_PI;
+
+    #[test]
+    fn test_as_rational() {
+        assert_eq!((1.0_f64 / 7.0).as_rational(100), Some((1, 7)));
+        assert_eq!((-0.75_f64).as_rational(100), Some((-3, 4)));
+        assert_eq!(2.5_f64.as_rational(10), Some((5, 2)));
+        assert_eq!(0.0_f64.as_rational(1), Some((0, 1)));
+        assert_eq!((1.1_f64 * 1.1).as_rational(1000), Some((121, 100)));
+        // 分母上限内不存在足够接近的分数
+        assert_eq!((1.0_f64 / 7.0).as_rational(6), None);
+        assert_eq!(M_PI.as_rational(1000), None);
+        assert_eq!(f64::NAN.as_rational(100), None);
+        assert_eq!(1e30_f64.as_rational(100), None);
+
+        assert_eq!((5.0_f64 / 7.0 - 3.0 / 7.0).precise(), 2.0 / 7.0);
+        assert_eq!((0.1_f64 + 0.2).precise(), 0.3);
+        assert_eq!(M_PI.precise(), M_PI);
+    }
+}
@@ -0,0 +1,158 @@
+// 快速傅里叶变换
+// 长度为 2 的幂时使用原地基 2 Cooley–Tukey 算法，其他长度使用 Bluestein 算法转换为 2 的幂长度的循环卷积
+// 正变换 X[k] = Σ x[n]·e^(-2πikn/N)，逆变换带 1/N 归一化，因此 ifft(fft(x)) = x
+
+use super::basic::Complex;
+use super::constants::M_PI;
+
+/// 原地正变换，支持任意长度（非 2 的幂时需要额外分配约 4N 的临时空间）
+pub fn fft(data: &mut [Complex]) {
+    transform(data, false);
+}
+
+/// 原地逆变换，结果已除以 N
+pub fn ifft(data: &mut [Complex]) {
+    transform(data, true);
+    let scale = 1.0 / data.len().max(1) as f64;
+    for value in data.iter_mut() {
+        *value = value.scale(scale);
+    }
+}
+
+/// 实数序列的正变换，返回全部 N 个频率分量（满足 X[N-k] = conj(X[k])）
+pub fn fft_real(input: &[f64]) -> Vec<Complex> {
+    let mut data: Vec<Complex> = input.iter().map(|&x| Complex::new(x, 0.0)).collect();
+    fft(&mut data);
+    data
+}
+
+/// 不做归一化的变换，inverse 为 true 时指数取正号
+fn transform(data: &mut [Complex], inverse: bool) {
+    let n = data.len();
+    if n <= 1 {
+        return;
+    }
+    if n.is_power_of_two() {
+        radix2(data, inverse);
+    } else {
+        bluestein(data, inverse);
+    }
+}
+
+/// 基 2 Cooley–Tukey：先按位反转重排，再逐级做蝶形运算，旋转因子只计算一次
+fn radix2(data: &mut [Complex], inverse: bool) {
+    let n = data.len();
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits);
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let twiddles: Vec<Complex> = (0..n / 2)
+        .map(|k| Complex::from_polar(1.0, sign * 2.0 * M_PI * k as f64 / n as f64))
+        .collect();
+
+    let mut len = 2;
+    while len <= n {
+        let half = len / 2;
+        let stride = n / len;
+        for start in (0..n).step_by(len) {
+            for j in 0..half {
+                let t = twiddles[j * stride] * data[start + j + half];
+                let u = data[start + j];
+                data[start + j] = u + t;
+                data[start + j + half] = u - t;
+            }
+        }
+        len *= 2;
+    }
+}
+
+/// Bluestein 算法：利用 kn = (k² + n² - (k-n)²) / 2 把 DFT 写成与 chirp 序列的卷积
+fn bluestein(data: &mut [Complex], inverse: bool) {
+    let n = data.len();
+    let m = (2 * n - 1).next_power_of_two();
+    let sign = if inverse { 1.0 } else { -1.0 };
+
+    // chirp[k] = e^(±πik²/N)，k² 先对 2N 取模以保持大 k 时的精度
+    let chirp: Vec<Complex> = (0..n as u64)
+        .map(|k| {
+            let k2 = (k * k) % (2 * n as u64);
+            Complex::from_polar(1.0, sign * M_PI * k2 as f64 / n as f64)
+        })
+        .collect();
+
+    let mut a = vec![Complex::default(); m];
+    for k in 0..n {
+        a[k] = data[k] * chirp[k];
+    }
+    let mut b = vec![Complex::default(); m];
+    b[0] = chirp[0].conj();
+    for k in 1..n {
+        b[k] = chirp[k].conj();
+        b[m - k] = chirp[k].conj();
+    }
+
+    radix2(&mut a, false);
+    radix2(&mut b, false);
+    for (x, y) in a.iter_mut().zip(&b) {
+        *x = *x * *y;
+    }
+    radix2(&mut a, true);
+
+    let scale = 1.0 / m as f64;
+    for k in 0..n {
+        data[k] = (a[k] * chirp[k]).scale(scale);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_dft(input: &[Complex]) -> Vec<Complex> {
+        let n = input.len();
+        (0..n)
+            .map(|k| {
+                input.iter().enumerate().fold(Complex::default(), |sum, (j, &x)| {
+                    sum + x * Complex::from_polar(1.0, -2.0 * M_PI * (j * k) as f64 / n as f64)
+                })
+            })
+            .collect()
+    }
+
+    fn assert_close(a: &[Complex], b: &[Complex]) {
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(b) {
+            assert!((*x - *y).abs() < 1e-9, "{x:?} != {y:?}");
+        }
+    }
+
+    #[test]
+    fn test_fft_matches_dft_and_round_trips() {
+        for n in [1, 2, 8, 6, 12, 17] {
+            let input: Vec<Complex> = (0..n)
+                .map(|i| Complex::new((i as f64 * 0.7).sin(), i as f64 * 0.25 - 1.0))
+                .collect();
+            let mut data = input.clone();
+            fft(&mut data);
+            assert_close(&data, &naive_dft(&input));
+            ifft(&mut data);
+            assert_close(&data, &input);
+        }
+    }
+
+    #[test]
+    fn test_fft_real() {
+        // cos(2π·3n/16) 的能量集中在第 3 和第 13 个频率分量
+        let input: Vec<f64> = (0..16).map(|i| (2.0 * M_PI * 3.0 * i as f64 / 16.0).cos()).collect();
+        let spectrum = fft_real(&input);
+        for (k, value) in spectrum.iter().enumerate() {
+            let expected = if k == 3 || k == 13 { 8.0 } else { 0.0 };
+            assert!((value.abs() - expected).abs() < 1e-9, "bin {k}: {value:?}");
+        }
+    }
+}
@@ -0,0 +1,89 @@
+// 数值积分
+// 复合梯形公式、复合 Simpson 公式和自适应 Simpson 积分，被积函数以闭包传入
+
+use super::basic::*;
+
+/// 自适应 Simpson 积分的最大递归深度，超过后直接采用当前估计，避免病态被积函数导致栈溢出
+const MAX_ADAPTIVE_DEPTH: u32 = 50;
+
+/// 复合梯形公式，将 [a, b] 等分为 n 个子区间（n 为 0 时按 1 处理）
+/// 误差为 O(h²)
+pub fn trapezoid(f: impl Fn(f64) -> f64, a: f64, b: f64, n: usize) -> f64 {
+    let n = n.max(1);
+    let h = (b - a) / n as f64;
+    let interior: f64 = (1..n).map(|i| f(a + i as f64 * h)).sum();
+    h * (0.5 * (f(a) + f(b)) + interior)
+}
+
+/// 复合 Simpson 公式，将 [a, b] 等分为 n 个子区间（n 为奇数时加 1，为 0 时按 2 处理）
+/// 误差为 O(h⁴)，对三次以内的多项式精确
+pub fn simpson(f: impl Fn(f64) -> f64, a: f64, b: f64, n: usize) -> f64 {
+    let n = (n.max(2) + 1) & !1;
+    let h = (b - a) / n as f64;
+    let interior: f64 = (1..n)
+        .map(|i| {
+            let weight = if i % 2 == 1 { 4.0 } else { 2.0 };
+            weight * f(a + i as f64 * h)
+        })
+        .sum();
+    h / 3.0 * (f(a) + f(b) + interior)
+}
+
+/// 自适应 Simpson 积分
+/// 每个区间比较整体与两半的 Simpson 估计，差值超过 15·tol 时二分并把容差减半，
+/// 满足条件后加上 Richardson 外推修正项；递归深度达到上限时直接返回当前估计
+pub fn adaptive_simpson(f: impl Fn(f64) -> f64, a: f64, b: f64, tol: f64) -> f64 {
+    if a == b {
+        return 0.0;
+    }
+    let (fa, fm, fb) = (f(a), f(0.5 * (a + b)), f(b));
+    let whole = simpson_rule(a, b, fa, fm, fb);
+    adaptive_step(&f, a, b, fa, fm, fb, whole, fabs(tol), 0)
+}
+
+/// 单个区间上的 Simpson 公式
+fn simpson_rule(a: f64, b: f64, fa: f64, fm: f64, fb: f64) -> f64 {
+    (b - a) / 6.0 * (fa + 4.0 * fm + fb)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn adaptive_step(f: &impl Fn(f64) -> f64, a: f64, b: f64, fa: f64, fm: f64, fb: f64, whole: f64, tol: f64, depth: u32) -> f64 {
+    let m = 0.5 * (a + b);
+    let (lm, rm) = (0.5 * (a + m), 0.5 * (m + b));
+    let (flm, frm) = (f(lm), f(rm));
+    let left = simpson_rule(a, m, fa, flm, fm);
+    let right = simpson_rule(m, b, fm, frm, fb);
+    let delta = left + right - whole;
+
+    if depth >= MAX_ADAPTIVE_DEPTH || fabs(delta) <= 15.0 * tol || !delta.is_finite() {
+        return left + right + delta / 15.0;
+    }
+    adaptive_step(f, a, m, fa, flm, fm, left, 0.5 * tol, depth + 1)
+        + adaptive_step(f, m, b, fm, frm, fb, right, 0.5 * tol, depth + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::math::{normal_pdf, M_PI};
+
+    #[test]
+    fn test_fixed_rules() {
+        assert!(fabs(trapezoid(sin, 0.0, M_PI, 1000) - 2.0) < 1e-5);
+        assert!(fabs(simpson(sin, 0.0, M_PI, 100) - 2.0) < 1e-7);
+        // Simpson 对三次多项式精确，奇数 n 会被调整为偶数
+        assert!(fabs(simpson(|x| x * x * x, 0.0, 2.0, 3) - 4.0) < 1e-12);
+        assert_eq!(trapezoid(|x| x, 1.0, 3.0, 0), 4.0);
+    }
+
+    #[test]
+    fn test_adaptive_simpson() {
+        assert!(fabs(adaptive_simpson(sin, 0.0, M_PI, 1e-10) - 2.0) < 1e-9);
+        assert!(fabs(adaptive_simpson(normal_pdf, -12.0, 12.0, 1e-10) - 1.0) < 1e-9);
+        assert!(fabs(adaptive_simpson(normal_pdf, 12.0, -12.0, 1e-10) + 1.0) < 1e-9);
+        // 跳跃间断点处误差估计始终偏大，递归在深度上限处停止
+        let step = adaptive_simpson(|x| if x < 1.0 / 3.0 { 0.0 } else { 1.0 }, 0.0, 1.0, 1e-12);
+        assert!(fabs(step - 2.0 / 3.0) < 1e-9);
+        assert_eq!(adaptive_simpson(sin, 1.0, 1.0, 1e-6), 0.0);
+    }
+}
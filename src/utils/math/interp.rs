@@ -0,0 +1,210 @@
+// 插值
+// 分段线性插值、自然三次样条和 Lagrange 多项式插值
+// 采样点的 x 坐标须严格递增（Lagrange 插值只要求互不相同）
+
+use std::{cmp::Ordering, fmt};
+
+/// 插值失败的原因
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InterpError {
+    /// 采样点为空、xs 与 ys 长度不同，或 x 坐标不是严格递增
+    InvalidData,
+    /// x 超出采样范围（仅在 [`Extrapolation::Error`] 模式下返回）
+    OutOfRange { x: f64 },
+}
+
+impl fmt::Display for InterpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InterpError::InvalidData => write!(f, "Invalid interpolation data"),
+            InterpError::OutOfRange { x } => write!(f, "Value {} is outside the interpolation range", x),
+        }
+    }
+}
+
+impl std::error::Error for InterpError {}
+
+/// 线性插值在采样范围之外的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Extrapolation {
+    /// 取最近端点的值
+    #[default]
+    Clamp,
+    /// 返回 [`InterpError::OutOfRange`]
+    Error,
+}
+
+/// 分段线性插值，超出范围时取端点值；数据无效时返回 NaN
+pub fn linear(x: f64, xs: &[f64], ys: &[f64]) -> f64 {
+    linear_with(x, xs, ys, Extrapolation::Clamp).unwrap_or(f64::NAN)
+}
+
+/// 分段线性插值，通过二分查找定位区间，O(log n)
+/// 为保持查找的复杂度，不检查 xs 是否递增，只检查长度
+pub fn linear_with(x: f64, xs: &[f64], ys: &[f64], extrapolation: Extrapolation) -> Result<f64, InterpError> {
+    if xs.is_empty() || xs.len() != ys.len() {
+        return Err(InterpError::InvalidData);
+    }
+    if x.is_nan() {
+        return Ok(f64::NAN);
+    }
+
+    let last = xs.len() - 1;
+    if x < xs[0] || x > xs[last] {
+        return match extrapolation {
+            Extrapolation::Clamp if x < xs[0] => Ok(ys[0]),
+            Extrapolation::Clamp => Ok(ys[last]),
+            Extrapolation::Error => Err(InterpError::OutOfRange { x }),
+        };
+    }
+
+    // 第一个大于 x 的位置，x 恰好等于采样点时 t = 0，结果与 ys 完全相同
+    let upper = xs.partition_point(|&v| v <= x);
+    if upper > last {
+        return Ok(ys[last]);
+    }
+    let lower = upper - 1;
+    let t = (x - xs[lower]) / (xs[upper] - xs[lower]);
+    Ok(ys[lower] + t * (ys[upper] - ys[lower]))
+}
+
+/// 自然三次样条（两端二阶导数为 0）
+/// 构造时求解三对角方程组得到各节点的二阶导数，之后每次求值只需二分查找区间，O(log n)
+#[derive(Debug, Clone)]
+pub struct CubicSpline {
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    second_derivatives: Vec<f64>,
+}
+
+impl CubicSpline {
+    /// 至少需要 2 个采样点，且 xs 严格递增
+    pub fn new(xs: &[f64], ys: &[f64]) -> Result<Self, InterpError> {
+        let n = xs.len();
+        if n < 2 || n != ys.len() || xs.windows(2).any(|w| w[0].partial_cmp(&w[1]) != Some(Ordering::Less)) {
+            return Err(InterpError::InvalidData);
+        }
+
+        // 内部节点满足 h[i-1]·M[i-1] + 2(h[i-1]+h[i])·M[i] + h[i]·M[i+1] = 6(Δ[i] - Δ[i-1])
+        // 用追赶法（Thomas 算法）求解，M[0] = M[n-1] = 0
+        let mut m = vec![0.0; n];
+        let mut diagonal = vec![0.0; n];
+        let mut rhs = vec![0.0; n];
+        for i in 1..n - 1 {
+            let (h0, h1) = (xs[i] - xs[i - 1], xs[i + 1] - xs[i]);
+            let slope0 = (ys[i] - ys[i - 1]) / h0;
+            let slope1 = (ys[i + 1] - ys[i]) / h1;
+            diagonal[i] = 2.0 * (h0 + h1);
+            rhs[i] = 6.0 * (slope1 - slope0);
+            if i > 1 {
+                let factor = h0 / diagonal[i - 1];
+                diagonal[i] -= factor * h0;
+                rhs[i] -= factor * rhs[i - 1];
+            }
+        }
+        for i in (1..n - 1).rev() {
+            let h1 = xs[i + 1] - xs[i];
+            m[i] = (rhs[i] - h1 * m[i + 1]) / diagonal[i];
+        }
+
+        Ok(CubicSpline {
+            xs: xs.to_vec(),
+            ys: ys.to_vec(),
+            second_derivatives: m,
+        })
+    }
+
+    /// 计算样条在 x 处的值，超出范围时沿用端点所在区间的三次多项式外推
+    pub fn eval(&self, x: f64) -> f64 {
+        if x.is_nan() {
+            return f64::NAN;
+        }
+        let last = self.xs.len() - 1;
+        let upper = self.xs.partition_point(|&v| v <= x).clamp(1, last);
+        let lower = upper - 1;
+
+        let h = self.xs[upper] - self.xs[lower];
+        let a = (self.xs[upper] - x) / h;
+        let b = (x - self.xs[lower]) / h;
+        let (m0, m1) = (self.second_derivatives[lower], self.second_derivatives[upper]);
+        a * self.ys[lower] + b * self.ys[upper] + ((a * a * a - a) * m0 + (b * b * b - b) * m1) * h * h / 6.0
+    }
+}
+
+/// 构造自然三次样条插值器，等价于 [`CubicSpline::new`]
+pub fn cubic_spline(xs: &[f64], ys: &[f64]) -> Result<CubicSpline, InterpError> {
+    CubicSpline::new(xs, ys)
+}
+
+/// Lagrange 多项式插值，O(n²)
+/// 采样点较多时高次多项式在区间两端会剧烈振荡（Runge 现象），此时应使用 [`CubicSpline`]
+/// 采样点为空时返回 NaN，x 坐标重复时结果为非有限值
+pub fn lagrange(points: &[(f64, f64)], x: f64) -> f64 {
+    if points.is_empty() {
+        return f64::NAN;
+    }
+    points
+        .iter()
+        .enumerate()
+        .map(|(j, &(xj, yj))| {
+            let basis = points
+                .iter()
+                .enumerate()
+                .filter(|&(m, _)| m != j)
+                .fold(1.0, |product, (_, &(xm, _))| product * (x - xm) / (xj - xm));
+            yj * basis
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::math::{fabs, sin};
+
+    #[test]
+    fn test_linear() {
+        let xs = [0.0, 1.0, 3.0, 4.0];
+        let ys = [1.0, 3.0, 4.0, 8.0];
+        for (&x, &y) in xs.iter().zip(&ys) {
+            assert_eq!(linear(x, &xs, &ys), y);
+        }
+        assert_eq!(linear(0.5, &xs, &ys), 2.0);
+        assert_eq!(linear(2.0, &xs, &ys), 3.5);
+
+        // 递增数据的插值结果单调不减
+        let samples: Vec<f64> = (0..=80).map(|i| linear(i as f64 * 0.05, &xs, &ys)).collect();
+        assert!(samples.windows(2).all(|w| w[0] <= w[1]));
+
+        assert_eq!(linear(-1.0, &xs, &ys), 1.0);
+        assert_eq!(linear(9.0, &xs, &ys), 8.0);
+        assert_eq!(linear_with(9.0, &xs, &ys, Extrapolation::Error), Err(InterpError::OutOfRange { x: 9.0 }));
+        assert_eq!(linear_with(4.0, &xs, &ys, Extrapolation::Error), Ok(8.0));
+        assert_eq!(linear_with(1.0, &xs, &ys[..3], Extrapolation::Clamp), Err(InterpError::InvalidData));
+        assert_eq!(linear(5.0, &[2.0], &[7.0]), 7.0);
+    }
+
+    #[test]
+    fn test_cubic_spline_and_lagrange() {
+        let xs: Vec<f64> = (0..=10).map(|i| i as f64 * 0.3).collect();
+        let ys: Vec<f64> = xs.iter().map(|&x| sin(x)).collect();
+        let spline = cubic_spline(&xs, &ys).unwrap();
+        for (&x, &y) in xs.iter().zip(&ys) {
+            assert!(fabs(spline.eval(x) - y) < 1e-15);
+        }
+        assert!(fabs(spline.eval(1.05) - sin(1.05)) < 1e-3);
+        // 线性数据的自然样条退化为直线
+        let line = CubicSpline::new(&[0.0, 1.0, 2.5, 4.0], &[1.0, 3.0, 6.0, 9.0]).unwrap();
+        assert!(fabs(line.eval(3.3) - 7.6) < 1e-12);
+        assert!(CubicSpline::new(&[0.0, 0.0], &[1.0, 2.0]).is_err());
+        assert!(CubicSpline::new(&[0.0], &[1.0]).is_err());
+
+        // 3 个点唯一确定 y = x² - 2x + 3
+        let points = [(0.0, 3.0), (1.0, 2.0), (3.0, 6.0)];
+        for &(x, y) in &points {
+            assert_eq!(lagrange(&points, x), y);
+        }
+        assert!(fabs(lagrange(&points, 2.0) - 3.0) < 1e-12);
+        assert!(lagrange(&[], 1.0).is_nan());
+    }
+}
@@ -0,0 +1,437 @@
+// 线性代数
+// 行主序的稠密矩阵和向量，行列式、逆矩阵和线性方程组求解基于带部分主元的 LU 分解，适合小规模矩阵
+
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+use super::basic::*;
+
+/// 行主序稠密矩阵
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+    data: Vec<f64>,
+}
+
+impl Matrix {
+    /// 零矩阵
+    pub fn new(rows: usize, cols: usize) -> Self {
+        Self { rows, cols, data: vec![0.0; rows * cols] }
+    }
+
+    /// 按行主序从数据创建，长度与维度不符时返回 None
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<f64>) -> Option<Self> {
+        (data.len() == rows * cols).then_some(Self { rows, cols, data })
+    }
+
+    /// 从各行创建，各行长度不一致时返回 None
+    pub fn from_rows(rows: &[&[f64]]) -> Option<Self> {
+        let cols = rows.first().map_or(0, |row| row.len());
+        if rows.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+        Some(Self { rows: rows.len(), cols, data: rows.concat() })
+    }
+
+    /// n 阶单位矩阵
+    pub fn identity(n: usize) -> Self {
+        let mut matrix = Self::new(n, n);
+        for i in 0..n {
+            matrix[(i, i)] = 1.0;
+        }
+        matrix
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// 行主序的底层数据
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<f64> {
+        (row < self.rows && col < self.cols).then(|| self.data[row * self.cols + col])
+    }
+
+    pub fn row(&self, row: usize) -> &[f64] {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn transpose(&self) -> Self {
+        let mut result = Self::new(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                result[(j, i)] = self[(i, j)];
+            }
+        }
+        result
+    }
+
+    /// 矩阵乘法，左矩阵的列数与右矩阵的行数不同时返回 None
+    pub fn mul(&self, other: &Matrix) -> Option<Matrix> {
+        if self.cols != other.rows {
+            return None;
+        }
+        let mut result = Self::new(self.rows, other.cols);
+        for i in 0..self.rows {
+            for k in 0..self.cols {
+                let a = self[(i, k)];
+                if a == 0.0 {
+                    continue;
+                }
+                for j in 0..other.cols {
+                    result[(i, j)] += a * other[(k, j)];
+                }
+            }
+        }
+        Some(result)
+    }
+
+    /// 矩阵加法，维度不同时返回 None
+    pub fn add(&self, other: &Matrix) -> Option<Matrix> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return None;
+        }
+        let data = self.data.iter().zip(&other.data).map(|(a, b)| a + b).collect();
+        Some(Self { rows: self.rows, cols: self.cols, data })
+    }
+
+    /// 数乘
+    pub fn scale(&self, factor: f64) -> Matrix {
+        Self { rows: self.rows, cols: self.cols, data: self.data.iter().map(|x| x * factor).collect() }
+    }
+
+    /// 矩阵与列向量相乘，维度不符时返回 None
+    pub fn mul_vector(&self, vector: &VectorN) -> Option<VectorN> {
+        if self.cols != vector.len() {
+            return None;
+        }
+        Some(VectorN::new((0..self.rows).map(|i| dot(self.row(i), vector.as_slice())).collect()))
+    }
+
+    /// 行列式，非方阵返回 None
+    pub fn determinant(&self) -> Option<f64> {
+        if !self.is_square() {
+            return None;
+        }
+        Some(match Lu::decompose(self) {
+            Some(lu) => lu.determinant(),
+            None => 0.0,
+        })
+    }
+
+    /// 逆矩阵，非方阵或奇异矩阵返回 None
+    pub fn inverse(&self) -> Option<Matrix> {
+        if !self.is_square() {
+            return None;
+        }
+        let lu = Lu::decompose(self)?;
+        let n = self.rows;
+        let mut result = Self::new(n, n);
+        let mut column = vec![0.0; n];
+        for j in 0..n {
+            column.iter_mut().enumerate().for_each(|(i, x)| *x = if i == j { 1.0 } else { 0.0 });
+            let solution = lu.solve(&column);
+            for i in 0..n {
+                result[(i, j)] = solution[i];
+            }
+        }
+        Some(result)
+    }
+
+    /// 求解 Ax = b，非方阵、维度不符或奇异矩阵返回 None
+    pub fn solve(&self, b: &VectorN) -> Option<VectorN> {
+        if !self.is_square() || b.len() != self.rows {
+            return None;
+        }
+        Some(VectorN::new(Lu::decompose(self)?.solve(b.as_slice())))
+    }
+}
+
+impl Index<(usize, usize)> for Matrix {
+    type Output = f64;
+
+    fn index(&self, (row, col): (usize, usize)) -> &f64 {
+        assert!(row < self.rows && col < self.cols, "matrix index out of bounds");
+        &self.data[row * self.cols + col]
+    }
+}
+
+impl IndexMut<(usize, usize)> for Matrix {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut f64 {
+        assert!(row < self.rows && col < self.cols, "matrix index out of bounds");
+        &mut self.data[row * self.cols + col]
+    }
+}
+
+/// 带部分主元的 LU 分解 PA = LU，L 的对角线为 1，与 U 合并存放
+struct Lu {
+    n: usize,
+    data: Vec<f64>,
+    /// 第 i 行来自原矩阵的第 permutation[i] 行
+    permutation: Vec<usize>,
+    /// 行交换次数的奇偶性对应的符号
+    sign: f64,
+}
+
+impl Lu {
+    /// 主元相对矩阵最大元素可以忽略时视为奇异，返回 None
+    fn decompose(matrix: &Matrix) -> Option<Self> {
+        let n = matrix.rows;
+        let mut data = matrix.data.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut sign = 1.0;
+        let scale = data.iter().fold(0.0, |max: f64, x| max.max(fabs(*x)));
+        let tolerance = scale * n as f64 * MACHINE_EPSILON;
+
+        for k in 0..n {
+            let pivot_row = (k..n).max_by(|&a, &b| fabs(data[a * n + k]).total_cmp(&fabs(data[b * n + k])))?;
+            if scale == 0.0 || nearly_equal(data[pivot_row * n + k], 0.0, Some(tolerance)) {
+                return None;
+            }
+            if pivot_row != k {
+                for j in 0..n {
+                    data.swap(k * n + j, pivot_row * n + j);
+                }
+                permutation.swap(k, pivot_row);
+                sign = -sign;
+            }
+
+            let pivot = data[k * n + k];
+            for i in k + 1..n {
+                let factor = data[i * n + k] / pivot;
+                data[i * n + k] = factor;
+                for j in k + 1..n {
+                    data[i * n + j] -= factor * data[k * n + j];
+                }
+            }
+        }
+
+        Some(Self { n, data, permutation, sign })
+    }
+
+    fn determinant(&self) -> f64 {
+        (0..self.n).fold(self.sign, |det, i| det * self.data[i * self.n + i])
+    }
+
+    /// 依次前代（Ly = Pb）和回代（Ux = y）
+    fn solve(&self, b: &[f64]) -> Vec<f64> {
+        let n = self.n;
+        let mut x: Vec<f64> = self.permutation.iter().map(|&i| b[i]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                x[i] -= self.data[i * n + j] * x[j];
+            }
+        }
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                x[i] -= self.data[i * n + j] * x[j];
+            }
+            x[i] /= self.data[i * n + i];
+        }
+        x
+    }
+}
+
+fn dot(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// 三维向量
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vector3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Vector3 {
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn dot(&self, other: &Vector3) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// 叉积，结果垂直于两个向量并满足右手定则
+    pub fn cross(&self, other: &Vector3) -> Vector3 {
+        Vector3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// 欧几里得范数
+    pub fn norm(&self) -> f64 {
+        sqrt(self.dot(self))
+    }
+
+    /// 单位向量，零向量返回 None
+    pub fn normalize(&self) -> Option<Vector3> {
+        let norm = self.norm();
+        (norm > 0.0).then(|| *self * (1.0 / norm))
+    }
+}
+
+impl Add for Vector3 {
+    type Output = Vector3;
+
+    fn add(self, other: Vector3) -> Vector3 {
+        Vector3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Vector3 {
+    type Output = Vector3;
+
+    fn sub(self, other: Vector3) -> Vector3 {
+        Vector3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Mul<f64> for Vector3 {
+    type Output = Vector3;
+
+    fn mul(self, factor: f64) -> Vector3 {
+        Vector3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl Neg for Vector3 {
+    type Output = Vector3;
+
+    fn neg(self) -> Vector3 {
+        Vector3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+/// 任意维度的向量
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct VectorN {
+    data: Vec<f64>,
+}
+
+impl VectorN {
+    pub fn new(data: Vec<f64>) -> Self {
+        Self { data }
+    }
+
+    pub fn zeros(n: usize) -> Self {
+        Self { data: vec![0.0; n] }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    /// 点积，维度不同时返回 None
+    pub fn dot(&self, other: &VectorN) -> Option<f64> {
+        (self.len() == other.len()).then(|| dot(&self.data, &other.data))
+    }
+
+    /// 叉积，仅对三维向量有定义，其他维度返回 None
+    pub fn cross(&self, other: &VectorN) -> Option<VectorN> {
+        if self.len() != 3 || other.len() != 3 {
+            return None;
+        }
+        let a = Vector3::new(self.data[0], self.data[1], self.data[2]);
+        let b = Vector3::new(other.data[0], other.data[1], other.data[2]);
+        let c = a.cross(&b);
+        Some(VectorN::new(vec![c.x, c.y, c.z]))
+    }
+
+    /// 欧几里得范数
+    pub fn norm(&self) -> f64 {
+        sqrt(dot(&self.data, &self.data))
+    }
+
+    /// 单位向量，零向量返回 None
+    pub fn normalize(&self) -> Option<VectorN> {
+        let norm = self.norm();
+        (norm > 0.0).then(|| VectorN::new(self.data.iter().map(|x| x / norm).collect()))
+    }
+}
+
+impl Index<usize> for VectorN {
+    type Output = f64;
+
+    fn index(&self, index: usize) -> &f64 {
+        &self.data[index]
+    }
+}
+
+impl IndexMut<usize> for VectorN {
+    fn index_mut(&mut self, index: usize) -> &mut f64 {
+        &mut self.data[index]
+    }
+}
+
+impl From<Vector3> for VectorN {
+    fn from(v: Vector3) -> Self {
+        VectorN::new(vec![v.x, v.y, v.z])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matrix_inverse_and_determinant() {
+        let a = Matrix::from_rows(&[&[4.0, 7.0, 2.0], &[3.0, 6.0, 1.0], &[2.0, 5.0, 3.0]]).unwrap();
+        assert!(nearly_equal(a.determinant().unwrap(), 9.0, Some(1e-12)));
+
+        let product = a.mul(&a.inverse().unwrap()).unwrap();
+        let identity = Matrix::identity(3);
+        for (x, y) in product.as_slice().iter().zip(identity.as_slice()) {
+            assert!(fabs(x - y) < 1e-12);
+        }
+
+        let x = a.solve(&VectorN::new(vec![1.0, 2.0, 3.0])).unwrap();
+        let b = a.mul_vector(&x).unwrap();
+        assert!(b.as_slice().iter().zip([1.0, 2.0, 3.0]).all(|(x, y)| fabs(x - y) < 1e-12));
+
+        let singular = Matrix::from_rows(&[&[1.0, 2.0], &[2.0, 4.0]]).unwrap();
+        assert_eq!(singular.determinant(), Some(0.0));
+        assert!(singular.inverse().is_none());
+        assert!(Matrix::new(2, 3).determinant().is_none());
+        assert!(Matrix::new(2, 3).mul(&Matrix::new(2, 3)).is_none());
+        assert_eq!(Matrix::new(2, 3).transpose().rows(), 3);
+    }
+
+    #[test]
+    fn test_vectors() {
+        let x = Vector3::new(1.0, 0.0, 0.0);
+        let y = Vector3::new(0.0, 1.0, 0.0);
+        assert_eq!(x.cross(&y), Vector3::new(0.0, 0.0, 1.0));
+        assert_eq!(x.dot(&y), 0.0);
+        assert_eq!(Vector3::new(3.0, 4.0, 0.0).norm(), 5.0);
+        assert!(Vector3::default().normalize().is_none());
+
+        let v = VectorN::new(vec![1.0, 2.0, 2.0]);
+        assert_eq!(v.norm(), 3.0);
+        assert_eq!(v.dot(&VectorN::from(x)), Some(1.0));
+        assert!(v.dot(&VectorN::zeros(2)).is_none());
+        assert_eq!(VectorN::from(x).cross(&VectorN::from(y)), Some(VectorN::from(Vector3::new(0.0, 0.0, 1.0))));
+    }
+}
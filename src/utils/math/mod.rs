@@ -2,8 +2,22 @@ mod constants;
 mod basic;
 mod classification;
 mod advanced;
+mod linalg;
+mod integrate;
+mod fft;
+mod roots;
+mod stats;
+mod interp;
+mod series;
 
 pub use constants::*;
 pub use basic::*;
 pub use classification::*;
 pub use advanced::*;
+pub use linalg::*;
+pub use integrate::*;
+pub use fft::*;
+pub use roots::*;
+pub use stats::*;
+pub use interp::*;
+pub use series::*;
@@ -0,0 +1,189 @@
+// 方程求根
+// Newton–Raphson 迭代、二分法和三次方程的解析解
+// 迭代法返回 Result，区分"迭代未收敛"与"区间内不保证有根"两种失败
+
+use super::basic::*;
+use super::constants::M_PI;
+use std::fmt;
+
+/// 求根失败的原因
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RootError {
+    /// 达到最大迭代次数仍未满足容差，或迭代中出现导数为 0 / 非有限值，附带最后一次的估计
+    NoConvergence { estimate: f64 },
+    /// 区间两端函数值同号（或含 NaN），无法保证区间内有实根
+    NoSignChange,
+}
+
+impl fmt::Display for RootError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RootError::NoConvergence { estimate } => {
+                write!(f, "Root search did not converge (last estimate: {})", estimate)
+            }
+            RootError::NoSignChange => write!(f, "No sign change in the bracketing interval"),
+        }
+    }
+}
+
+impl std::error::Error for RootError {}
+
+/// Newton–Raphson 迭代，相邻两次估计之差不超过 tol 时返回
+/// 导数为 0、迭代值变为非有限值或 max_iter 次后仍未收敛时返回 [`RootError::NoConvergence`]
+pub fn newton(
+    f: impl Fn(f64) -> f64,
+    df: impl Fn(f64) -> f64,
+    x0: f64,
+    tol: f64,
+    max_iter: usize,
+) -> Result<f64, RootError> {
+    let tol = fabs(tol);
+    let mut x = x0;
+    for _ in 0..max_iter {
+        let fx = f(x);
+        if fx == 0.0 {
+            return Ok(x);
+        }
+        let dfx = df(x);
+        if dfx == 0.0 || !dfx.is_finite() {
+            return Err(RootError::NoConvergence { estimate: x });
+        }
+        let next = x - fx / dfx;
+        if !next.is_finite() {
+            return Err(RootError::NoConvergence { estimate: x });
+        }
+        if fabs(next - x) <= tol {
+            return Ok(next);
+        }
+        x = next;
+    }
+    Err(RootError::NoConvergence { estimate: x })
+}
+
+/// 二分法，要求 f(a) 与 f(b) 异号，区间宽度不超过 tol 时返回中点
+/// 每次迭代区间减半，tol 小于浮点精度时在区间无法继续细分处停止，因此总会终止
+pub fn bisection(f: impl Fn(f64) -> f64, a: f64, b: f64, tol: f64) -> Result<f64, RootError> {
+    let (mut lo, mut hi) = if a <= b { (a, b) } else { (b, a) };
+    let (mut f_lo, f_hi) = (f(lo), f(hi));
+    if f_lo == 0.0 {
+        return Ok(lo);
+    }
+    if f_hi == 0.0 {
+        return Ok(hi);
+    }
+    // 同号或含 NaN
+    if f_lo.is_nan() || f_hi.is_nan() || f_lo.signum() == f_hi.signum() {
+        return Err(RootError::NoSignChange);
+    }
+
+    let tol = fabs(tol);
+    loop {
+        let mid = lo + 0.5 * (hi - lo);
+        if hi - lo <= tol || mid <= lo || mid >= hi {
+            return Ok(mid);
+        }
+        let f_mid = f(mid);
+        if f_mid == 0.0 {
+            return Ok(mid);
+        }
+        if f_mid.signum() == f_lo.signum() {
+            lo = mid;
+            f_lo = f_mid;
+        } else {
+            hi = mid;
+        }
+    }
+}
+
+/// 求解 ax³ + bx² + cx + d = 0 的全部实根，按升序返回且不含重复
+/// 判别式为负时用三角法得到三个实根，为正时用 Cardano 公式得到唯一实根；
+/// a 接近 0 时退化为 [`solve_quadratic`]，返回空 Vec 表示没有实根
+pub fn solve_cubic(a: f64, b: f64, c: f64, d: f64) -> Vec<f64> {
+    if fabs(a) < 1e-15 {
+        let (x1, x2) = solve_quadratic(b, c, d);
+        let mut roots: Vec<f64> = x1.into_iter().chain(x2).collect();
+        roots.dedup();
+        return roots;
+    }
+
+    // 代换 x = t - B/3 得到缺项方程 t³ + pt + q = 0
+    let (b, c, d) = (b / a, c / a, d / a);
+    let shift = b / 3.0;
+    let p = c - b * shift;
+    let q = 2.0 * shift * shift * shift - c * shift + d;
+
+    let half_q = q / 2.0;
+    let third_p = p / 3.0;
+    let discriminant = half_q * half_q + third_p * third_p * third_p;
+    // 判别式的相对容差，避免重根因舍入误差被判为复根或单根
+    let scale = fmax(half_q * half_q, fabs(third_p * third_p * third_p));
+    let tolerance = 1e-12 * scale;
+
+    let mut roots = if scale == 0.0 {
+        // p = q = 0：三重根
+        vec![0.0]
+    } else if fabs(discriminant) <= tolerance {
+        // 一个单根和一个二重根
+        let double = cbrt(half_q);
+        vec![-2.0 * double, double]
+    } else if discriminant > 0.0 {
+        let sqrt_d = sqrt(discriminant);
+        vec![cbrt(-half_q + sqrt_d) + cbrt(-half_q - sqrt_d)]
+    } else {
+        let m = 2.0 * sqrt(-third_p);
+        let cos_arg = fmin(fmax(-half_q / (-third_p * sqrt(-third_p)), -1.0), 1.0);
+        let theta = acos(cos_arg) / 3.0;
+        (0..3).map(|k| m * cos(theta - 2.0 * M_PI * k as f64 / 3.0)).collect()
+    };
+
+    for root in roots.iter_mut() {
+        *root -= shift;
+    }
+    roots.sort_by(|x, y| x.total_cmp(y));
+    roots.dedup();
+    roots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::math::{M_PI_2, M_SQRT2};
+
+    fn assert_roots(actual: &[f64], expected: &[f64]) {
+        assert_eq!(actual.len(), expected.len(), "{actual:?} != {expected:?}");
+        for (x, y) in actual.iter().zip(expected) {
+            assert!(fabs(x - y) < 1e-9, "{actual:?} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_iterative_solvers() {
+        let root = newton(|x| x * x - 2.0, |x| 2.0 * x, 1.0, 1e-12, 50).unwrap();
+        assert!(fabs(root - M_SQRT2) < 1e-12);
+        // atan 的 Newton 迭代从 |x0| > 1.39 开始发散
+        assert!(matches!(newton(atan, |x| 1.0 / (1.0 + x * x), 2.0, 1e-12, 50), Err(RootError::NoConvergence { .. })));
+        assert!(matches!(newton(|x| x * x + 1.0, |x| 2.0 * x, 0.0, 1e-12, 50), Err(RootError::NoConvergence { .. })));
+
+        let root = bisection(cos, 2.0, 0.0, 1e-12).unwrap();
+        assert!(fabs(root - M_PI_2) < 1e-12);
+        assert_eq!(bisection(|x| x * x + 1.0, -1.0, 1.0, 1e-12), Err(RootError::NoSignChange));
+        assert_eq!(bisection(|x| x - 1.0, 1.0, 3.0, 1e-12), Ok(1.0));
+    }
+
+    #[test]
+    fn test_solve_cubic() {
+        // (x-1)(x-2)(x-3)
+        assert_roots(&solve_cubic(1.0, -6.0, 11.0, -6.0), &[1.0, 2.0, 3.0]);
+        assert_roots(&solve_cubic(2.0, -12.0, 22.0, -12.0), &[1.0, 2.0, 3.0]);
+        // x³ - 1 只有一个实根
+        assert_roots(&solve_cubic(1.0, 0.0, 0.0, -1.0), &[1.0]);
+        // (x-1)²(x+2)
+        assert_roots(&solve_cubic(1.0, 0.0, -3.0, 2.0), &[-2.0, 1.0]);
+        // (x+1)³
+        assert_roots(&solve_cubic(1.0, 3.0, 3.0, 1.0), &[-1.0]);
+        // 退化为二次和一次方程
+        assert_roots(&solve_cubic(0.0, 1.0, -3.0, 2.0), &[1.0, 2.0]);
+        assert_roots(&solve_cubic(0.0, 0.0, 2.0, -1.0), &[0.5]);
+        assert!(solve_cubic(0.0, 1.0, 0.0, 1.0).is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+// 描述统计
+// 均值、方差、分位数和相关系数，数据不足或含 NaN 时返回 f64::NAN，与分布函数的约定一致
+
+use super::basic::*;
+
+/// 算术平均数，空切片返回 NaN
+pub fn mean(data: &[f64]) -> f64 {
+    if data.is_empty() {
+        return f64::NAN;
+    }
+    data.iter().sum::<f64>() / data.len() as f64
+}
+
+/// 方差，sample 为 true 时计算样本方差（除以 n-1），否则计算总体方差（除以 n）
+/// 使用两遍算法并减去均值舍入误差的修正项；样本方差至少需要 2 个数据，总体方差至少需要 1 个
+pub fn variance(data: &[f64], sample: bool) -> f64 {
+    let n = data.len();
+    let dof = if sample { n.saturating_sub(1) } else { n };
+    if dof == 0 {
+        return f64::NAN;
+    }
+    let m = mean(data);
+    let (squares, residual) = data.iter().fold((0.0, 0.0), |(squares, residual), &x| {
+        let d = x - m;
+        (squares + d * d, residual + d)
+    });
+    (squares - residual * residual / n as f64) / dof as f64
+}
+
+/// 标准差，sample 含义同 [`variance`]
+pub fn std_dev(data: &[f64], sample: bool) -> f64 {
+    sqrt(variance(data, sample))
+}
+
+/// 中位数，等价于 `percentile(data, 50.0)`
+pub fn median(data: &[f64]) -> f64 {
+    percentile(data, 50.0)
+}
+
+/// 第 p 百分位数（p 取 0 到 100），在排序后相邻两个数据之间线性插值
+/// 与 NumPy 默认的 linear 方法相同：位置为 (n-1)·p/100；p 越界、数据为空或含 NaN 时返回 NaN
+pub fn percentile(data: &[f64], p: f64) -> f64 {
+    if data.is_empty() || !(0.0..=100.0).contains(&p) || data.iter().any(|x| x.is_nan()) {
+        return f64::NAN;
+    }
+    let mut sorted = data.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+
+    let position = (sorted.len() - 1) as f64 * p / 100.0;
+    let lower = floor(position) as usize;
+    let upper = (lower + 1).min(sorted.len() - 1);
+    let fraction = position - lower as f64;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * fraction
+}
+
+/// Pearson 相关系数，范围 [-1, 1]
+/// 两组数据长度不同、少于 2 个或任一组方差为 0 时返回 NaN
+pub fn correlation(x: &[f64], y: &[f64]) -> f64 {
+    if x.len() != y.len() || x.len() < 2 {
+        return f64::NAN;
+    }
+    let (mx, my) = (mean(x), mean(y));
+    let (mut sxy, mut sxx, mut syy) = (0.0, 0.0, 0.0);
+    for (&a, &b) in x.iter().zip(y) {
+        let (dx, dy) = (a - mx, b - my);
+        sxy += dx * dy;
+        sxx += dx * dx;
+        syy += dy * dy;
+    }
+    if sxx == 0.0 || syy == 0.0 {
+        return f64::NAN;
+    }
+    fmin(fmax(sxy / sqrt(sxx * syy), -1.0), 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_moments() {
+        let data = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        assert_eq!(mean(&data), 5.0);
+        assert_eq!(variance(&data, false), 4.0);
+        assert_eq!(std_dev(&data, false), 2.0);
+        assert!(fabs(variance(&data, true) - 32.0 / 7.0) < 1e-12);
+
+        assert!(mean(&[]).is_nan());
+        assert!(variance(&[1.0], true).is_nan());
+        assert_eq!(variance(&[1.0], false), 0.0);
+    }
+
+    #[test]
+    fn test_percentile_and_correlation() {
+        assert_eq!(median(&[3.0, 1.0, 2.0]), 2.0);
+        assert_eq!(median(&[4.0, 1.0, 3.0, 2.0]), 2.5);
+        assert_eq!(percentile(&[1.0, 2.0, 3.0, 4.0], 25.0), 1.75);
+        assert_eq!(percentile(&[1.0, 2.0, 3.0, 4.0], 100.0), 4.0);
+        assert_eq!(percentile(&[7.0], 30.0), 7.0);
+        assert!(percentile(&[1.0, 2.0], 101.0).is_nan());
+        assert!(median(&[]).is_nan());
+
+        assert!(fabs(correlation(&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0]) - 1.0) < 1e-12);
+        assert!(fabs(correlation(&[1.0, 2.0, 3.0], &[3.0, 2.0, 1.0]) + 1.0) < 1e-12);
+        assert!(fabs(correlation(&[1.0, 2.0, 3.0, 4.0], &[1.0, 3.0, 2.0, 4.0]) - 0.8) < 1e-12);
+        assert!(correlation(&[1.0, 1.0], &[1.0, 2.0]).is_nan());
+        assert!(correlation(&[1.0, 2.0], &[1.0]).is_nan());
+    }
+}
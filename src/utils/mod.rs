@@ -1,3 +1,4 @@
 pub mod time;
 pub mod format;
-pub mod math;
\ No newline at end of file
+pub mod math;
+pub mod checksum;
\ No newline at end of file
@@ -1,5 +1,5 @@
 use std::time::{SystemTime, UNIX_EPOCH, Instant};
-use chrono::{DateTime, Local, Utc};
+use chrono::{DateTime, Duration as ChronoDuration, FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Utc};
 
 /// 时区信息
 #[derive(Debug, Clone)]
@@ -9,8 +9,119 @@ pub struct TimezoneInfo {
     pub name: String,
 }
 
+/// 时区选择
+/// 用于需要按墙上时间（wall-clock）计算的场景，例如每天固定时刻轮转日志
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Timezone {
+    /// 系统本地时区（会跟随夏令时变化）
+    #[default]
+    Local,
+    /// 协调世界时
+    Utc,
+    /// 固定偏移（相对 UTC 东移的秒数，例如 +08:00 为 28800）
+    Offset(i32),
+}
+
+impl Timezone {
+    /// 将系统时间转换为该时区下的日期时间
+    pub fn to_datetime(&self, time: SystemTime) -> DateTime<FixedOffset> {
+        match self {
+            Timezone::Local => DateTime::<Local>::from(time).fixed_offset(),
+            Timezone::Utc => DateTime::<Utc>::from(time).fixed_offset(),
+            Timezone::Offset(seconds) => {
+                let offset = FixedOffset::east_opt(*seconds).unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+                DateTime::<Utc>::from(time).with_timezone(&offset)
+            }
+        }
+    }
+
+    /// 该时区下 time 所在的日历日期
+    pub fn date_of(&self, time: SystemTime) -> NaiveDate {
+        self.to_datetime(time).date_naive()
+    }
+
+    /// 计算严格晚于 after 的下一个 hour:minute 墙上时间
+    /// 若该时刻因夏令时跳变而不存在，则取跳变后的第一个有效时刻；若出现两次则取较早的一次
+    pub fn next_daily_at(&self, after: SystemTime, hour: u8, minute: u8) -> SystemTime {
+        match self {
+            Timezone::Local => Self::next_wall_clock(&Local, after, hour, minute),
+            Timezone::Utc => Self::next_wall_clock(&Utc, after, hour, minute),
+            Timezone::Offset(seconds) => {
+                let offset = FixedOffset::east_opt(*seconds).unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+                Self::next_wall_clock(&offset, after, hour, minute)
+            }
+        }
+    }
+
+    fn next_wall_clock<Tz: TimeZone>(tz: &Tz, after: SystemTime, hour: u8, minute: u8) -> SystemTime {
+        let after_utc = DateTime::<Utc>::from(after);
+        let hour = u32::from(hour.min(23));
+        let minute = u32::from(minute.min(59));
+        let mut date = after_utc.with_timezone(tz).date_naive();
+
+        // 从当天开始逐日向后查找，最多检查几天即可覆盖时区跳变
+        for _ in 0..4 {
+            if let Some(naive) = date.and_hms_opt(hour, minute, 0) {
+                if let Some(candidate) = Self::resolve_local(tz, naive) {
+                    if candidate > after_utc {
+                        return candidate.into();
+                    }
+                }
+            }
+            match date.succ_opt() {
+                Some(next) => date = next,
+                None => break,
+            }
+        }
+
+        // 理论上不会到达这里，保底返回一天之后
+        after + std::time::Duration::from_secs(86400)
+    }
+
+    fn resolve_local<Tz: TimeZone>(tz: &Tz, naive: NaiveDateTime) -> Option<DateTime<Utc>> {
+        match tz.from_local_datetime(&naive) {
+            LocalResult::Single(time) => Some(time.with_timezone(&Utc)),
+            LocalResult::Ambiguous(earliest, _) => Some(earliest.with_timezone(&Utc)),
+            LocalResult::None => {
+                // 夏令时跳过的时间段，按分钟向后寻找第一个存在的时刻
+                (1..=180).find_map(|minutes| {
+                    tz.from_local_datetime(&(naive + ChronoDuration::minutes(minutes)))
+                        .earliest()
+                        .map(|time| time.with_timezone(&Utc))
+                })
+            }
+        }
+    }
+}
+
+/// ISO 8601 时间中秒的小数位数
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SubsecPrecision {
+    /// 不带小数部分 eg: 2024-08-10T16:00:00Z
+    Seconds,
+    /// 3 位 eg: 2024-08-10T16:00:00.123Z
+    Millis,
+    /// 6 位 eg: 2024-08-10T16:00:00.123456Z
+    #[default]
+    Micros,
+    /// 9 位 eg: 2024-08-10T16:00:00.123456789Z
+    Nanos,
+}
+
+impl SubsecPrecision {
+    /// 对应的 chrono 格式片段
+    fn chrono_format(&self) -> &'static str {
+        match self {
+            SubsecPrecision::Seconds => "",
+            SubsecPrecision::Millis => "%.3f",
+            SubsecPrecision::Micros => "%.6f",
+            SubsecPrecision::Nanos => "%.9f",
+        }
+    }
+}
+
 /// 时间格式枚举
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum TimeFormat {
     /// Unix时间戳（秒） eg: 1723238400
     Unix,
@@ -24,6 +135,9 @@ pub enum TimeFormat {
     Iso8601,
     /// 程序运行时间 eg: +1000ms
     Relative,
+    /// 自定义 strftime 格式 eg: "%Y/%m/%d %H:%M:%S" -> 2024/08/10 16:00:00
+    /// 格式无效时退回 ISO 8601
+    Custom(String),
 }
 
 impl TimeFormat {
@@ -96,6 +210,9 @@ pub fn get_time(format: TimeFormat) -> String {
         TimeFormat::LocalTime => get_current_time().local_time,
         TimeFormat::Iso8601 => get_current_time().iso8601,
         TimeFormat::Relative => get_current_time().relative.to_string(),
+        TimeFormat::Custom(pattern) => {
+            TimeUtils::format_at(SystemTime::now(), &pattern, Timezone::Local).unwrap_or_else(|| get_current_time().iso8601)
+        }
     }
 }
 
@@ -245,6 +362,20 @@ impl TimeUtils {
         get_current_time().iso8601
     }
 
+    /// 按指定的小数位数和时区格式化当前时间（ISO 8601）
+    pub fn iso8601_with(precision: SubsecPrecision, timezone: Timezone) -> String {
+        Self::format_iso8601(SystemTime::now(), precision, timezone)
+    }
+
+    /// 按指定的小数位数和时区格式化给定时间（ISO 8601）
+    /// UTC 使用 `Z` 后缀，其他时区使用 `+08:00` 形式的偏移
+    pub fn format_iso8601(time: SystemTime, precision: SubsecPrecision, timezone: Timezone) -> String {
+        let datetime = timezone.to_datetime(time);
+        let offset = if timezone == Timezone::Utc { "Z" } else { "%:z" };
+        let pattern = format!("%Y-%m-%dT%H:%M:%S{}{}", precision.chrono_format(), offset);
+        datetime.format(&pattern).to_string()
+    }
+
     /// 获取程序运行时间（自然时间）
     pub fn program_uptime_string() -> String {
         Self::format_natural_time(get_program_uptime_millis())
@@ -395,6 +526,14 @@ impl TimeUtils {
         }
     }
     
+    /// 按 strftime 格式和时区格式化给定时间，格式中包含无效占位符时返回 None
+    pub fn format_at(time: SystemTime, format: &str, timezone: Timezone) -> Option<String> {
+        use std::fmt::Write;
+        let mut output = String::new();
+        write!(output, "{}", timezone.to_datetime(time).format(format)).ok()?;
+        Some(output)
+    }
+
     /// 格式化时间到指定格式
     pub fn format_time(format: &str) -> String {
         let local: DateTime<Local> = Local::now();